@@ -0,0 +1,328 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
+
+use crate::{
+    checked::{CheckedSub, SaturatingSub, WrappingAdd, WrappingSub},
+    eq::DimensionsEq,
+    strict::{StorageAdd, StorageSub},
+    units::{Kelvin, Second},
+    Quantity, UnitTrait,
+};
+
+/// Point (absolute value) in an affine space of `Q` (differences).
+///
+/// Timestamps vs durations, positions vs displacements, absolute vs delta
+/// temperatures all share the same algebra:
+///
+/// - `point - point = Q`
+/// - `point + Q = point`
+/// - `point - Q = point`
+/// - `point + point` doesn't make sense and doesn't compile
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{absolute::Absolute, IntExt};
+///
+/// let start = Absolute::new(100.m());
+/// let end = start + 20.m();
+///
+/// assert_eq!(end - start, 20.m());
+/// assert_eq!(end - 5.m(), Absolute::new(115.m()));
+/// ```
+///
+/// ```compile_fail
+/// use typed_phy::{absolute::Absolute, IntExt};
+///
+/// let a = Absolute::new(100.m());
+/// let _ = a + a;
+/// ```
+#[repr(transparent)]
+pub struct Absolute<Q> {
+    /// Offset from the origin
+    offset: Q,
+}
+
+impl<Q> Absolute<Q> {
+    /// Creates point that is `offset` away from the origin.
+    #[inline]
+    pub const fn new(offset: Q) -> Self {
+        Self { offset }
+    }
+
+    /// Returns offset of this point from the origin.
+    #[inline]
+    pub fn offset(self) -> Q {
+        self.offset
+    }
+}
+
+/// Point in time, e.g. value of a tick counter (ticks since boot) of an
+/// embedded clock.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     absolute::Timestamp,
+///     prefixes::Milli,
+///     units::Second,
+///     Quantity,
+/// };
+///
+/// type Ms = Quantity<u16, Milli<Second>>;
+///
+/// // Counter overflowed between the readings
+/// let start: Timestamp<u16, Milli<Second>> = Timestamp::since_origin(Ms::new(65_000));
+/// let end = start.wrapping_add(Ms::new(1_000));
+/// assert_eq!(end, Timestamp::since_origin(Ms::new(464)));
+///
+/// assert_eq!(end.wrapping_since(start), Ms::new(1_000));
+/// assert_eq!(end.saturating_since(start), Ms::new(0));
+/// assert_eq!(end.checked_since(start), None);
+/// ```
+pub type Timestamp<S, U = Second> = Absolute<Quantity<S, U>>;
+
+/// Methods of [`Timestamp`]s, they are available only for units of time.
+///
+/// ```compile_fail
+/// use typed_phy::{absolute::Absolute, IntExt};
+///
+/// let a = Absolute::new(10.m());
+/// let _ = a.wrapping_since(Absolute::new(5.m()));
+/// ```
+impl<S, U> Absolute<Quantity<S, U>>
+where
+    U: UnitTrait,
+    U::Dimensions: DimensionsEq<<Second as UnitTrait>::Dimensions>,
+{
+    /// Creates timestamp that is `elapsed` after the origin (e.g. boot of the
+    /// device).
+    #[inline]
+    pub fn since_origin(elapsed: Quantity<S, U>) -> Self {
+        Self::new(elapsed)
+    }
+
+    /// Returns `self - earlier` or `None` if the subtraction overflows.
+    #[inline]
+    pub fn checked_since(self, earlier: Self) -> Option<Quantity<S, U>>
+    where
+        S: CheckedSub<Output = S> + StorageSub<U, Output = S>,
+    {
+        self.offset.checked_sub(earlier.offset)
+    }
+
+    /// Returns `self - earlier` saturating at the numeric bounds (e.g. `0` if
+    /// `earlier` is later than `self` for unsigned storage).
+    #[inline]
+    pub fn saturating_since(self, earlier: Self) -> Quantity<S, U>
+    where
+        S: SaturatingSub<Output = S> + StorageSub<U, Output = S>,
+    {
+        self.offset.saturating_sub(earlier.offset)
+    }
+
+    /// Returns `self - earlier` wrapping around at the boundary of the type,
+    /// this is the right thing for free-running (overflowing) tick counters.
+    #[inline]
+    pub fn wrapping_since(self, earlier: Self) -> Quantity<S, U>
+    where
+        S: WrappingSub<Output = S> + StorageSub<U, Output = S>,
+    {
+        self.offset.wrapping_sub(earlier.offset)
+    }
+
+    /// Returns `self + delta` wrapping around at the boundary of the type.
+    #[inline]
+    pub fn wrapping_add(self, delta: Quantity<S, U>) -> Self
+    where
+        S: WrappingAdd<Output = S> + StorageAdd<U, Output = S>,
+    {
+        Self::new(self.offset.wrapping_add(delta))
+    }
+}
+
+/// Absolute temperature, i.e. temperature on a scale with the origin at the
+/// absolute zero.
+///
+/// Differences of temperatures are [`TemperatureInterval`]s, which are plain
+/// linear quantities: `1 °C` of difference is exactly `1 K`
+/// ([`DegreeCelsiusInterval`] is the same type as [`Kelvin`]), so rates like
+/// `dT/dt` are just `TemperatureInterval / time`.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     absolute::{Temperature, TemperatureInterval},
+///     units::{DegreeCelsiusInterval, DegreeFahrenheitInterval, KelvinPerMinute, KelvinPerSecond},
+///     IntExt, Quantity,
+/// };
+///
+/// let before: Temperature<f64> = Temperature::new(Quantity::new(293.15));
+/// let after = before + TemperatureInterval::<f64, DegreeCelsiusInterval>::new(3.0);
+///
+/// let rate: Quantity<f64, KelvinPerSecond> = (after - before) / 60.0.s();
+/// assert!((rate.into_unit::<KelvinPerMinute>().into_inner() - 3.0).abs() < 1e-9);
+///
+/// let f = (after - before).into_unit::<DegreeFahrenheitInterval>();
+/// assert!((f.into_inner() - 5.4).abs() < 1e-9);
+/// ```
+///
+/// [`DegreeCelsiusInterval`]: crate::units::DegreeCelsiusInterval
+/// [`Kelvin`]: crate::units::Kelvin
+pub type Temperature<S, U = Kelvin> = Absolute<Quantity<S, U>>;
+
+/// Difference of two [`Temperature`]s.
+pub type TemperatureInterval<S, U = Kelvin> = Quantity<S, U>;
+
+/// `point - point = Q`
+impl<Q: Sub> Sub for Absolute<Q> {
+    type Output = Q::Output;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.offset - rhs.offset
+    }
+}
+
+/// `point + Q = point`
+impl<Q: Add<Output = Q>> Add<Q> for Absolute<Q> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Q) -> Self::Output {
+        Self::new(self.offset + rhs)
+    }
+}
+
+/// `point - Q = point`
+impl<Q: Sub<Output = Q>> Sub<Q> for Absolute<Q> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Q) -> Self::Output {
+        Self::new(self.offset - rhs)
+    }
+}
+
+impl<Q: AddAssign> AddAssign<Q> for Absolute<Q> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Q) {
+        self.offset += rhs;
+    }
+}
+
+impl<Q: SubAssign> SubAssign<Q> for Absolute<Q> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Q) {
+        self.offset -= rhs;
+    }
+}
+
+impl<Q: Clone> Clone for Absolute<Q> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.offset.clone())
+    }
+}
+
+impl<Q: Copy> Copy for Absolute<Q> {}
+
+impl<Q: Default> Default for Absolute<Q> {
+    /// Returns the origin.
+    #[inline]
+    fn default() -> Self {
+        Self::new(Q::default())
+    }
+}
+
+impl<Q: PartialEq> PartialEq for Absolute<Q> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+    }
+}
+
+impl<Q: Eq> Eq for Absolute<Q> {}
+
+impl<Q: PartialOrd> PartialOrd for Absolute<Q> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.offset.partial_cmp(&other.offset)
+    }
+}
+
+impl<Q: Ord> Ord for Absolute<Q> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.offset.cmp(&other.offset)
+    }
+}
+
+impl<Q: fmt::Debug> fmt::Debug for Absolute<Q> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Absolute").field(&self.offset).finish()
+    }
+}
+
+impl<Q: fmt::Display> fmt::Display for Absolute<Q> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.offset, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Absolute;
+    use crate::{units::Kelvin, IntExt, Quantity};
+
+    #[test]
+    fn affine() {
+        let mut t = Absolute::new(Quantity::<i32, Kelvin>::new(300));
+        t += Quantity::new(10);
+        assert_eq!(t.offset(), Quantity::new(310));
+
+        t -= Quantity::new(20);
+        assert_eq!(t - Absolute::default(), Quantity::new(290));
+        assert!(t < t + Quantity::new(1));
+    }
+
+    #[test]
+    fn temperature_rate() {
+        use super::{Temperature, TemperatureInterval};
+        use crate::units::{DegreeCelsiusInterval, KelvinPerMinute, KelvinPerSecond};
+
+        let a: Temperature<i32> = Absolute::new(Quantity::new(300));
+        let b = a + TemperatureInterval::<i32, DegreeCelsiusInterval>::new(120);
+        let rate: Quantity<i32, KelvinPerSecond> = (b - a) / 60.s();
+        assert_eq!(rate, Quantity::new(2));
+        assert_eq!(rate.into_unit::<KelvinPerMinute>(), Quantity::new(120));
+    }
+
+    #[test]
+    fn timestamp() {
+        use super::Timestamp;
+
+        let a: Timestamp<u8> = Timestamp::since_origin(250.s());
+        let b = a.wrapping_add(10.s());
+        assert_eq!(b.offset(), 4.s());
+        assert_eq!(b.wrapping_since(a), 10.s());
+        assert_eq!(a.saturating_since(b), 246.s());
+        assert_eq!(b.checked_since(a), None);
+        assert_eq!(a.checked_since(b), Some(246.s()));
+    }
+
+    #[test]
+    fn unit_change() {
+        // `point - point` keeps the output of `Q - Q`
+        let a = Absolute::new(5.0.s());
+        let b = Absolute::new(2.0.s());
+        assert_eq!((a - b) * 2.0.mps(), 6.0.m());
+    }
+}
@@ -0,0 +1,138 @@
+//! Sound pressure levels (requires `float-math` feature).
+//!
+//! Sound pressure is a plain [`Quantity`] of pascals, but it's usually
+//! reported on a logarithmic scale, in decibels relative to `20 μPa` (the
+//! threshold of hearing). [`SoundPressureLevel`] is such a level, it converts
+//! both ways ([`Quantity::to_spl`] and [`SoundPressureLevel::to_pressure`]):
+//!
+//! - `L = 20 log10(p / p0)`
+//! - `p = p0 10^(L / 20)`
+//!
+//! ## Examples
+//!
+//! ```
+//! use typed_phy::{acoustics::SoundPressureLevel, units::Pascal, Quantity};
+//!
+//! let p = Quantity::<f64, Pascal>::new(2.0);
+//! let level = p.to_spl();
+//! assert!((level.db() - 100.0).abs() < 1e-9);
+//!
+//! let back = SoundPressureLevel::from_db(level.db()).to_pressure();
+//! assert!((back - p).into_inner().abs() < 1e-12);
+//! ```
+use core::fmt;
+
+use crate::{prefixes::Micro, units::Pascal, Quantity, UnitTrait};
+
+/// Sound pressure level, in decibels relative to `20 μPa` (dB SPL).
+///
+/// Levels are logarithmic, so they don't add like quantities do: two sources
+/// of `60 dB` give `≈ 63 dB`, not `120 dB`. Use
+/// [`combine`](SoundPressureLevel::combine) for that.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct SoundPressureLevel<S> {
+    /// Level in decibels
+    db: S,
+}
+
+impl<S> SoundPressureLevel<S> {
+    /// Creates level from a value in decibels.
+    #[inline]
+    pub const fn from_db(db: S) -> Self {
+        Self { db }
+    }
+
+    /// Returns level in decibels.
+    #[inline]
+    pub fn db(self) -> S {
+        self.db
+    }
+}
+
+macro_rules! spl_impls {
+    ($( $S:ident => { $log10:ident, $pow:ident, $fabs:ident } ),+ $(,)?) => {
+        $(
+            /// Sound pressure level of pressure quantities.
+            impl<U> Quantity<$S, U>
+            where
+                U: UnitTrait<Dimensions = <Pascal as UnitTrait>::Dimensions>,
+            {
+                /// Converts (RMS) sound pressure into a level.
+                ///
+                /// The sign of the pressure is ignored. Zero pressure gives
+                /// `-inf` dB.
+                #[inline]
+                pub fn to_spl(self) -> SoundPressureLevel<$S> {
+                    let reference = SoundPressureLevel::<$S>::reference().into_base();
+                    let ratio = self.into_base().into_inner() / reference.into_inner();
+                    SoundPressureLevel::from_db(20.0 * libm::$log10(libm::$fabs(ratio)))
+                }
+            }
+
+            impl SoundPressureLevel<$S> {
+                /// Returns the reference pressure `p0 = 20 μPa` (`0 dB SPL`).
+                #[inline]
+                pub fn reference() -> Quantity<$S, Micro<Pascal>> {
+                    Quantity::new(20.0)
+                }
+
+                /// Converts this level into (RMS) sound pressure.
+                #[inline]
+                pub fn to_pressure(self) -> Quantity<$S, Pascal> {
+                    let ratio = libm::$pow(10.0, self.db / 20.0);
+                    Self::reference().into_base() * ratio
+                }
+
+                /// Level of two incoherent sources sounding together:
+                /// `10 log10(10^(L1 / 10) + 10^(L2 / 10))`.
+                ///
+                /// ## Examples
+                ///
+                /// ```
+                /// use typed_phy::acoustics::SoundPressureLevel;
+                ///
+                /// let a = SoundPressureLevel::from_db(60.0f64);
+                /// assert!((a.combine(a).db() - 63.0103).abs() < 1e-4);
+                /// ```
+                #[inline]
+                pub fn combine(self, other: Self) -> Self {
+                    let power = |l: $S| libm::$pow(10.0, l / 10.0);
+                    Self::from_db(10.0 * libm::$log10(power(self.db) + power(other.db)))
+                }
+            }
+        )+
+    };
+}
+
+spl_impls! {
+    f32 => { log10f, powf, fabsf },
+    f64 => { log10, pow, fabs },
+}
+
+impl<S: fmt::Display> fmt::Display for SoundPressureLevel<S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.db, f)?;
+        f.write_str(" dB SPL")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SoundPressureLevel;
+    use crate::{prefixes::Milli, units::Pascal, Quantity};
+
+    #[test]
+    fn conversions() {
+        let reference = SoundPressureLevel::<f64>::reference();
+        assert_eq!(reference.to_spl().db(), 0.0);
+
+        let level = Quantity::<f64, Milli<Pascal>>::new(-20.0).to_spl();
+        assert!((level.db() - 60.0).abs() < 1e-9);
+
+        let p = SoundPressureLevel::from_db(94.0f32).to_pressure();
+        assert!((p.into_inner() - 1.0024).abs() < 1e-4);
+
+        assert_eq!(format!("{}", SoundPressureLevel::from_db(85)), "85 dB SPL");
+    }
+}
@@ -0,0 +1,142 @@
+use core::{
+    fmt,
+    ops::{Div, Mul},
+};
+
+use typenum::{Exp, Pow, Unsigned, U2};
+
+use crate::{
+    fraction::{Fraction, FractionTrait},
+    from_int::FromUnsigned,
+    prefixes::Milli,
+    simplify::Simplify,
+    units::Volt,
+    Quantity,
+};
+
+/// Millivolts per count of a converter as a fraction (`Vref / 2^Bits`), simplified
+/// to keep intermediate products small.
+type Scale<Vref, Bits> = <Fraction<Vref, Exp<U2, Bits>> as Simplify>::Output;
+
+/// Converter between raw ADC/DAC counts and voltage.
+///
+/// `Vref` is the reference voltage in millivolts and `Bits` is the resolution
+/// of the converter, both are type-level unsigned integers (e.g.
+/// `Prod<U33, U100>` and `U12` for a 12-bit converter with 3.3 V reference). Since the scale is
+/// known at compile time, conversion is done with a single multiplication and
+/// division by the (simplified) fraction `Vref / 2^Bits`, i.e. the result is
+/// exact up to the truncation of the final division.
+///
+/// Note: the intermediate product (`raw * Vref / gcd(Vref, 2^Bits)`) must fit
+/// into the storage.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{adc::Converter, prefixes::Milli, units::Volt, Quantity};
+/// use typenum::{Prod, U100, U12, U33};
+///
+/// let adc = Converter::<Prod<U33, U100>, U12>::new();
+///
+/// assert_eq!(adc.to_voltage(2048u32), Quantity::<_, Milli<Volt>>::new(1650));
+/// assert_eq!(adc.to_voltage(4095u32), Quantity::new(3299));
+/// assert_eq!(adc.to_raw(Quantity::new(1650u32)), 2048);
+/// ```
+pub struct Converter<Vref, Bits>(phantasm::Invariant<(Vref, Bits)>);
+
+impl<Vref, Bits> Converter<Vref, Bits> {
+    /// Creates new converter.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(phantasm::Invariant)
+    }
+}
+
+impl<Vref, Bits> Converter<Vref, Bits>
+where
+    Vref: Unsigned,
+    Bits: Unsigned,
+    U2: Pow<Bits>,
+    Fraction<Vref, Exp<U2, Bits>>: Simplify,
+    Scale<Vref, Bits>: FractionTrait,
+{
+    /// Converts raw counts to voltage (`raw * Vref / 2^Bits`), truncating.
+    #[inline]
+    pub fn to_voltage<S>(&self, raw: S) -> Quantity<S, Milli<Volt>>
+    where
+        S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+    {
+        Quantity::new(Scale::<Vref, Bits>::mul(raw))
+    }
+
+    /// Converts voltage to raw counts (`voltage * 2^Bits / Vref`), truncating.
+    ///
+    /// Note: voltages above the reference give counts that don't fit into
+    /// `Bits`, it's up to the caller to clamp them.
+    #[inline]
+    pub fn to_raw<S>(&self, voltage: Quantity<S, Milli<Volt>>) -> S
+    where
+        S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+    {
+        Scale::<Vref, Bits>::div(voltage.into_inner())
+    }
+}
+
+impl<Vref, Bits> Clone for Converter<Vref, Bits> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Vref, Bits> Copy for Converter<Vref, Bits> {}
+
+impl<Vref, Bits> Default for Converter<Vref, Bits> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Vref, Bits> fmt::Debug for Converter<Vref, Bits>
+where
+    Vref: Unsigned,
+    Bits: Unsigned,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Converter<{} mV, {} bit>", Vref::U64, Bits::U64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typenum::{Prod, U10, U100, U1000, U12, U16, U25, U5};
+
+    use super::Converter;
+    use crate::Quantity;
+
+    type U2500 = Prod<U25, U100>;
+    type U5000 = Prod<U5, U1000>;
+
+    #[test]
+    fn round_trip() {
+        // 5 V / 2^10 isn't a whole number of millivolts
+        let adc = Converter::<U5000, U10>::new();
+        assert_eq!(adc.to_voltage(1u32), Quantity::new(4));
+        assert_eq!(adc.to_voltage(512u32), Quantity::new(2500));
+        assert_eq!(adc.to_voltage(1023u64), Quantity::new(4995));
+        assert_eq!(adc.to_raw(Quantity::new(2500u32)), 512);
+        assert_eq!(adc.to_voltage(1000.0f64), Quantity::new(4882.8125));
+
+        // Scale is simplified, so `u32` doesn't overflow with 16 bits
+        let dac = Converter::<U2500, U16>::new();
+        assert_eq!(dac.to_voltage(65_535u32), Quantity::new(2499));
+        assert_eq!(dac.to_raw(Quantity::new(2500u32)), 65_536);
+
+        assert_eq!(
+            format!("{:?}", Converter::<U5000, U12>::new()),
+            "Converter<5000 mV, 12 bit>"
+        );
+    }
+}
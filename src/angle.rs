@@ -0,0 +1,281 @@
+use core::{
+    fmt,
+    ops::{Add, Neg, Rem, Sub},
+};
+
+use crate::{
+    units::{Degree, Radian},
+    Quantity, UnitTrait,
+};
+
+/// Units of plane angle: [`Radian`] and [`Degree`](crate::units::Degree).
+///
+/// Radian is dimensionless, so this trait is used to restrict functions of
+/// angles (like `sin`) to angles, other dimensionless quantities (e.g.
+/// [`Percent`](crate::units::Percent)) don't implement it.
+pub trait AngleUnit: UnitTrait + sealed::AngleUnit {}
+
+impl<U: UnitTrait + sealed::AngleUnit> AngleUnit for U {}
+
+mod sealed {
+    use crate::units::{Degree, Dimensionless};
+
+    pub trait AngleUnit {}
+
+    // `Radian` is the same type as `Dimensionless`, but its definition
+    // (`Metre / Metre`) can't be normalized by coherence checking
+    impl AngleUnit for Dimensionless {}
+
+    impl AngleUnit for Degree {}
+}
+
+/// Angle normalized to `[0, 2π)` radians, addition and subtraction wrap
+/// around the full turn.
+///
+/// Useful for headings and odometry where `350° + 20°` should be `10°`.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{angle::Angle, Quantity};
+///
+/// let heading = Angle::from_degrees(Quantity::new(350.0f64));
+/// let turned = heading + Angle::from_degrees(Quantity::new(20.0));
+/// assert!((turned.to_degrees().into_inner() - 10.0).abs() < 1e-9);
+///
+/// // The shortest way from 350° to 10° is 20° counterclockwise
+/// let delta = heading.shortest_distance_to(turned);
+/// assert!((delta.into_inner().to_degrees() - 20.0).abs() < 1e-9);
+/// ```
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Angle<S> {
+    /// In `[0, 2π)`
+    radians: S,
+}
+
+/// Floating point storage of [`Angle`] (`f32` and `f64`).
+pub trait AngleStorage:
+    Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + Rem<Output = Self>
+    + PartialOrd
+    + Copy
+{
+    /// `0`
+    const ZERO: Self;
+
+    /// `π`
+    const PI: Self;
+
+    /// `2π`
+    const TAU: Self;
+
+    /// Converts degrees to radians.
+    fn to_radians(self) -> Self;
+
+    /// Converts radians to degrees.
+    fn to_degrees(self) -> Self;
+}
+
+macro_rules! impls_angle_storage {
+    ($( $S:ident ),+) => {
+        $(
+            impl AngleStorage for $S {
+                const ZERO: Self = 0.0;
+                const PI: Self = core::$S::consts::PI;
+                const TAU: Self = 2.0 * core::$S::consts::PI;
+
+                #[inline]
+                fn to_radians(self) -> Self {
+                    $S::to_radians(self)
+                }
+
+                #[inline]
+                fn to_degrees(self) -> Self {
+                    $S::to_degrees(self)
+                }
+            }
+        )+
+    };
+}
+
+impls_angle_storage!(f32, f64);
+
+impl<S: AngleStorage> Angle<S> {
+    /// Creates new angle from radians, normalizing it to `[0, 2π)`.
+    #[inline]
+    pub fn new(radians: Quantity<S, Radian>) -> Self {
+        Self {
+            radians: wrap(radians.into_inner()),
+        }
+    }
+
+    /// Creates new angle from degrees, normalizing it to `[0, 360)`.
+    #[inline]
+    pub fn from_degrees(degrees: Quantity<S, Degree>) -> Self {
+        Self::new(Quantity::new(degrees.into_inner().to_radians()))
+    }
+
+    /// Returns the angle in `[0, 2π)` radians.
+    #[inline]
+    pub fn radians(self) -> Quantity<S, Radian> {
+        Quantity::new(self.radians)
+    }
+
+    /// Returns the angle in `[-π, π)` radians.
+    #[inline]
+    pub fn signed_radians(self) -> Quantity<S, Radian> {
+        let r = self.radians;
+        Quantity::new(if r >= S::PI { r - S::TAU } else { r })
+    }
+
+    /// Returns the angle in `[0, 360)` degrees.
+    #[inline]
+    pub fn to_degrees(self) -> Quantity<S, Degree> {
+        Quantity::new(self.radians.to_degrees())
+    }
+
+    /// Returns the signed shortest rotation from `self` to `other` in `[-π, π)`
+    /// radians (positive is counterclockwise).
+    #[inline]
+    pub fn shortest_distance_to(self, other: Self) -> Quantity<S, Radian> {
+        (other - self).signed_radians()
+    }
+}
+
+/// Normalizes `radians` to `[0, 2π)`
+fn wrap<S: AngleStorage>(radians: S) -> S {
+    let r = radians % S::TAU;
+    let r = if r < S::ZERO { r + S::TAU } else { r };
+    // `r + TAU` can round up to `TAU`
+    if r >= S::TAU {
+        S::ZERO
+    } else {
+        r
+    }
+}
+
+impl<S: AngleStorage> Add for Angle<S> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            radians: wrap(self.radians + rhs.radians),
+        }
+    }
+}
+
+impl<S: AngleStorage> Sub for Angle<S> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            radians: wrap(self.radians - rhs.radians),
+        }
+    }
+}
+
+impl<S: AngleStorage> Add<Quantity<S, Radian>> for Angle<S> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Quantity<S, Radian>) -> Self::Output {
+        Self::new(Quantity::new(self.radians + rhs.into_inner()))
+    }
+}
+
+impl<S: AngleStorage> Sub<Quantity<S, Radian>> for Angle<S> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Quantity<S, Radian>) -> Self::Output {
+        Self::new(Quantity::new(self.radians - rhs.into_inner()))
+    }
+}
+
+impl<S: AngleStorage> Neg for Angle<S> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            radians: wrap(-self.radians),
+        }
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Angle<S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Angle").field(&self.radians).finish()
+    }
+}
+
+impl<S: AngleStorage> From<Quantity<S, Radian>> for Angle<S> {
+    #[inline]
+    fn from(radians: Quantity<S, Radian>) -> Self {
+        Self::new(radians)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+
+    use super::Angle;
+    use crate::{
+        units::{Degree, Radian},
+        Quantity,
+    };
+
+    type A = Angle<f64>;
+
+    fn rad(x: f64) -> Quantity<f64, Radian> {
+        Quantity::new(x)
+    }
+
+    fn deg(x: f64) -> Quantity<f64, Degree> {
+        Quantity::new(x)
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn normalize() {
+        assert_close(A::new(rad(3.0 * PI)).radians().into_inner(), PI);
+        assert_close(A::new(rad(-PI / 2.0)).radians().into_inner(), 1.5 * PI);
+        assert_eq!(A::new(rad(2.0 * PI)).radians(), rad(0.0));
+        assert_eq!(A::new(rad(-1e-20)).radians(), rad(0.0));
+        assert_close(A::from_degrees(deg(-90.0)).to_degrees().into_inner(), 270.0);
+
+        assert_close(
+            A::new(rad(1.5 * PI)).signed_radians().into_inner(),
+            -PI / 2.0,
+        );
+        assert_close(A::new(rad(PI)).signed_radians().into_inner(), -PI);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = A::from_degrees(deg(10.0));
+        let b = A::from_degrees(deg(350.0));
+
+        assert_close((a - b).to_degrees().into_inner(), 20.0);
+        assert_close((b - a).to_degrees().into_inner(), 340.0);
+        assert_close((-a).to_degrees().into_inner(), 350.0);
+        assert_close((a + rad(2.0 * PI)).to_degrees().into_inner(), 10.0);
+
+        assert_close(a.shortest_distance_to(b).into_inner().to_degrees(), -20.0);
+        assert_close(b.shortest_distance_to(a).into_inner().to_degrees(), 20.0);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", A::new(rad(1.5))), "Angle(1.5)");
+    }
+}
@@ -0,0 +1,256 @@
+use core::{fmt, marker::PhantomData, sync::atomic::Ordering};
+
+use crate::Quantity;
+
+/// Atomic integer type that can be used as a storage of [`AtomicQuantity`].
+///
+/// This trait is implemented for atomic integers from [`core::sync::atomic`].
+pub trait AtomicStorage {
+    /// Non-atomic integer type that is stored in this atomic
+    type Storage;
+
+    /// Creates new atomic integer
+    fn new(value: Self::Storage) -> Self;
+
+    /// Consumes the atomic and returns the contained value
+    fn into_inner(self) -> Self::Storage;
+
+    /// Loads a value from the atomic integer
+    fn load(&self, order: Ordering) -> Self::Storage;
+
+    /// Stores a value into the atomic integer
+    fn store(&self, value: Self::Storage, order: Ordering);
+
+    /// Stores a value into the atomic integer, returning the previous value
+    fn swap(&self, value: Self::Storage, order: Ordering) -> Self::Storage;
+
+    /// Adds to the current value, returning the previous value
+    fn fetch_add(&self, value: Self::Storage, order: Ordering) -> Self::Storage;
+
+    /// Subtracts from the current value, returning the previous value
+    fn fetch_sub(&self, value: Self::Storage, order: Ordering) -> Self::Storage;
+}
+
+macro_rules! atomic_impls {
+    ($( $size:literal: $Atomic:ident => $Int:ty ),+ $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $size)]
+            impl AtomicStorage for core::sync::atomic::$Atomic {
+                type Storage = $Int;
+
+                #[inline]
+                fn new(value: $Int) -> Self {
+                    Self::new(value)
+                }
+
+                #[inline]
+                fn into_inner(self) -> $Int {
+                    self.into_inner()
+                }
+
+                #[inline]
+                fn load(&self, order: Ordering) -> $Int {
+                    self.load(order)
+                }
+
+                #[inline]
+                fn store(&self, value: $Int, order: Ordering) {
+                    self.store(value, order)
+                }
+
+                #[inline]
+                fn swap(&self, value: $Int, order: Ordering) -> $Int {
+                    self.swap(value, order)
+                }
+
+                #[inline]
+                fn fetch_add(&self, value: $Int, order: Ordering) -> $Int {
+                    self.fetch_add(value, order)
+                }
+
+                #[inline]
+                fn fetch_sub(&self, value: $Int, order: Ordering) -> $Int {
+                    self.fetch_sub(value, order)
+                }
+            }
+        )+
+    };
+}
+
+atomic_impls! {
+    "8": AtomicU8 => u8,
+    "16": AtomicU16 => u16,
+    "32": AtomicU32 => u32,
+    "64": AtomicU64 => u64,
+    "ptr": AtomicUsize => usize,
+    "8": AtomicI8 => i8,
+    "16": AtomicI16 => i16,
+    "32": AtomicI32 => i32,
+    "64": AtomicI64 => i64,
+    "ptr": AtomicIsize => isize,
+}
+
+/// Quantity of unit `U` that is stored in atomic integer `A`.
+///
+/// This is useful for sharing state between e.g. interrupt handlers and main
+/// loop, without losing the unit. All methods take and return [`Quantity`]s.
+///
+/// Note: wrapping behaviour on overflow of `fetch_add`/`fetch_sub` is the same
+/// as of the underlying atomic.
+///
+/// ## Examples
+///
+/// ```
+/// use core::sync::atomic::{AtomicI32, Ordering};
+///
+/// use typed_phy::{atomic::AtomicQuantity, units::Metre, IntExt};
+///
+/// static DISTANCE: AtomicQuantity<AtomicI32, Metre> =
+///     AtomicQuantity::from_atomic(AtomicI32::new(0));
+///
+/// DISTANCE.store(10.m(), Ordering::Relaxed);
+/// assert_eq!(DISTANCE.fetch_add(5.m(), Ordering::Relaxed), 10.m());
+/// assert_eq!(DISTANCE.load(Ordering::Relaxed), 15.m());
+/// ```
+pub struct AtomicQuantity<A, U> {
+    inner: A,
+    _unit: PhantomData<U>,
+}
+
+impl<A, U> AtomicQuantity<A, U> {
+    /// Creates new atomic quantity from the given atomic integer.
+    ///
+    /// Unlike [`new`](AtomicQuantity::new) this function is `const`, so it can
+    /// be used to initialize `static`s.
+    #[inline]
+    pub const fn from_atomic(inner: A) -> Self {
+        Self {
+            inner,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<A, U> AtomicQuantity<A, U>
+where
+    A: AtomicStorage,
+{
+    /// Creates new atomic quantity.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// use typed_phy::{atomic::AtomicQuantity, IntExt};
+    ///
+    /// let it = AtomicQuantity::<AtomicU32, _>::new(10.s());
+    /// assert_eq!(it.load(Ordering::SeqCst), 10.s());
+    /// ```
+    #[inline]
+    pub fn new(quantity: Quantity<A::Storage, U>) -> Self {
+        Self::from_atomic(A::new(quantity.into_inner()))
+    }
+
+    /// Consumes the atomic and returns the contained quantity.
+    #[inline]
+    pub fn into_inner(self) -> Quantity<A::Storage, U> {
+        Quantity::new(self.inner.into_inner())
+    }
+
+    /// Loads a quantity from the atomic.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> Quantity<A::Storage, U> {
+        Quantity::new(self.inner.load(order))
+    }
+
+    /// Stores a quantity into the atomic.
+    #[inline]
+    pub fn store(&self, quantity: Quantity<A::Storage, U>, order: Ordering) {
+        self.inner.store(quantity.into_inner(), order)
+    }
+
+    /// Stores a quantity into the atomic, returning the previous quantity.
+    #[inline]
+    pub fn swap(
+        &self,
+        quantity: Quantity<A::Storage, U>,
+        order: Ordering,
+    ) -> Quantity<A::Storage, U> {
+        Quantity::new(self.inner.swap(quantity.into_inner(), order))
+    }
+
+    /// Adds to the current quantity, returning the previous quantity.
+    #[inline]
+    pub fn fetch_add(
+        &self,
+        quantity: Quantity<A::Storage, U>,
+        order: Ordering,
+    ) -> Quantity<A::Storage, U> {
+        Quantity::new(self.inner.fetch_add(quantity.into_inner(), order))
+    }
+
+    /// Subtracts from the current quantity, returning the previous quantity.
+    #[inline]
+    pub fn fetch_sub(
+        &self,
+        quantity: Quantity<A::Storage, U>,
+        order: Ordering,
+    ) -> Quantity<A::Storage, U> {
+        Quantity::new(self.inner.fetch_sub(quantity.into_inner(), order))
+    }
+}
+
+impl<A, U> Default for AtomicQuantity<A, U>
+where
+    A: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::from_atomic(A::default())
+    }
+}
+
+/// Formats the quantity loaded with [`Ordering::Relaxed`], like atomics from
+/// [`core::sync::atomic`] do.
+impl<A, U> fmt::Debug for AtomicQuantity<A, U>
+where
+    A: AtomicStorage,
+    Quantity<A::Storage, U>: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicU16, AtomicU8, Ordering};
+
+    use super::AtomicQuantity;
+    use crate::{units::Second, IntExt};
+
+    #[test]
+    fn ops() {
+        let it = AtomicQuantity::<AtomicU16, Second>::new(10.s());
+
+        assert_eq!(it.swap(20.s(), Ordering::SeqCst), 10.s());
+        assert_eq!(it.fetch_sub(5.s(), Ordering::SeqCst), 20.s());
+        assert_eq!(it.fetch_add(1.s(), Ordering::SeqCst), 15.s());
+        assert_eq!(it.into_inner(), 16.s());
+    }
+
+    #[test]
+    fn wrapping() {
+        let it = AtomicQuantity::<AtomicU8, Second>::new(255.s());
+        it.fetch_add(2.s(), Ordering::SeqCst);
+        assert_eq!(it.load(Ordering::SeqCst), 1.s());
+    }
+
+    #[test]
+    fn debug() {
+        let it = AtomicQuantity::<AtomicU8, Second>::new(3.s());
+        assert_eq!(format!("{:?}", it), format!("{:?}", 3u8.s()));
+    }
+}
@@ -0,0 +1,396 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    marker::PhantomData,
+    ops::{Add, Sub},
+};
+
+use typenum::{IsLessOrEqual, NInt, NonZero, PInt, True, Unsigned, U0, Z0};
+
+use crate::{
+    checked::{CheckedAdd, CheckedSub, SaturatingAdd, SaturatingSub},
+    from_int::{FromInteger, FromUnsigned},
+    Quantity, UnitTrait,
+};
+
+/// Compile-time limit of a [`Bounded`] quantity.
+///
+/// Implemented for [`typenum`] integers (e.g. `Z0`, `P1`, `N273`) and for
+/// [`Unbounded`].
+///
+/// [`typenum`]: https://docs.rs/typenum
+pub trait Bound<S> {
+    /// Returns value of the limit or `None` if there is no limit.
+    fn get() -> Option<S>;
+}
+
+/// No limit (e.g. there is no upper limit for temperature).
+pub struct Unbounded;
+
+impl<S> Bound<S> for Unbounded {
+    #[inline]
+    fn get() -> Option<S> {
+        None
+    }
+}
+
+// Non-negative limits use `FromUnsigned` to support unsigned storages
+impl<S: FromUnsigned> Bound<S> for Z0 {
+    #[inline]
+    fn get() -> Option<S> {
+        Some(S::from_unsigned::<U0>())
+    }
+}
+
+impl<S: FromUnsigned, U: Unsigned + NonZero> Bound<S> for PInt<U> {
+    #[inline]
+    fn get() -> Option<S> {
+        Some(S::from_unsigned::<U>())
+    }
+}
+
+impl<S: FromInteger, U: Unsigned + NonZero> Bound<S> for NInt<U> {
+    #[inline]
+    fn get() -> Option<S> {
+        Some(S::from_integer::<Self>())
+    }
+}
+
+/// Compile-time check that the lower limit `Self` isn't greater than the
+/// upper limit `Max` of a [`Bounded`] quantity.
+pub trait BoundLe<Max> {}
+
+impl<Max> BoundLe<Max> for Unbounded {}
+
+macro_rules! bound_le_impls {
+    ($( [$( $gen:ident ),*] $Min:ty ),+) => {
+        $(
+            impl<$( $gen: Unsigned + NonZero, )*> BoundLe<Unbounded> for $Min {}
+
+            impl<$( $gen: Unsigned + NonZero, )*> BoundLe<Z0> for $Min
+            where
+                $Min: IsLessOrEqual<Z0, Output = True>,
+            {
+            }
+
+            impl<$( $gen: Unsigned + NonZero, )* V: Unsigned + NonZero> BoundLe<PInt<V>> for $Min
+            where
+                $Min: IsLessOrEqual<PInt<V>, Output = True>,
+            {
+            }
+
+            impl<$( $gen: Unsigned + NonZero, )* V: Unsigned + NonZero> BoundLe<NInt<V>> for $Min
+            where
+                $Min: IsLessOrEqual<NInt<V>, Output = True>,
+            {
+            }
+        )+
+    };
+}
+
+bound_le_impls!([] Z0, [U] PInt<U>, [U] NInt<U>);
+
+/// Quantity that is guaranteed to be in `MIN..=MAX` range (limits are in
+/// units of `Q`).
+///
+/// The range is checked on construction, arithmetic (`+`/`-`) clamps the
+/// result to the range (even if the storage overflows), `checked_*` methods
+/// return `None` instead.
+///
+/// `MIN` must not be greater than `MAX`:
+///
+/// ```compile_fail,E0277
+/// use typed_phy::{bounded::Bounded, units::Metre, IntExt, Quantity};
+/// use typenum::{P1, P5};
+///
+/// Bounded::<Quantity<i32, Metre>, P5, P1>::new(3.m());
+/// ```
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     bounded::{Bounded, Unbounded},
+///     units::{Dimensionless, Kelvin},
+///     IntExt, Quantity,
+/// };
+/// use typenum::{P1, Z0};
+///
+/// type DutyCycle = Bounded<Quantity<f32, Dimensionless>, Z0, P1>;
+///
+/// let duty = DutyCycle::new(0.75.dimensionless()).unwrap();
+/// assert_eq!(DutyCycle::new(1.5.dimensionless()), None);
+///
+/// // Clamps to the range
+/// assert_eq!((duty + duty).get(), 1.0.dimensionless());
+/// assert_eq!(duty.checked_add(duty), None);
+///
+/// // Temperature can't be below absolute zero
+/// type Temperature = Bounded<Quantity<f64, Kelvin>, Z0, Unbounded>;
+/// assert!(Temperature::new(Quantity::new(-1.0)).is_none());
+/// assert_eq!(
+///     Temperature::new_clamped(Quantity::new(-1.0)).get(),
+///     Quantity::new(0.0)
+/// );
+/// ```
+pub struct Bounded<Q, MIN, MAX> {
+    value: Q,
+    _bounds: PhantomData<(MIN, MAX)>,
+}
+
+impl<S, U, MIN, MAX> Bounded<Quantity<S, U>, MIN, MAX>
+where
+    U: UnitTrait,
+    MIN: Bound<S> + BoundLe<MAX>,
+    MAX: Bound<S>,
+{
+    /// Creates new bounded quantity or returns `None` if `value` is out of
+    /// the range (or it's `NaN`).
+    #[inline]
+    pub fn new(value: Quantity<S, U>) -> Option<Self>
+    where
+        S: PartialOrd,
+    {
+        let v = value.into_inner();
+        let in_range = MIN::get().into_iter().all(|min| v >= min)
+            && MAX::get().into_iter().all(|max| v <= max);
+
+        if in_range {
+            Some(Self::new_unchecked(Quantity::new(v)))
+        } else {
+            None
+        }
+    }
+
+    /// Creates new bounded quantity clamping `value` to the range.
+    ///
+    /// Note: `NaN` is clamped to `MIN` (or `MAX` if there is no lower limit).
+    #[inline]
+    pub fn new_clamped(value: Quantity<S, U>) -> Self
+    where
+        S: PartialOrd,
+    {
+        let mut v = value.into_inner();
+        if let Some(min) = MIN::get() {
+            if !matches!(
+                v.partial_cmp(&min),
+                Some(Ordering::Greater | Ordering::Equal)
+            ) {
+                v = min;
+            }
+        }
+        if let Some(max) = MAX::get() {
+            if !matches!(v.partial_cmp(&max), Some(Ordering::Less | Ordering::Equal)) {
+                v = max;
+            }
+        }
+
+        Self::new_unchecked(Quantity::new(v))
+    }
+
+    #[inline]
+    fn new_unchecked(value: Quantity<S, U>) -> Self {
+        Self {
+            value,
+            _bounds: PhantomData,
+        }
+    }
+
+    /// Returns the lower limit or `None` if there is no limit.
+    #[inline]
+    pub fn min() -> Option<Quantity<S, U>> {
+        MIN::get().map(Quantity::new)
+    }
+
+    /// Returns the upper limit or `None` if there is no limit.
+    #[inline]
+    pub fn max() -> Option<Quantity<S, U>> {
+        MAX::get().map(Quantity::new)
+    }
+
+    /// Returns the inner quantity.
+    #[inline]
+    pub fn get(self) -> Quantity<S, U> {
+        self.value
+    }
+
+    /// Adds two bounded quantities, returns `None` if the result is out of
+    /// the range.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self>
+    where
+        S: CheckedAdd<Output = S> + PartialOrd,
+    {
+        let v = self
+            .value
+            .into_inner()
+            .checked_add(rhs.value.into_inner())?;
+        Self::new(Quantity::new(v))
+    }
+
+    /// Subtracts two bounded quantities, returns `None` if the result is out
+    /// of the range.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self>
+    where
+        S: CheckedSub<Output = S> + PartialOrd,
+    {
+        let v = self
+            .value
+            .into_inner()
+            .checked_sub(rhs.value.into_inner())?;
+        Self::new(Quantity::new(v))
+    }
+}
+
+/// Adds two bounded quantities, clamping the result to the range.
+impl<S, U, MIN, MAX> Add for Bounded<Quantity<S, U>, MIN, MAX>
+where
+    S: SaturatingAdd<Output = S> + PartialOrd,
+    U: UnitTrait,
+    MIN: Bound<S> + BoundLe<MAX>,
+    MAX: Bound<S>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        let v = self
+            .value
+            .into_inner()
+            .saturating_add(rhs.value.into_inner());
+        Self::new_clamped(Quantity::new(v))
+    }
+}
+
+/// Subtracts two bounded quantities, clamping the result to the range.
+impl<S, U, MIN, MAX> Sub for Bounded<Quantity<S, U>, MIN, MAX>
+where
+    S: SaturatingSub<Output = S> + PartialOrd,
+    U: UnitTrait,
+    MIN: Bound<S> + BoundLe<MAX>,
+    MAX: Bound<S>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        let v = self
+            .value
+            .into_inner()
+            .saturating_sub(rhs.value.into_inner());
+        Self::new_clamped(Quantity::new(v))
+    }
+}
+
+impl<Q: Clone, MIN, MAX> Clone for Bounded<Q, MIN, MAX> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            _bounds: PhantomData,
+        }
+    }
+}
+
+impl<Q: Copy, MIN, MAX> Copy for Bounded<Q, MIN, MAX> {}
+
+impl<Q: PartialEq, MIN, MAX> PartialEq for Bounded<Q, MIN, MAX> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<Q: PartialOrd, MIN, MAX> PartialOrd for Bounded<Q, MIN, MAX> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<Q: fmt::Debug, MIN, MAX> fmt::Debug for Bounded<Q, MIN, MAX> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl<Q: fmt::Display, MIN, MAX> fmt::Display for Bounded<Q, MIN, MAX> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typenum::{N10, P1, P100, P200, Z0};
+
+    use super::{Bounded, Unbounded};
+    use crate::{
+        units::{Dimensionless, Metre},
+        IntExt, Quantity,
+    };
+
+    #[test]
+    fn new() {
+        type B = Bounded<Quantity<i32, Metre>, N10, P100>;
+
+        assert_eq!(B::new(5.m()).map(B::get), Some(5.m()));
+        assert_eq!(B::new((-10).m()).map(B::get), Some((-10).m()));
+        assert_eq!(B::new(100.m()).map(B::get), Some(100.m()));
+        assert_eq!(B::new((-11).m()), None);
+        assert_eq!(B::new(101.m()), None);
+
+        assert_eq!(B::new_clamped((-50).m()).get(), (-10).m());
+        assert_eq!(B::new_clamped(500.m()).get(), 100.m());
+
+        assert_eq!(B::min(), Some((-10).m()));
+        assert_eq!(B::max(), Some(100.m()));
+        assert_eq!(Bounded::<Quantity<i32, Metre>, Z0, Unbounded>::max(), None);
+    }
+
+    #[test]
+    fn nan() {
+        type B = Bounded<Quantity<f64, Dimensionless>, Z0, P1>;
+
+        assert_eq!(B::new(f64::NAN.dimensionless()), None);
+        assert_eq!(
+            B::new_clamped(f64::NAN.dimensionless()).get(),
+            0.0.dimensionless()
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        type B = Bounded<Quantity<i32, Metre>, Z0, P100>;
+
+        let a = B::new(70.m()).unwrap();
+        let b = B::new(40.m()).unwrap();
+
+        assert_eq!((a + b).get(), 100.m());
+        assert_eq!((b - a).get(), 0.m());
+        assert_eq!((a - b).get(), 30.m());
+
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(b.checked_sub(a), None);
+        assert_eq!(a.checked_sub(b).map(B::get), Some(30.m()));
+        assert!(a > b);
+    }
+
+    #[test]
+    fn storage_overflow() {
+        type B = Bounded<Quantity<u8, Metre>, Z0, P200>;
+
+        let a = B::new(40u8.m()).unwrap();
+        let b = B::new(70u8.m()).unwrap();
+        let c = B::new(150u8.m()).unwrap();
+
+        assert_eq!((a - b).get(), 0u8.m());
+        assert_eq!(a.checked_sub(b), None);
+
+        assert_eq!((c + c).get(), 200u8.m());
+        assert_eq!(c.checked_add(c), None);
+    }
+}
@@ -1,11 +1,17 @@
-//! Traits for checked operations similar to [`core::ops`]'s.
+//! Traits for checked (and wrapping/saturating) operations similar to
+//! [`core::ops`]'s.
 //! We can't use [`num`]'s `Checked*` traits because they assume `Rhs` and
 //! `Output` to equal `Self`.
 //!
 //! [`core::ops`]: core::ops
 //! [`num`]: https://rust-num.github.io/num/num_traits/ops/checked/index.html
 
-use core::ops::{Add, Div, Mul, Sub};
+use core::{
+    convert::TryFrom,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::from_int::Zero;
 
 /// Performs addition that returns `None` on underflow or overflow.
 pub trait CheckedAdd<Rhs = Self>: Add<Rhs> {
@@ -40,6 +46,68 @@ pub trait CheckedDiv<Rhs = Self>: Div<Rhs> {
     fn checked_div(self, rhs: Rhs) -> Option<Self::Output>;
 }
 
+/// Performs addition that wraps around on overflow.
+pub trait WrappingAdd<Rhs = Self>: Add<Rhs> {
+    /// Adds two numbers, wrapping around at the boundary of the type.
+    #[must_use]
+    fn wrapping_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Performs subtraction that wraps around on overflow.
+pub trait WrappingSub<Rhs = Self>: Sub<Rhs> {
+    /// Subtracts two numbers, wrapping around at the boundary of the type.
+    #[must_use]
+    fn wrapping_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Performs addition that saturates at the numeric bounds instead of
+/// overflowing.
+pub trait SaturatingAdd<Rhs = Self>: Add<Rhs> {
+    /// Adds two numbers, saturating at the numeric bounds.
+    #[must_use]
+    fn saturating_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Performs subtraction that saturates at the numeric bounds instead of
+/// overflowing.
+pub trait SaturatingSub<Rhs = Self>: Sub<Rhs> {
+    /// Subtracts two numbers, saturating at the numeric bounds.
+    #[must_use]
+    fn saturating_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Performs negation that returns `None` on overflow (e.g. `-i32::MIN`, or
+/// negating a non-zero unsigned integer).
+pub trait CheckedNeg: Sized {
+    /// Negates the number, returns `None` if the result isn't representable.
+    #[must_use]
+    fn checked_neg(self) -> Option<Self>;
+}
+
+/// Performs negation that wraps around on overflow.
+pub trait WrappingNeg: Sized {
+    /// Negates the number, wrapping around at the boundary of the type.
+    #[must_use]
+    fn wrapping_neg(self) -> Self;
+}
+
+/// Conversion that saturates at the numeric bounds of `Self` instead of
+/// failing (e.g. `300i32` becomes `255u8` and `-1i32` becomes `0u8`).
+pub trait SaturatingFrom<T>: Sized {
+    /// Converts `value`, clamping it to the range of `Self`.
+    #[must_use]
+    fn saturating_from(value: T) -> Self;
+}
+
+/// Conversion that returns `None` if the value doesn't fit into `Self`
+/// (floats accept any integer, rounding it to the nearest representable
+/// value).
+pub trait CheckedFrom<T>: Sized {
+    /// Converts `value`, returns `None` if it's out of the range of `Self`.
+    #[must_use]
+    fn checked_from(value: T) -> Option<Self>;
+}
+
 macro_rules! checked_impls {
     (impl $trait_name:ident by $method:ident for $( $t:ty ),+) => {
         $(
@@ -53,7 +121,142 @@ macro_rules! checked_impls {
     }
 }
 
+macro_rules! infallible_impls {
+    (impl $trait_name:ident by $method:ident for $( $t:ty ),+) => {
+        $(
+            impl $trait_name for $t {
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    Self::$method(self, rhs)
+                }
+            }
+        )+
+    }
+}
+
 checked_impls!(impl CheckedAdd by checked_add for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 checked_impls!(impl CheckedSub by checked_sub for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 checked_impls!(impl CheckedMul by checked_mul for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 checked_impls!(impl CheckedDiv by checked_div for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+infallible_impls!(impl WrappingAdd by wrapping_add for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+infallible_impls!(impl WrappingSub by wrapping_sub for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+infallible_impls!(impl SaturatingAdd by saturating_add for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+infallible_impls!(impl SaturatingSub by saturating_sub for u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// Floats don't overflow (they become infinite instead), so their checked
+// operations always succeed and saturating operations are the plain ones
+macro_rules! float_impls {
+    ($( $t:ty ),+) => {
+        $(
+            impl CheckedAdd for $t {
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    Some(self + rhs)
+                }
+            }
+
+            impl CheckedSub for $t {
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    Some(self - rhs)
+                }
+            }
+
+            impl SaturatingAdd for $t {
+                #[inline]
+                fn saturating_add(self, rhs: Self) -> Self {
+                    self + rhs
+                }
+            }
+
+            impl SaturatingSub for $t {
+                #[inline]
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    self - rhs
+                }
+            }
+        )+
+    };
+}
+
+float_impls!(f32, f64);
+
+macro_rules! neg_impls {
+    ($( $t:ty ),+) => {
+        $(
+            impl CheckedNeg for $t {
+                #[inline]
+                fn checked_neg(self) -> Option<Self> {
+                    Self::checked_neg(self)
+                }
+            }
+
+            impl WrappingNeg for $t {
+                #[inline]
+                fn wrapping_neg(self) -> Self {
+                    Self::wrapping_neg(self)
+                }
+            }
+        )+
+    }
+}
+
+neg_impls!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! saturating_from_impls {
+    ($( $t:ty ),+) => {
+        $(
+            impl<T> SaturatingFrom<T> for $t
+            where
+                $t: TryFrom<T>,
+                T: Zero + PartialOrd + Copy,
+            {
+                #[inline]
+                fn saturating_from(value: T) -> Self {
+                    // Conversion between integers can only fail if the value is out of range
+                    <$t>::try_from(value).unwrap_or(if value < T::zero() {
+                        <$t>::MIN
+                    } else {
+                        <$t>::MAX
+                    })
+                }
+            }
+        )+
+    }
+}
+
+saturating_from_impls!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! checked_from_impls {
+    (int: $( $t:ty ),+; float: $( $f:ty ),+ $(,)?) => {
+        $(
+            impl<T> CheckedFrom<T> for $t
+            where
+                $t: TryFrom<T>,
+            {
+                #[inline]
+                fn checked_from(value: T) -> Option<Self> {
+                    <$t>::try_from(value).ok()
+                }
+            }
+        )+
+        $(
+            checked_from_impls!(@float $f: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        )+
+    };
+    (@float $f:ty: $( $t:ty ),+) => {
+        $(
+            impl CheckedFrom<$t> for $f {
+                #[inline]
+                fn checked_from(value: $t) -> Option<Self> {
+                    Some(value as $f)
+                }
+            }
+        )+
+    };
+}
+
+checked_from_impls! {
+    int: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    float: f32, f64,
+}
@@ -0,0 +1,101 @@
+//! Complex-valued quantities for AC analysis (requires `complex` feature).
+//!
+//! All arithmetic of [`Quantity`] works for [`Complex`] storage as-is, so
+//! phasor voltages, currents and impedances multiply and divide with correct
+//! units. This module adds helpers to split complex quantities into real ones.
+//!
+//! ## Examples
+//!
+//! ```
+//! use typed_phy::{
+//!     complex::Complex,
+//!     units::{Ampere, Ohm, Volt},
+//!     Quantity,
+//! };
+//!
+//! let impedance = Quantity::<_, Ohm>::new(Complex::new(3.0, 4.0));
+//! let current = Quantity::<_, Ampere>::new(Complex::new(2.0, 0.0));
+//!
+//! let voltage: Quantity<Complex<f64>, Volt> = impedance * current;
+//! assert_eq!(voltage.re(), Quantity::new(6.0));
+//! assert_eq!(voltage.im(), Quantity::new(8.0));
+//! # #[cfg(feature = "float-math")]
+//! assert_eq!(voltage.abs(), Quantity::<f64, Volt>::new(10.0));
+//!
+//! assert_eq!(voltage / impedance, current);
+//! ```
+use core::ops::{Add, Mul, Neg};
+
+pub use num_complex::Complex;
+
+use crate::{from_int::Sqrt, Quantity, UnitTrait};
+
+impl<T, U> Quantity<Complex<T>, U>
+where
+    U: UnitTrait,
+{
+    /// Creates complex quantity from real and imaginary parts.
+    #[inline]
+    pub fn from_parts(re: Quantity<T, U>, im: Quantity<T, U>) -> Self {
+        Self::new(Complex::new(re.into_inner(), im.into_inner()))
+    }
+
+    /// Returns real part of the quantity.
+    #[inline]
+    pub fn re(self) -> Quantity<T, U> {
+        Quantity::new(self.into_inner().re)
+    }
+
+    /// Returns imaginary part of the quantity.
+    #[inline]
+    pub fn im(self) -> Quantity<T, U> {
+        Quantity::new(self.into_inner().im)
+    }
+
+    /// Returns magnitude (`sqrt(re² + im²)`) of the quantity (`Sqrt` is
+    /// implemented for floats with `float-math` feature).
+    #[inline]
+    pub fn abs(self) -> Quantity<T, U>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Sqrt + Copy,
+    {
+        let Complex { re, im } = self.into_inner();
+        Quantity::new((re * re + im * im).sqrt())
+    }
+
+    /// Returns complex conjugate of the quantity.
+    #[inline]
+    pub fn conj(self) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        let Complex { re, im } = self.into_inner();
+        Self::new(Complex::new(re, -im))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Complex;
+    use crate::{
+        units::{Ampere, Ohm, Volt, Watt},
+        Quantity,
+    };
+
+    #[test]
+    fn phasors() {
+        let z = Quantity::<_, Ohm>::from_parts(Quantity::new(0.0), Quantity::new(2.0));
+        let i = Quantity::<_, Ampere>::new(Complex::new(1.0, 1.0));
+
+        let v: Quantity<Complex<f64>, Volt> = z * i;
+        assert_eq!(v, Quantity::new(Complex::new(-2.0, 2.0)));
+        assert_eq!(v / i, z);
+        assert_eq!(v / z, i);
+
+        // complex power
+        let s: Quantity<Complex<f64>, Watt> = v * i.conj();
+        assert_eq!(s, Quantity::new(Complex::new(0.0, 4.0)));
+        #[cfg(feature = "float-math")]
+        assert_eq!(s.abs(), Quantity::new(4.0));
+    }
+}
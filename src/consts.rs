@@ -0,0 +1,100 @@
+//! Mathematical constants as dimensionless quantities.
+//!
+//! Multiplying a quantity by a raw float goes through the scalar `Mul<S>`
+//! path, these constants are quantities, so they take part in unit
+//! arithmetic like any other dimensionless quantity.
+//!
+//! ## Examples
+//!
+//! ```
+//! use typed_phy::{consts, IntExt};
+//!
+//! let r = 2.0.m();
+//! let circumference = consts::TAU::<f64>() * r;
+//! assert_eq!(circumference, (2.0 * core::f64::consts::TAU).m());
+//!
+//! let area = consts::PI::<f64>() * r * r;
+//! assert!((area - 12.566_370.sqm()).into_inner().abs() < 1e-6);
+//! ```
+#![allow(non_snake_case)]
+
+use crate::{units::Dimensionless, Quantity};
+
+/// Float type that has mathematical constants.
+pub trait FloatConsts {
+    /// Archimedes' constant (`π`)
+    const PI: Self;
+    /// The full circle constant (`τ = 2π`)
+    const TAU: Self;
+    /// Euler's number (`e`)
+    const E: Self;
+    /// The golden ratio (`φ`)
+    const PHI: Self;
+    /// `√2`
+    const SQRT_2: Self;
+    /// `ln(2)`
+    const LN_2: Self;
+    /// `ln(10)`
+    const LN_10: Self;
+}
+
+macro_rules! float_consts_impls {
+    ($( $F:ident ),+) => {
+        $(
+            impl FloatConsts for $F {
+                const PI: Self = core::$F::consts::PI;
+                const TAU: Self = 2.0 * core::$F::consts::PI;
+                const E: Self = core::$F::consts::E;
+                const PHI: Self = 1.618_033_988_749_894_848_204_586_834_365_638_118;
+                const SQRT_2: Self = core::$F::consts::SQRT_2;
+                const LN_2: Self = core::$F::consts::LN_2;
+                const LN_10: Self = core::$F::consts::LN_10;
+            }
+        )+
+    };
+}
+
+float_consts_impls!(f32, f64);
+
+macro_rules! consts {
+    ($( $(#[$attr:meta])* $name:ident ),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            #[inline]
+            pub const fn $name<S: FloatConsts>() -> Quantity<S, Dimensionless> {
+                Quantity::new(S::$name)
+            }
+        )+
+    };
+}
+
+consts! {
+    /// Archimedes' constant (`π`)
+    PI,
+    /// The full circle constant (`τ = 2π`)
+    TAU,
+    /// Euler's number (`e`)
+    E,
+    /// The golden ratio (`φ = (1 + √5) / 2`)
+    PHI,
+    /// `√2`
+    SQRT_2,
+    /// `ln(2)`
+    LN_2,
+    /// `ln(10)`
+    LN_10,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{E, PHI, PI};
+    use crate::IntExt;
+
+    #[test]
+    fn consts() {
+        assert_eq!(PI::<f64>() * 1.0.m(), core::f64::consts::PI.m());
+        assert_eq!(E::<f32>().into_inner(), core::f32::consts::E);
+        let phi = PHI::<f64>();
+        assert!((phi * phi - (phi + 1.0.dimensionless())).into_inner().abs() < 1e-15);
+    }
+}
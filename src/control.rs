@@ -0,0 +1,521 @@
+//! Controllers with dimension-checked gains (requires `control` feature).
+//!
+//! Gains are typed by the units of the controller input (`In`), output
+//! (`Out`) and time (`T`), so e.g. an integral gain can't be passed as a
+//! proportional one and gains tuned for millimetres can't be used with
+//! metres:
+//!
+//! ```compile_fail
+//! use typed_phy::{
+//!     control::Pid,
+//!     units::{Metre, Second, Volt},
+//!     IntExt, Unit,
+//! };
+//!
+//! let gain = 2.0.quantity::<Unit![Volt / Metre]>();
+//! // `gain` is used as the integral gain, which must be in `V/(m·s)`
+//! let _ = Pid::<_, Metre, Volt, Second>::new(gain, gain, gain);
+//! ```
+//!
+//! The same goes for coefficients of state-space models ([`StateSpace1`],
+//! [`StateSpace2`]), where units of every coefficient are derived from the
+//! units of the input, the output and the states.
+use core::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use typenum::{Prod, Quot};
+
+use crate::{eq::UnitEq, from_int::Zero, units::Second, Quantity, UnitTrait};
+
+/// PID controller, turns an error of unit `In` into a control value of unit
+/// `Out` (`T` is the unit of time steps).
+///
+/// Gains have units:
+/// - `Kp`: `Out / In`
+/// - `Ki`: `Out / (In · T)`
+/// - `Kd`: `Out · T / In`
+///
+/// Since units of gains must be equal (not only have the same dimensions) to
+/// the expected ones, no conversions (and so no truncation) are made.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     control::Pid,
+///     units::{Metre, Second, Volt},
+///     IntExt, Unit,
+/// };
+///
+/// let mut pid = Pid::<f64, Metre, Volt, Second>::new(
+///     2.0.quantity::<Unit![Volt / Metre]>(),
+///     0.5.quantity::<Unit![Volt / Metre / Second]>(),
+///     0.25.quantity::<Unit![Volt * Second / Metre]>(),
+/// );
+///
+/// // 2 * 1 + 0.5 * (1 * 2) + 0 (there is no previous error yet)
+/// assert_eq!(pid.step(1.0.m(), 2.0.s()), 3.0.v());
+/// // 2 * 0 + 0.5 * (1 * 2 + 0 * 2) + 0.25 * (0 - 1) / 2
+/// assert_eq!(pid.step(0.0.m(), 2.0.s()), 0.875.v());
+/// ```
+pub struct Pid<S, In, Out, T = Second> {
+    kp: S,
+    ki: S,
+    kd: S,
+    /// Sum of `error * dt`
+    integral: S,
+    previous: Option<S>,
+    _units: PhantomData<(In, Out, T)>,
+}
+
+impl<S, In, Out, T> Pid<S, In, Out, T>
+where
+    In: UnitTrait + Mul<T>,
+    Out: UnitTrait + Div<In> + Div<Prod<In, T>> + Mul<T>,
+    T: UnitTrait<Dimensions = <Second as UnitTrait>::Dimensions>,
+    Prod<Out, T>: Div<In>,
+{
+    /// Creates new controller with the given proportional (`kp`), integral
+    /// (`ki`) and derivative (`kd`) gains.
+    #[inline]
+    pub fn new<P, I, D>(kp: Quantity<S, P>, ki: Quantity<S, I>, kd: Quantity<S, D>) -> Self
+    where
+        S: Zero,
+        P: UnitEq<Quot<Out, In>>,
+        I: UnitEq<Quot<Out, Prod<In, T>>>,
+        D: UnitEq<Quot<Prod<Out, T>, In>>,
+    {
+        Self {
+            kp: kp.into_inner(),
+            ki: ki.into_inner(),
+            kd: kd.into_inner(),
+            integral: S::zero(),
+            previous: None,
+            _units: PhantomData,
+        }
+    }
+
+    /// Feeds the current `error` (`setpoint - measurement`) and the time
+    /// passed since the previous step to the controller, returns the control
+    /// value.
+    ///
+    /// The derivative term is zero on the first step (after creation or
+    /// [`reset`](Self::reset)).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `dt` is an integer zero and this isn't the first step.
+    #[inline]
+    pub fn step(&mut self, error: Quantity<S, In>, dt: Quantity<S, T>) -> Quantity<S, Out>
+    where
+        S: Add<Output = S> + Sub<Output = S> + Mul<Output = S> + Div<Output = S> + Zero + Copy,
+    {
+        // Units of gains are checked in `new`, so computing in storages gives
+        // values in `Out` (ratios of units cancel out)
+        let (e, dt) = (error.into_inner(), dt.into_inner());
+
+        self.integral = self.integral + e * dt;
+        let derivative = match self.previous.replace(e) {
+            Some(previous) => (e - previous) / dt,
+            None => S::zero(),
+        };
+
+        Quantity::new(self.kp * e + self.ki * self.integral + self.kd * derivative)
+    }
+
+    /// Returns the accumulated integral of the error.
+    #[inline]
+    pub fn integral(&self) -> Quantity<S, Prod<In, T>>
+    where
+        S: Copy,
+    {
+        Quantity::new(self.integral)
+    }
+
+    /// Resets the accumulated integral and forgets the previous error.
+    #[inline]
+    pub fn reset(&mut self)
+    where
+        S: Zero,
+    {
+        self.integral = S::zero();
+        self.previous = None;
+    }
+}
+
+/// Coefficient that turns a quantity of unit `From` into a quantity of unit
+/// `To` (so its unit is `To / From`).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     control::Gain,
+///     prefixes::Milli,
+///     units::{Metre, Volt},
+///     IntExt, Quantity, Unit,
+/// };
+///
+/// let gain = Gain::<_, Metre, Milli<Volt>>::new(2.quantity::<Unit![Milli<Volt> / Metre]>());
+/// assert_eq!(gain.apply(3.m()), 6.mv());
+/// ```
+///
+/// Units must be equal (not only have the same dimensions):
+///
+/// ```compile_fail
+/// use typed_phy::{
+///     control::Gain,
+///     prefixes::Milli,
+///     units::{Metre, Volt},
+///     IntExt, Unit,
+/// };
+///
+/// let gain = Gain::<_, Metre, Milli<Volt>>::new(2.quantity::<Unit![Volt / Metre]>());
+/// ```
+pub struct Gain<S, From, To> {
+    k: S,
+    _units: PhantomData<(From, To)>,
+}
+
+impl<S, From, To> Gain<S, From, To>
+where
+    To: UnitTrait + Div<From>,
+{
+    /// Creates new gain, unit of `k` must be equal to `To / From`.
+    #[inline]
+    pub fn new<K>(k: Quantity<S, K>) -> Self
+    where
+        K: UnitEq<Quot<To, From>>,
+    {
+        Self {
+            k: k.into_inner(),
+            _units: PhantomData,
+        }
+    }
+
+    /// Returns the gain as a quantity.
+    #[inline]
+    pub fn get(&self) -> Quantity<S, Quot<To, From>>
+    where
+        S: Copy,
+    {
+        Quantity::new(self.k)
+    }
+}
+
+impl<S, From, To> Gain<S, From, To> {
+    /// Returns `k * x`.
+    #[inline]
+    pub fn apply(&self, x: Quantity<S, From>) -> Quantity<S, To>
+    where
+        S: Mul<Output = S> + Copy,
+    {
+        // Unit of `k` is checked in `new`, so ratios cancel out
+        Quantity::new(self.k * x.into_inner())
+    }
+}
+
+impl<S: Clone, From, To> Clone for Gain<S, From, To> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            k: self.k.clone(),
+            _units: PhantomData,
+        }
+    }
+}
+
+impl<S: Copy, From, To> Copy for Gain<S, From, To> {}
+
+/// Discrete-time state-space model with a single state (of unit `X`):
+///
+/// ```text
+/// x[k + 1] = a * x[k] + b * u[k]
+///     y[k] = c * x[k] + d * u[k]
+/// ```
+///
+/// where `u` is the input (of unit `In`) and `y` is the output (of unit
+/// `Out`).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     control::{Gain, StateSpace1},
+///     units::{Dimensionless, Metre, MetrePerSecond},
+///     IntExt,
+/// };
+///
+/// // Position integrating velocity with a 0.5 s step
+/// let mut model = StateSpace1::<f64, Metre, MetrePerSecond, Metre>::new(
+///     Gain::new(1.0.quantity::<Dimensionless>()),
+///     Gain::new(0.5.s()),
+///     Gain::new(1.0.quantity::<Dimensionless>()),
+///     Gain::new(0.0.s()),
+///     0.0.m(),
+/// );
+///
+/// assert_eq!(model.step(2.0.mps()), 0.0.m());
+/// assert_eq!(model.step(2.0.mps()), 1.0.m());
+/// assert_eq!(model.state(), 2.0.m());
+/// ```
+pub struct StateSpace1<S, X, In, Out> {
+    a: Gain<S, X, X>,
+    b: Gain<S, In, X>,
+    c: Gain<S, X, Out>,
+    d: Gain<S, In, Out>,
+    x: Quantity<S, X>,
+}
+
+impl<S, X, In, Out> StateSpace1<S, X, In, Out>
+where
+    S: Add<Output = S> + Mul<Output = S> + Copy,
+{
+    /// Creates new model with the given coefficients and initial state.
+    #[inline]
+    pub fn new(
+        a: Gain<S, X, X>,
+        b: Gain<S, In, X>,
+        c: Gain<S, X, Out>,
+        d: Gain<S, In, Out>,
+        initial: Quantity<S, X>,
+    ) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            d,
+            x: initial,
+        }
+    }
+
+    /// Returns the output for the input `u` and advances the state.
+    #[inline]
+    pub fn step(&mut self, u: Quantity<S, In>) -> Quantity<S, Out> {
+        let x = self.x;
+        let y = self.c.apply(x).into_inner() + self.d.apply(u).into_inner();
+
+        self.x = Quantity::new(self.a.apply(x).into_inner() + self.b.apply(u).into_inner());
+        Quantity::new(y)
+    }
+
+    /// Returns the current state.
+    #[inline]
+    pub fn state(&self) -> Quantity<S, X> {
+        self.x
+    }
+
+    /// Sets the current state.
+    #[inline]
+    pub fn set_state(&mut self, x: Quantity<S, X>) {
+        self.x = x;
+    }
+}
+
+/// Discrete-time state-space model with 2 states (of units `X0` and `X1`):
+///
+/// ```text
+/// x[k + 1] = A * x[k] + B * u[k]
+///     y[k] = C * x[k] + D * u[k]
+/// ```
+///
+/// where `u` is the input (of unit `In`) and `y` is the output (of unit
+/// `Out`). `A` is `((a00, a01), (a10, a11))`, `B` is `(b0, b1)` and `C` is
+/// `(c0, c1)`, units of all coefficients are derived from `X0`, `X1`, `In`
+/// and `Out`.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     control::{Gain, StateSpace2},
+///     units::{Dimensionless, Hertz, KiloGram, Metre, MetrePerSecond, Newton, Second},
+///     IntExt, Unit,
+/// };
+///
+/// // A body of 2 kg pushed by a force (position is the output), 1 s step
+/// let one = 1.0.quantity::<Dimensionless>();
+/// let mut model = StateSpace2::<f64, Metre, MetrePerSecond, Newton, Metre>::new(
+///     (
+///         (Gain::new(one), Gain::new(1.0.s())),
+///         (Gain::new(0.0.quantity::<Hertz>()), Gain::new(one)),
+///     ),
+///     (
+///         Gain::new(0.0.quantity::<Unit![Second ^ 2 / KiloGram]>()),
+///         Gain::new(0.5.quantity::<Unit![Second / KiloGram]>()),
+///     ),
+///     (Gain::new(one), Gain::new(0.0.s())),
+///     Gain::new(0.0.quantity::<Unit![Metre / Newton]>()),
+///     (0.0.m(), 0.0.mps()),
+/// );
+///
+/// assert_eq!(model.step(4.0.quantity()), 0.0.m());
+/// assert_eq!(model.step(0.0.quantity()), 0.0.m());
+/// assert_eq!(model.step(0.0.quantity()), 2.0.m());
+/// assert_eq!(model.state(), (4.0.m(), 2.0.mps()));
+/// ```
+#[allow(clippy::type_complexity)]
+pub struct StateSpace2<S, X0, X1, In, Out> {
+    a: (
+        (Gain<S, X0, X0>, Gain<S, X1, X0>),
+        (Gain<S, X0, X1>, Gain<S, X1, X1>),
+    ),
+    b: (Gain<S, In, X0>, Gain<S, In, X1>),
+    c: (Gain<S, X0, Out>, Gain<S, X1, Out>),
+    d: Gain<S, In, Out>,
+    x: (Quantity<S, X0>, Quantity<S, X1>),
+}
+
+impl<S, X0, X1, In, Out> StateSpace2<S, X0, X1, In, Out>
+where
+    S: Add<Output = S> + Mul<Output = S> + Copy,
+{
+    /// Creates new model with the given coefficients and initial state.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    pub fn new(
+        a: (
+            (Gain<S, X0, X0>, Gain<S, X1, X0>),
+            (Gain<S, X0, X1>, Gain<S, X1, X1>),
+        ),
+        b: (Gain<S, In, X0>, Gain<S, In, X1>),
+        c: (Gain<S, X0, Out>, Gain<S, X1, Out>),
+        d: Gain<S, In, Out>,
+        initial: (Quantity<S, X0>, Quantity<S, X1>),
+    ) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            d,
+            x: initial,
+        }
+    }
+
+    /// Returns the output for the input `u` and advances the state.
+    #[inline]
+    pub fn step(&mut self, u: Quantity<S, In>) -> Quantity<S, Out> {
+        let (x0, x1) = self.x;
+        let ((a00, a01), (a10, a11)) = &self.a;
+        let (b0, b1) = &self.b;
+        let (c0, c1) = &self.c;
+
+        let y =
+            c0.apply(x0).into_inner() + c1.apply(x1).into_inner() + self.d.apply(u).into_inner();
+
+        self.x = (
+            Quantity::new(
+                a00.apply(x0).into_inner() + a01.apply(x1).into_inner() + b0.apply(u).into_inner(),
+            ),
+            Quantity::new(
+                a10.apply(x0).into_inner() + a11.apply(x1).into_inner() + b1.apply(u).into_inner(),
+            ),
+        );
+        Quantity::new(y)
+    }
+
+    /// Returns the current state.
+    #[inline]
+    pub fn state(&self) -> (Quantity<S, X0>, Quantity<S, X1>) {
+        self.x
+    }
+
+    /// Sets the current state.
+    #[inline]
+    pub fn set_state(&mut self, x: (Quantity<S, X0>, Quantity<S, X1>)) {
+        self.x = x;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Gain, Pid, StateSpace1, StateSpace2};
+    use crate::{
+        prefixes::Milli,
+        units::{Dimensionless, Metre, Second, Volt},
+        IntExt, Quantity,
+    };
+
+    #[test]
+    fn pid() {
+        // Gains for millivolts/millimetres/milliseconds, integer storage
+        type MilliMetre = Milli<Metre>;
+        type MilliVolt = Milli<Volt>;
+        type MilliSecond = Milli<Second>;
+
+        let mut pid = Pid::<i32, MilliMetre, MilliVolt, MilliSecond>::new(
+            3.quantity::<Unit![Volt / Metre]>(),
+            1.quantity::<Unit![MilliVolt / MilliMetre / MilliSecond]>(),
+            10.quantity::<Unit![Volt * MilliSecond / Metre]>(),
+        );
+        let mm = Quantity::<_, MilliMetre>::new;
+        let ms = Quantity::<_, MilliSecond>::new;
+
+        // 3 * 4 + 1 * 8
+        assert_eq!(pid.step(mm(4), ms(2)), 20.mv());
+        // 3 * 2 + 1 * 12 + 10 * (2 - 4) / 2
+        assert_eq!(pid.step(mm(2), ms(2)), 8.mv());
+        assert_eq!(
+            pid.integral(),
+            12.quantity::<Unit![MilliMetre * MilliSecond]>()
+        );
+
+        pid.reset();
+        assert_eq!(pid.integral().into_inner(), 0);
+        assert_eq!(pid.step(mm(1), ms(1)), 4.mv());
+    }
+
+    #[test]
+    fn state_space() {
+        type MilliVolt = Milli<Volt>;
+
+        // Gains are checked up to structural differences of units
+        let gain = Gain::<_, Metre, MilliVolt>::new(5.quantity::<Unit![MilliVolt / Metre]>());
+        assert_eq!(gain.get(), 5.quantity::<Unit![MilliVolt / Metre]>());
+        assert_eq!(gain.apply(2.m()), 10.mv());
+
+        // Leaky accumulator: x[k + 1] = x[k] / 2 + u[k], y[k] = 2 * x[k]
+        let mut model = StateSpace1::<_, Volt, Volt, MilliVolt>::new(
+            Gain::new(Quantity::<_, Dimensionless>::new(0.5)),
+            Gain::new(Quantity::<_, Dimensionless>::new(1.0)),
+            Gain::new(2000.0.quantity::<Unit![MilliVolt / Volt]>()),
+            Gain::new(0.0.quantity::<Unit![MilliVolt / Volt]>()),
+            0.0.v(),
+        );
+        assert_eq!(model.step(1.0.v()), 0.0.mv());
+        assert_eq!(model.step(1.0.v()), 2000.0.mv());
+        assert_eq!(model.step(0.0.v()), 3000.0.mv());
+        assert_eq!(model.state(), 0.75.v());
+
+        model.set_state(0.0.v());
+        assert_eq!(model.step(0.0.v()), 0.0.mv());
+
+        // x0 stays constant, x1 sums inputs, y = x1 + u
+        let one = Quantity::<_, Dimensionless>::new(1);
+        let zero = Quantity::<_, Dimensionless>::new(0);
+        let mut model = StateSpace2::<_, Dimensionless, Volt, Volt, Volt>::new(
+            (
+                (
+                    Gain::new(one),
+                    Gain::new(0.quantity::<Unit![Dimensionless / Volt]>()),
+                ),
+                (Gain::new(0.v()), Gain::new(one)),
+            ),
+            (
+                Gain::new(0.quantity::<Unit![Dimensionless / Volt]>()),
+                Gain::new(one),
+            ),
+            (Gain::new(0.v()), Gain::new(one)),
+            Gain::new(one),
+            (one, 0.v()),
+        );
+        assert_eq!(model.step(3.v()), 3.v());
+        assert_eq!(model.step(4.v()), 7.v());
+        assert_eq!(model.state(), (one, 7.v()));
+
+        model.set_state((zero, 1.v()));
+        assert_eq!(model.step(1.v()), 2.v());
+    }
+}
@@ -0,0 +1,248 @@
+//! Database integration (requires `sqlx` and/or `diesel` features).
+//!
+//! [`Quantity`] is stored as the underlying number (i.e. the column has the
+//! SQL type of the storage), so services don't need to unwrap quantities at
+//! the DB boundary. The unit isn't stored, it's up to the schema to document
+//! it, e.g. by naming the column `distance_mm` and using
+//! `Quantity<i64, Milli<Metre>>` for it everywhere.
+//!
+//! Note: reading a column with a different unit (e.g. `Quantity<i64, Metre>`
+//! from a column that was written as `Quantity<i64, Milli<Metre>>`) isn't
+//! detected, so it's best to keep the mapping in one place (e.g. the model
+//! struct).
+//!
+//! ## sqlx
+//!
+//! `Quantity<S, U>` implements [`Type`], [`Encode`] and [`Decode`] for every
+//! database that supports `S`:
+//!
+//! ```ignore
+//! #[derive(sqlx::FromRow)]
+//! struct Reading {
+//!     /// Stored as `BIGINT` in millivolts
+//!     voltage: Quantity<i64, Milli<Volt>>,
+//! }
+//!
+//! sqlx::query("INSERT INTO readings (voltage) VALUES ($1)")
+//!     .bind(1500.mv())
+//!     .execute(&pool)
+//!     .await?;
+//! ```
+//!
+//! ## diesel
+//!
+//! `Quantity<S, U>` implements [`ToSql`], [`FromSql`] and [`AsExpression`] for
+//! every SQL type supported by `S`, so it can be used in `Queryable` and
+//! `Insertable` structs directly:
+//!
+//! ```ignore
+//! #[derive(Queryable, Insertable)]
+//! #[diesel(table_name = readings)]
+//! struct Reading {
+//!     /// `voltage -> BigInt` in the schema, in millivolts
+//!     voltage: Quantity<i64, Milli<Volt>>,
+//! }
+//! ```
+//!
+//! [`Quantity`]: crate::Quantity
+//! [`Type`]: sqlx_core::types::Type
+//! [`Encode`]: sqlx_core::encode::Encode
+//! [`Decode`]: sqlx_core::decode::Decode
+//! [`ToSql`]: diesel::serialize::ToSql
+//! [`FromSql`]: diesel::deserialize::FromSql
+//! [`AsExpression`]: diesel::expression::AsExpression
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use sqlx_core::{
+        database::Database,
+        decode::Decode,
+        encode::{Encode, IsNull},
+        error::BoxDynError,
+        types::Type,
+    };
+
+    use crate::Quantity;
+
+    impl<S, U, DB> Type<DB> for Quantity<S, U>
+    where
+        S: Type<DB>,
+        DB: Database,
+    {
+        #[inline]
+        fn type_info() -> DB::TypeInfo {
+            S::type_info()
+        }
+
+        #[inline]
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            S::compatible(ty)
+        }
+    }
+
+    impl<'q, S, U, DB> Encode<'q, DB> for Quantity<S, U>
+    where
+        S: Encode<'q, DB>,
+        DB: Database,
+    {
+        #[inline]
+        fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+            self.into_inner().encode(buf)
+        }
+
+        #[inline]
+        fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+            self.storage_ref().encode_by_ref(buf)
+        }
+
+        #[inline]
+        fn produces(&self) -> Option<DB::TypeInfo> {
+            self.storage_ref().produces()
+        }
+
+        #[inline]
+        fn size_hint(&self) -> usize {
+            self.storage_ref().size_hint()
+        }
+    }
+
+    impl<'r, S, U, DB> Decode<'r, DB> for Quantity<S, U>
+    where
+        S: Decode<'r, DB>,
+        DB: Database,
+    {
+        #[inline]
+        fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            S::decode(value).map(Quantity::new)
+        }
+    }
+}
+
+#[cfg(feature = "diesel")]
+mod diesel_impls {
+    use core::fmt::Debug;
+
+    use diesel::{
+        backend::Backend,
+        deserialize::{self, FromSql},
+        expression::{AsExpression, TypedExpressionType},
+        serialize::{self, Output, ToSql},
+        sql_types::SqlType,
+    };
+
+    use crate::Quantity;
+
+    impl<S, U, ST, DB> ToSql<ST, DB> for Quantity<S, U>
+    where
+        S: ToSql<ST, DB>,
+        DB: Backend,
+        Self: Debug,
+    {
+        #[inline]
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.storage_ref().to_sql(out)
+        }
+    }
+
+    impl<S, U, ST, DB> FromSql<ST, DB> for Quantity<S, U>
+    where
+        S: FromSql<ST, DB>,
+        DB: Backend,
+    {
+        #[inline]
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            S::from_sql(bytes).map(Quantity::new)
+        }
+
+        #[inline]
+        fn from_nullable_sql(bytes: Option<DB::RawValue<'_>>) -> deserialize::Result<Self> {
+            S::from_nullable_sql(bytes).map(Quantity::new)
+        }
+    }
+
+    impl<S, U, ST> AsExpression<ST> for Quantity<S, U>
+    where
+        S: AsExpression<ST>,
+        ST: SqlType + TypedExpressionType,
+    {
+        type Expression = S::Expression;
+
+        #[inline]
+        fn as_expression(self) -> Self::Expression {
+            self.into_inner().as_expression()
+        }
+    }
+
+    impl<'a, S, U, ST> AsExpression<ST> for &'a Quantity<S, U>
+    where
+        &'a S: AsExpression<ST>,
+        ST: SqlType + TypedExpressionType,
+    {
+        type Expression = <&'a S as AsExpression<ST>>::Expression;
+
+        #[inline]
+        fn as_expression(self) -> Self::Expression {
+            self.storage_ref().as_expression()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prefixes::Milli, units::Metre, Quantity};
+
+    type Mm = Quantity<i64, Milli<Metre>>;
+
+    #[test]
+    #[cfg(feature = "sqlx")]
+    fn sqlx() {
+        use sqlx_core::{
+            any::{Any, AnyArgumentBuffer, AnyTypeInfoKind, AnyValue, AnyValueKind},
+            decode::Decode,
+            encode::{Encode, IsNull},
+            types::Type,
+            value::Value,
+        };
+
+        assert_eq!(
+            <Mm as Type<Any>>::type_info().kind(),
+            AnyTypeInfoKind::BigInt
+        );
+
+        let mut buf = AnyArgumentBuffer(Vec::new());
+        assert!(matches!(
+            Encode::<Any>::encode(Mm::new(1500), &mut buf),
+            Ok(IsNull::No)
+        ));
+        assert!(matches!(buf.0[..], [AnyValueKind::BigInt(1500)]));
+
+        let value = AnyValue {
+            kind: buf.0.remove(0),
+        };
+        let decoded = <Mm as Decode<Any>>::decode(value.as_ref()).unwrap();
+        assert_eq!(decoded, Mm::new(1500));
+    }
+
+    #[test]
+    #[cfg(feature = "diesel")]
+    fn diesel() {
+        use diesel::{
+            backend::Backend, deserialize::FromSql, expression::AsExpression, serialize::ToSql,
+            sql_types::BigInt,
+        };
+
+        // diesel has no in-memory backend, so only check that the impls apply
+        // to every backend that supports `i64`
+        #[allow(dead_code)]
+        fn check<DB: Backend>()
+        where
+            i64: ToSql<BigInt, DB> + FromSql<BigInt, DB>,
+        {
+            fn is_column<DB: Backend, T: ToSql<BigInt, DB> + FromSql<BigInt, DB>>() {}
+            is_column::<DB, Mm>();
+        }
+
+        let _ = AsExpression::<BigInt>::as_expression(Mm::new(1500));
+        let _ = AsExpression::<BigInt>::as_expression(&Mm::new(1500));
+    }
+}
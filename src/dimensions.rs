@@ -3,7 +3,7 @@ use core::{
     ops::{Add, Div, Mul, Sub},
 };
 
-use typenum::{Diff, Integer, Sum};
+use typenum::{Diff, Integer, Pow, Prod, Sum};
 
 /// Trait implemented for [`Dimensions`].
 /// Mostly needed to simplify bound and write
@@ -153,7 +153,7 @@ where
 impl<L, M, T, I, O, N, J> Clone for Dimensions<L, M, T, I, O, N, J> {
     #[inline]
     fn clone(&self) -> Self {
-        Self::new()
+        *self
     }
 }
 
@@ -226,9 +226,41 @@ where
     }
 }
 
+/// This multiplies exponents by `P` at type-level. E.g.
+/// `Dimensions<1, 0, -1, ...> ^ 2 = Dimensions<2, 0, -2, ...>`
+///
+/// It's used for raising quantities to integer powers.
+impl<P, L, M, T, I, O, N, J> Pow<P> for Dimensions<L, M, T, I, O, N, J>
+where
+    P: Integer,
+    L: Mul<P>,
+    M: Mul<P>,
+    T: Mul<P>,
+    I: Mul<P>,
+    O: Mul<P>,
+    N: Mul<P>,
+    J: Mul<P>,
+{
+    #[allow(clippy::type_complexity)]
+    type Output = Dimensions<
+        Prod<L, P>,
+        Prod<M, P>,
+        Prod<T, P>,
+        Prod<I, P>,
+        Prod<O, P>,
+        Prod<N, P>,
+        Prod<J, P>,
+    >;
+
+    #[inline]
+    fn powi(self, _exp: P) -> Self::Output {
+        Dimensions::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use typenum::{N2, N3, N4, N5, N6, N7, N8, P1, P2, P3, P4, P5, P6, P7, P8, Z0};
+    use typenum::{Pow, N1, N2, N3, N4, N5, N6, N7, N8, P1, P2, P3, P4, P5, P6, P7, P8, Z0};
 
     use super::Dimensions;
 
@@ -253,4 +285,16 @@ mod tests {
             Dimensions::<Z0, Z0, Z0, Z0, Z0, Z0, Z0>::new()
                 * Dimensions::<P8, N7, P6, N5, P4, N3, P2>::new();
     }
+
+    #[test]
+    fn pow() {
+        let _: Dimensions<P2, Z0, N2, Z0, Z0, Z0, Z0> =
+            Pow::powi(Dimensions::<P1, Z0, N1, Z0, Z0, Z0, Z0>::new(), P2::new());
+
+        let _: Dimensions<N3, P6, Z0, Z0, Z0, Z0, Z0> =
+            Pow::powi(Dimensions::<P1, N2, Z0, Z0, Z0, Z0, Z0>::new(), N3::new());
+
+        let _: Dimensions<Z0, Z0, Z0, Z0, Z0, Z0, Z0> =
+            Pow::powi(Dimensions::<P8, N7, P6, N5, P4, N3, P2>::new(), Z0::new());
+    }
 }
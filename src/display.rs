@@ -0,0 +1,987 @@
+use core::{
+    fmt::{self, Display, Write},
+    marker::PhantomData,
+    ops::{Div, Mul, Sub},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use crate::{
+    from_int::{FromUnsigned, ToF64},
+    rt::{RtFraction, UnitRtExt},
+    units::{Day, Hour, Minute, NewtonMetre, Second},
+    DimensionsEq, Quantity, UnitTrait,
+};
+#[cfg(feature = "alloc")]
+use crate::{rt::RtUnit, unit::SIMPLE_UNITS};
+
+/// The smallest unit that is shown by [`HumanDuration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Granularity {
+    /// Show only days
+    Day,
+    /// Show days and hours
+    Hour,
+    /// Show days, hours and minutes
+    Minute,
+    /// Show days, hours, minutes and seconds
+    Second,
+}
+
+/// Display adapter that splits time quantity into days, hours, minutes and
+/// seconds (e.g. `5415 s` is displayed as `1 h 30 min 15 s`).
+///
+/// Created by [`Quantity::humanize`]. Only non-zero components are shown,
+/// everything smaller than the [`Granularity`] is truncated.
+///
+/// Note: this adapter works with integer storages only (float storages are
+/// not truncated during splitting, so the output won't make sense).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{display::Granularity, IntExt};
+///
+/// assert_eq!(format!("{}", 5415.s().humanize()), "1 h 30 min 15 s");
+/// assert_eq!(format!("{}", 90_061.s().humanize()), "1 d 1 h 1 min 1 s");
+/// assert_eq!(format!("{}", 3600.s().humanize()), "1 h");
+/// assert_eq!(format!("{}", 0.s().humanize()), "0 s");
+/// assert_eq!(format!("{}", (-75).s().humanize()), "-1 min 15 s");
+///
+/// // Units with other ratios work too
+/// assert_eq!(format!("{}", 150.min_().humanize()), "2 h 30 min");
+///
+/// // Granularity
+/// let q = 5415.s().humanize().granularity(Granularity::Minute);
+/// assert_eq!(format!("{}", q), "1 h 30 min");
+/// let q = 59.s().humanize().granularity(Granularity::Minute);
+/// assert_eq!(format!("{}", q), "0 min");
+/// ```
+pub struct HumanDuration<S, U> {
+    quantity: Quantity<S, U>,
+    granularity: Granularity,
+}
+
+impl<S, U> HumanDuration<S, U> {
+    /// Set the smallest unit to show.
+    #[inline]
+    pub fn granularity(self, granularity: Granularity) -> Self {
+        Self {
+            granularity,
+            ..self
+        }
+    }
+}
+
+impl<S, U> Quantity<S, U>
+where
+    U: UnitTrait<Dimensions = <Second as UnitTrait>::Dimensions>,
+{
+    /// Returns display adapter that shows the time quantity split into days,
+    /// hours, minutes and seconds. See [`HumanDuration`] for more.
+    #[inline]
+    pub fn humanize(self) -> HumanDuration<S, U> {
+        HumanDuration {
+            quantity: self,
+            granularity: Granularity::Second,
+        }
+    }
+}
+
+impl<S, U> Display for HumanDuration<S, U>
+where
+    U: UnitTrait<Dimensions = <Second as UnitTrait>::Dimensions>,
+    S: FromUnsigned
+        + Mul<Output = S>
+        + Div<Output = S>
+        + Sub<Output = S>
+        + PartialOrd
+        + Default
+        + Copy
+        + Display,
+{
+    #[inline]
+    #[allow(unused_assignments)] // the last `component!` doesn't need to update `first`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let zero = S::default();
+        let mut rest = self.quantity.into_unit::<Second>();
+        // The sign is written separately and the components are negated one by
+        // one, since negating the whole `rest` overflows for `S::MIN`
+        let negative = rest.into_inner() < zero;
+        if negative {
+            f.write_char('-')?;
+        }
+
+        let mut first = true;
+
+        macro_rules! component {
+            ($Unit:ty, $granularity:expr) => {
+                if $granularity <= self.granularity {
+                    let part = split_off::<_, $Unit>(&mut rest);
+                    let part = if negative {
+                        Quantity::<_, $Unit>::new(zero - part.into_inner())
+                    } else {
+                        part
+                    };
+
+                    // Zero is shown only if nothing else is shown
+                    if part.into_inner() != zero || (first && $granularity == self.granularity) {
+                        if !first {
+                            f.write_char(' ')?;
+                        }
+                        f.write_fmt(format_args!("{}", part))?;
+                        first = false;
+                    }
+                }
+            };
+        }
+
+        component!(Day, Granularity::Day);
+        component!(Hour, Granularity::Hour);
+        component!(Minute, Granularity::Minute);
+        component!(Second, Granularity::Second);
+
+        Ok(())
+    }
+}
+
+/// Display adapter that shows quantity in the unit `T`, without changing the
+/// stored value.
+///
+/// Created by [`Quantity::display_in`]. The ratio is applied at print time,
+/// the value is rendered as `f64` (so the conversion may be lossy). Precision
+/// of the formatter is respected.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     prefixes::{Kilo, Milli},
+///     units::{Hour, Metre},
+///     IntExt, Quantity,
+/// };
+///
+/// let q = Quantity::<i32, Milli<Metre>>::new(1500);
+/// assert_eq!(format!("{}", q.display_in::<Metre>()), "1.5 m");
+/// assert_eq!(format!("{:.3}", q.display_in::<Kilo<Metre>>()), "0.002 km");
+/// assert_eq!(format!("{}", 90.min_().display_in::<Hour>()), "1.5 h");
+///
+/// // The quantity itself isn't changed
+/// assert_eq!(q.into_inner(), 1500);
+/// ```
+pub struct DisplayIn<S, U, T> {
+    quantity: Quantity<S, U>,
+    _target: PhantomData<T>,
+}
+
+impl<S, U> Quantity<S, U>
+where
+    U: UnitTrait,
+{
+    /// Returns display adapter that shows the quantity in the unit `T`. See
+    /// [`DisplayIn`] for more.
+    #[inline]
+    pub fn display_in<T>(self) -> DisplayIn<S, U, T>
+    where
+        T: UnitTrait,
+        T::Dimensions: DimensionsEq<U::Dimensions>,
+    {
+        DisplayIn {
+            quantity: self,
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<S, U, T> Display for DisplayIn<S, U, T>
+where
+    U: UnitTrait,
+    T: UnitTrait + Display + Default,
+    T::Dimensions: DimensionsEq<U::Dimensions>,
+    S: ToF64 + Copy,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (u, t) = (U::RT.ratio, T::RT.ratio);
+        let value = self.quantity.into_inner().to_f64() * u.numerator.to_f64() / u.divisor.to_f64()
+            * t.divisor.to_f64()
+            / t.numerator.to_f64();
+
+        match f.precision() {
+            Some(precision) => f.write_fmt(format_args!(
+                "{value:.precision$} {unit}",
+                value = value,
+                precision = precision,
+                unit = T::default(),
+            )),
+            None => f.write_fmt(format_args!(
+                "{value} {unit}",
+                value = value,
+                unit = T::default(),
+            )),
+        }
+    }
+}
+
+/// Display adapter that shows torque in newton metres (`N·m`) instead of
+/// joules.
+///
+/// [`NewtonMetre`] and [`Joule`] are the same type, so the [`Display`] impl of
+/// [`Quantity`] can't tell them apart and always uses `J`. Call sites that know
+/// that the quantity is a torque can use this adapter instead.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{prefixes::Kilo, units::NewtonMetre, Quantity};
+///
+/// let torque = Quantity::<f64, NewtonMetre>::new(2.5);
+/// assert_eq!(format!("{}", torque), "2.5 J");
+/// assert_eq!(format!("{}", torque.display_torque()), "2.5 N·m");
+///
+/// let big = Quantity::<u32, Kilo<NewtonMetre>>::new(3);
+/// assert_eq!(format!("{:.1}", big.display_torque()), "3000.0 N·m");
+/// ```
+///
+/// [`NewtonMetre`]: crate::units::NewtonMetre
+/// [`Joule`]: crate::units::Joule
+pub struct DisplayTorque<S, U> {
+    quantity: Quantity<S, U>,
+}
+
+impl<S, U> Quantity<S, U>
+where
+    U: UnitTrait,
+    U::Dimensions: DimensionsEq<<NewtonMetre as UnitTrait>::Dimensions>,
+{
+    /// Returns display adapter that shows the quantity as a torque (in `N·m`).
+    /// See [`DisplayTorque`] for more.
+    #[inline]
+    pub fn display_torque(self) -> DisplayTorque<S, U> {
+        DisplayTorque { quantity: self }
+    }
+}
+
+impl<S, U> Display for DisplayTorque<S, U>
+where
+    U: UnitTrait,
+    S: ToF64 + Copy,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ratio = U::RT.ratio;
+        let value =
+            self.quantity.into_inner().to_f64() * ratio.numerator.to_f64() / ratio.divisor.to_f64();
+
+        match f.precision() {
+            Some(precision) => f.write_fmt(format_args!(
+                "{value:.precision$} N·m",
+                value = value,
+                precision = precision,
+            )),
+            None => f.write_fmt(format_args!("{} N·m", value)),
+        }
+    }
+}
+
+/// Symbol of a (user-defined) unit, used by [`Quantity::display_symbol`].
+///
+/// Prefixes are type aliases that only change the ratio of the unit, so the
+/// [`Display`] impl of [`Unit`](crate::Unit) can't know about symbols of user
+/// units. Registering the symbol via this trait lets [`DisplaySymbol`] pick up
+/// the SI prefix automatically (e.g. `Kilo<MyUnit>` is shown as `kX`).
+///
+/// Note: the symbol is used **only** by the [`DisplaySymbol`] adapter (returned
+/// by [`Quantity::display_symbol`]). [`Quantity`]'s own [`Display`] impl is
+/// implemented once for all units, and without specialization it can't check
+/// whether a symbol was registered for the unit, so it keeps showing user
+/// units via their dimensions and ratio (see the example below).
+///
+/// The trait is implemented for a marker type rather than for the unit itself
+/// since units are aliases of [`Unit`](crate::Unit), which is a foreign type
+/// for user crates.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     display::UnitSymbol, fraction::Fraction, prefixes::Kilo, units::Joule, Quantity, Unit,
+///     UnitTrait,
+/// };
+/// use typenum::{U1, U3};
+///
+/// // Unit "X" that is worth 3 joules
+/// type X = Unit<<Joule as UnitTrait>::Dimensions, Fraction<U3, U1>>;
+///
+/// struct XSymbol;
+///
+/// impl UnitSymbol for XSymbol {
+///     type Unit = X;
+///     const SYMBOL: &'static str = "X";
+/// }
+///
+/// let q = Quantity::<u32, Kilo<X>>::new(5);
+/// assert_eq!(format!("{}", q.display_symbol::<XSymbol>()), "5 kX");
+///
+/// // `Display` of the quantity itself ignores the registered symbol
+/// assert_eq!(format!("{}", q), "5 m^2 * kg * s^-2 (ratio: 3000)");
+/// ```
+pub trait UnitSymbol {
+    /// The unit that has the symbol
+    type Unit: UnitTrait;
+
+    /// Symbol of the unit (e.g. `"X"`)
+    const SYMBOL: &'static str;
+}
+
+/// Display adapter that shows the quantity with the symbol registered via
+/// [`UnitSymbol`] impl for `T`.
+///
+/// If the unit of the quantity is `T::Unit` with an SI prefix, the value is
+/// shown as is with the prefix symbol (e.g. `5 kX`), otherwise the value is
+/// converted to `T::Unit` (e.g. `0.5 X`).
+///
+/// This is the only way to show a quantity with a [`UnitSymbol`], `Display`
+/// of [`Quantity`] doesn't use the registered symbols.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     display::UnitSymbol,
+///     fraction::Fraction,
+///     prefixes::Micro,
+///     units::Joule,
+///     Quantity, Unit, UnitTrait,
+/// };
+/// use typenum::{U1, U3};
+///
+/// struct X;
+///
+/// impl UnitSymbol for X {
+///     type Unit = Unit<<Joule as UnitTrait>::Dimensions, Fraction<U3, U1>>;
+///     const SYMBOL: &'static str = "X";
+/// }
+///
+/// let q = Quantity::<f64, Micro<<X as UnitSymbol>::Unit>>::new(1.5);
+/// assert_eq!(format!("{}", q.display_symbol::<X>()), "1.5 μX");
+///
+/// // Joules aren't a prefixed `X`, so the value is converted
+/// let j = Quantity::<f64, Joule>::new(1.5);
+/// assert_eq!(format!("{}", j.display_symbol::<X>()), "0.5 X");
+/// ```
+pub struct DisplaySymbol<S, U, T> {
+    quantity: Quantity<S, U>,
+    _target: PhantomData<T>,
+}
+
+impl<S, U> Quantity<S, U>
+where
+    U: UnitTrait,
+{
+    /// Returns display adapter that shows the quantity with the symbol
+    /// registered for `T`. See [`DisplaySymbol`] for more.
+    #[inline]
+    pub fn display_symbol<T>(self) -> DisplaySymbol<S, U, T>
+    where
+        T: UnitSymbol,
+        <T::Unit as UnitTrait>::Dimensions: DimensionsEq<U::Dimensions>,
+    {
+        DisplaySymbol {
+            quantity: self,
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<S, U, T> Display for DisplaySymbol<S, U, T>
+where
+    U: UnitTrait,
+    T: UnitSymbol,
+    S: ToF64 + Display + Copy,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (u, t) = (U::RT.ratio, T::Unit::RT.ratio);
+        let prefix = u
+            .checked_mul(RtFraction {
+                numerator: t.divisor,
+                divisor: t.numerator,
+            })
+            .and_then(|ratio| ratio.pow10())
+            .and_then(crate::prefixes::symbol);
+
+        match prefix {
+            Some(prefix) => {
+                Display::fmt(&self.quantity.into_inner(), f)?;
+                f.write_fmt(format_args!(" {}{}", prefix, T::SYMBOL))
+            },
+            None => {
+                let value = self.quantity.into_inner().to_f64() * u.numerator.to_f64()
+                    / u.divisor.to_f64()
+                    * t.divisor.to_f64()
+                    / t.numerator.to_f64();
+                Display::fmt(&value, f)?;
+                f.write_fmt(format_args!(" {}", T::SYMBOL))
+            },
+        }
+    }
+}
+
+/// Style of unit symbols used by [`DisplayQuantity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitStyle {
+    /// Symbols as they are (e.g. `μm`, `Ω`, `Pa·s`)
+    Unicode,
+    /// ASCII-only symbols (e.g. `um`, `Ohm`, `Pa*s`), for outputs that can't
+    /// handle unicode
+    Ascii,
+}
+
+/// Configurable display adapter for quantities.
+///
+/// Created by [`Quantity::display`]. By default it's the same as the `Display`
+/// of the quantity, but the unit can be hidden, the separator between the
+/// value and the unit can be changed and the unit symbol can be restricted to
+/// ASCII. Formatting flags (e.g. precision) are applied to the value.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{display::UnitStyle, prefixes::Micro, units::Metre, IntExt, Quantity};
+///
+/// let q = 1.5.m();
+/// assert_eq!(format!("{}", q.display()), "1.5 m");
+/// assert_eq!(format!("{:.2}", q.display().no_unit()), "1.50");
+/// assert_eq!(format!("{}", q.display().separator("")), "1.5m");
+///
+/// let q = Quantity::<_, Micro<Metre>>::new(20);
+/// assert_eq!(format!("{}", q.display().unit_style(UnitStyle::Ascii)), "20 um");
+/// ```
+pub struct DisplayQuantity<'a, S, U> {
+    quantity: Quantity<S, U>,
+    unit: bool,
+    separator: &'a str,
+    style: UnitStyle,
+}
+
+impl<S, U> Quantity<S, U> {
+    /// Returns configurable display adapter. See [`DisplayQuantity`] for more.
+    #[inline]
+    pub fn display(self) -> DisplayQuantity<'static, S, U> {
+        DisplayQuantity {
+            quantity: self,
+            unit: true,
+            separator: " ",
+            style: UnitStyle::Unicode,
+        }
+    }
+}
+
+impl<'a, S, U> DisplayQuantity<'a, S, U> {
+    /// Shows only the value, without the unit (and the separator).
+    #[inline]
+    pub fn no_unit(self) -> Self {
+        Self {
+            unit: false,
+            ..self
+        }
+    }
+
+    /// Sets the separator between the value and the unit (`" "` by default).
+    #[inline]
+    pub fn separator(self, separator: &str) -> DisplayQuantity<'_, S, U> {
+        DisplayQuantity {
+            quantity: self.quantity,
+            unit: self.unit,
+            separator,
+            style: self.style,
+        }
+    }
+
+    /// Sets the style of the unit symbol ([`UnitStyle::Unicode`] by default).
+    #[inline]
+    pub fn unit_style(self, style: UnitStyle) -> Self {
+        Self { style, ..self }
+    }
+}
+
+impl<S, U> Display for DisplayQuantity<'_, S, U>
+where
+    S: Display,
+    U: Display + Default,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.quantity.storage_ref(), f)?;
+        if !self.unit {
+            return Ok(());
+        }
+
+        f.write_str(self.separator)?;
+        match self.style {
+            UnitStyle::Unicode => f.write_fmt(format_args!("{}", U::default())),
+            UnitStyle::Ascii => AsciiWriter(f).write_fmt(format_args!("{}", U::default())),
+        }
+    }
+}
+
+/// Writer that replaces non-ASCII characters of unit symbols
+struct AsciiWriter<W>(W);
+
+impl<W: Write> Write for AsciiWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                'μ' => self.0.write_char('u')?,
+                '·' => self.0.write_char('*')?,
+                'Ω' => self.0.write_str("Ohm")?,
+                'Å' => self.0.write_str("Angstrom")?,
+                c => self.0.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`Quantity::fmt_into`] when the buffer is too small to
+/// hold the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufTooSmall;
+
+impl Display for BufTooSmall {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("buffer is too small")
+    }
+}
+
+/// Writer of bytes into a fixed-size buffer
+struct BufWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for BufWriter<'_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<S, U> Quantity<S, U> {
+    /// Formats the quantity (the same way as `Display` does) into `buf`,
+    /// returning the written part of it.
+    ///
+    /// This doesn't need an allocator, so it's suitable for `no_std` targets
+    /// without `alloc`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{display::BufTooSmall, IntExt};
+    ///
+    /// let mut buf = [0; 16];
+    /// assert_eq!(1500.m().fmt_into(&mut buf), Ok("1500 m"));
+    /// assert_eq!((-3i8).kmph().fmt_into(&mut buf), Ok("-3 km/h"));
+    ///
+    /// let mut small = [0; 4];
+    /// assert_eq!(1500.m().fmt_into(&mut small), Err(BufTooSmall));
+    /// ```
+    #[inline]
+    pub fn fmt_into<'b>(&self, buf: &'b mut [u8]) -> Result<&'b str, BufTooSmall>
+    where
+        Self: Display,
+    {
+        let mut w = BufWriter { buf, len: 0 };
+        fmt::write(&mut w, format_args!("{}", self)).map_err(|_| BufTooSmall)?;
+
+        let BufWriter { buf, len } = w;
+        // Only `&str`s were written
+        core::str::from_utf8(&buf[..len]).map_err(|_| BufTooSmall)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, U> Quantity<S, U>
+where
+    U: UnitTrait,
+{
+    /// Renders the quantity into a `String` with the exact unit (the same as
+    /// `Display` impl).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// assert_eq!(1500.m().to_si_string(), "1500 m");
+    /// assert_eq!(2.h().to_si_string(), "2 h");
+    /// ```
+    #[inline]
+    pub fn to_si_string(&self) -> String
+    where
+        Self: Display,
+    {
+        self.to_string()
+    }
+
+    /// Renders the quantity into a `String` in engineering form, i.e. with
+    /// the SI prefix that makes the value fall into `[1; 1000)`.
+    ///
+    /// The value is rendered as `f64` with at most 3 digits after the
+    /// decimal point. The prefix is applied to the unit of the quantity if it
+    /// has a symbol (e.g. `Wh` for `kWh`), otherwise to the base unit of the
+    /// same dimensions (e.g. `s` for `min`). Units those can't be prefixed
+    /// (e.g. `m/s`) are shown in the base unit without a prefix.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{prefixes::Milli, units::Second, IntExt, Quantity};
+    ///
+    /// assert_eq!(1500.m().to_engineering_string(), "1.5 km");
+    /// assert_eq!(Quantity::<_, Milli<Second>>::new(2).to_engineering_string(), "2 ms");
+    /// assert_eq!(0.000_012_5.s().to_engineering_string(), "12.5 μs");
+    /// assert_eq!(2.kg().to_engineering_string(), "2 kg");
+    /// assert_eq!(36.kmph().to_engineering_string(), "10 m/s");
+    /// assert_eq!(2500.wh().to_engineering_string(), "2.5 kWh");
+    /// assert_eq!(90.min_().to_engineering_string(), "5.4 ks");
+    /// ```
+    #[inline]
+    pub fn to_engineering_string(&self) -> String
+    where
+        S: ToF64 + Copy,
+    {
+        let own = RtUnit::of::<U>();
+        let unit = RtUnit {
+            ratio: RtFraction {
+                numerator: 1,
+                divisor: 1,
+            },
+            ..own
+        };
+        let value = self.to_base_f64();
+
+        // The unit the quantity is written in (maybe with a prefix) is the best
+        // choice, e.g. `Wh` for `kWh`, otherwise the first unit of the same
+        // dimensions is used (base units go first in the table, so e.g. `J` is
+        // preferred over `Wh`)
+        let mut candidates = SIMPLE_UNITS
+            .iter()
+            .filter(|(_, simple)| simple.dimensions == unit.dimensions);
+        let best = candidates
+            .clone()
+            .find(|(_, simple)| is_prefixed(own.ratio, simple.ratio))
+            .or_else(|| candidates.next());
+        let (symbol, mut value) = match best {
+            Some(&(symbol, simple)) => (
+                symbol,
+                value * simple.ratio.divisor.to_f64() / simple.ratio.numerator.to_f64(),
+            ),
+            None => return format!("{} {}", format_decimal(value), unit),
+        };
+
+        let mut exp = 0;
+        if value.is_finite() && value != 0.0 {
+            while (value >= 1000.0 || value <= -1000.0) && exp < 30 {
+                value /= 1000.0;
+                exp += 3;
+            }
+            while value > -1.0 && value < 1.0 && exp > -30 {
+                value *= 1000.0;
+                exp -= 3;
+            }
+        }
+
+        format!(
+            "{} {}{}",
+            format_decimal(value),
+            crate::prefixes::symbol(exp).unwrap_or_default(),
+            symbol
+        )
+    }
+}
+
+/// Returns `true` if `ratio` is `base` with an SI prefix, i.e. they differ by
+/// a power of 10
+#[cfg(feature = "alloc")]
+fn is_prefixed(ratio: RtFraction, base: RtFraction) -> bool {
+    ratio
+        .checked_mul(RtFraction {
+            numerator: base.divisor,
+            divisor: base.numerator,
+        })
+        .and_then(|quot| quot.pow10())
+        .is_some()
+}
+
+/// Formats `f64` with at most 3 digits after the decimal point, without
+/// trailing zeros
+#[cfg(feature = "alloc")]
+fn format_decimal(value: f64) -> String {
+    let mut s = format!("{:.3}", value);
+    if s.contains('.') {
+        let len = s.trim_end_matches('0').trim_end_matches('.').len();
+        s.truncate(len);
+    }
+    if s == "-0" {
+        s.remove(0);
+    }
+    s
+}
+
+/// Splits the whole number of `T`s off the `rest`
+fn split_off<S, T>(rest: &mut Quantity<S, Second>) -> Quantity<S, T>
+where
+    T: UnitTrait<Dimensions = <Second as UnitTrait>::Dimensions>,
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S> + Sub<Output = S> + Copy,
+{
+    let part = rest.into_unit::<T>();
+    *rest = Quantity::new(rest.into_inner() - part.into_unit::<Second>().into_inner());
+    part
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        display::{BufTooSmall, Granularity, UnitStyle},
+        prefixes::{Centi, Kilo, Milli},
+        units::{Metre, Newton, NewtonMetre, Second},
+        IntExt, Quantity,
+    };
+
+    #[test]
+    fn display_builder() {
+        use crate::units::{Ohm, PascalSecond};
+
+        let q = Quantity::<i32, Kilo<Ohm>>::new(-4);
+        assert_eq!(format!("{}", q.display()), format!("{}", q));
+        assert_eq!(format!("{:>4}", q.display().no_unit()), "  -4");
+        assert_eq!(
+            format!("{}", q.display().unit_style(UnitStyle::Ascii)),
+            "-4 kOhm"
+        );
+
+        let sep = String::from("; ");
+        let q = Quantity::<f64, PascalSecond>::new(0.89);
+        let d = q.display().separator(&sep).unit_style(UnitStyle::Ascii);
+        assert_eq!(format!("{}", d), "0.89; Pa*s");
+        assert_eq!(format!("{}", d.no_unit()), "0.89");
+        assert_eq!(format!("{}", q.display().separator("")), "0.89Pa·s");
+    }
+
+    #[test]
+    fn fmt_into() {
+        fn check<S, U>(q: Quantity<S, U>)
+        where
+            Quantity<S, U>: core::fmt::Display,
+        {
+            let mut buf = [0; 64];
+            assert_eq!(q.fmt_into(&mut buf).unwrap(), format!("{}", q));
+        }
+
+        check(0u8.m());
+        check(i128::MIN.s());
+        check(u128::MAX.kg());
+        check(Quantity::<f32, Milli<Second>>::new(-0.25));
+        check(Quantity::<_, Milli<Metre>>::new(1500));
+        check(Quantity::<i32, Kilo<Kilo<Metre>>>::new(-7) * 2.km());
+        check(5.m() / 2.s() / 1.s() / 1.s());
+        check(Quantity::<u16, Centi<Centi<Second>>>::new(3) * 1.m());
+        check(Quantity::<i64, Milli<Milli<Second>>>::new(-3) / 1.m());
+
+        assert_eq!(100.m().fmt_into(&mut [0; 5]), Ok("100 m"));
+        assert_eq!(100.m().fmt_into(&mut [0; 4]), Err(BufTooSmall));
+        assert_eq!(100.m().fmt_into(&mut []), Err(BufTooSmall));
+    }
+
+    #[test]
+    fn humanize() {
+        assert_eq!(format!("{}", 5415.s().humanize()), "1 h 30 min 15 s");
+        assert_eq!(format!("{}", 86_400u32.s().humanize()), "1 d");
+        assert_eq!(format!("{}", 61i64.s().humanize()), "1 min 1 s");
+        assert_eq!(format!("{}", 2.h().humanize()), "2 h");
+        assert_eq!(
+            format!("{}", Quantity::<_, Milli<Second>>::new(61_999).humanize()),
+            "1 min 1 s"
+        );
+
+        assert_eq!(
+            format!("{}", (-90_061).s().humanize()),
+            "-1 d 1 h 1 min 1 s"
+        );
+        assert_eq!(
+            format!("{}", i32::MIN.s().humanize()),
+            "-24855 d 3 h 14 min 8 s"
+        );
+        assert_eq!(
+            format!("{}", i64::MIN.s().humanize().granularity(Granularity::Day)),
+            "-106751991167300 d"
+        );
+    }
+
+    #[test]
+    fn humanize_granularity() {
+        let it = 90_061.s().humanize();
+        assert_eq!(format!("{}", it.granularity(Granularity::Day)), "1 d");
+        assert_eq!(
+            format!("{}", 90_061.s().humanize().granularity(Granularity::Hour)),
+            "1 d 1 h"
+        );
+        assert_eq!(
+            format!("{}", 3599.s().humanize().granularity(Granularity::Hour)),
+            "0 h"
+        );
+    }
+
+    #[test]
+    fn display_in() {
+        assert_eq!(format!("{}", 3.km().display_in::<Metre>()), "3000 m");
+        assert_eq!(
+            format!("{}", 25.dm().display_in::<Centi<Metre>>()),
+            "250 cm"
+        );
+        assert_eq!(
+            format!("{:.1}", 1234.m().display_in::<Kilo<Metre>>()),
+            "1.2 km"
+        );
+        assert_eq!(format!("{}", 2.5f32.m().display_in::<Metre>()), "2.5 m");
+        assert_eq!(
+            format!(
+                "{}",
+                Quantity::<u8, Milli<Second>>::new(250).display_in::<Second>()
+            ),
+            "0.25 s"
+        );
+
+        // Ratios those don't fit into `u64`
+        let far = Quantity::<f64, crate::prefixes::Yotta<Metre>>::new(5.0);
+        assert_eq!(
+            format!("{:.0}", far.display_in::<crate::prefixes::Zetta<Metre>>()),
+            "5000 Zm"
+        );
+        assert!((far.to_base_f64() - 5e24).abs() < 1e10);
+    }
+
+    #[test]
+    fn display_torque() {
+        let torque = Quantity::<i32, Milli<NewtonMetre>>::new(1500);
+        assert_eq!(format!("{}", torque.display_torque()), "1.5 N·m");
+        assert_eq!(
+            format!(
+                "{}",
+                (Quantity::<i32, Newton>::new(2) * 3.m()).display_torque()
+            ),
+            "6 N·m"
+        );
+
+        // The ratio doesn't fit into `u64`
+        let big = Quantity::<f64, crate::prefixes::Yotta<NewtonMetre>>::new(2.0);
+        assert_eq!(format!("{}", big.display_torque()), format!("{} N·m", 2e24));
+    }
+
+    #[test]
+    fn display_symbol() {
+        use super::UnitSymbol;
+        use typenum::{Prod, U1000, U523, U8};
+
+        use crate::{
+            prefixes::{DivBy, Mega, MulBy},
+            units::Joule,
+        };
+
+        type Cal = DivBy<MulBy<Joule, Prod<U8, U523>>, U1000>;
+
+        struct CalSymbol;
+
+        impl UnitSymbol for CalSymbol {
+            type Unit = Cal;
+            const SYMBOL: &'static str = "cal";
+        }
+
+        assert_eq!(
+            format!(
+                "{}",
+                Quantity::<u32, Kilo<Cal>>::new(2).display_symbol::<CalSymbol>()
+            ),
+            "2 kcal"
+        );
+        assert_eq!(
+            format!(
+                "{:.1}",
+                Quantity::<f64, Mega<Cal>>::new(0.25).display_symbol::<CalSymbol>()
+            ),
+            "0.2 Mcal"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Quantity::<i32, Cal>::new(-7).display_symbol::<CalSymbol>()
+            ),
+            "-7 cal"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Quantity::<f64, Joule>::new(4184.0).display_symbol::<CalSymbol>()
+            ),
+            "1000 cal"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "alloc"), ignore)]
+    fn strings() {
+        #[cfg(feature = "alloc")]
+        {
+            use crate::{
+                prefixes::Micro,
+                units::{Gram, Watt},
+            };
+
+            assert_eq!(25.dm().to_si_string(), "25 dm");
+            assert_eq!(25.dm().to_engineering_string(), "2.5 m");
+            assert_eq!((-1234).m().to_engineering_string(), "-1.234 km");
+            assert_eq!(0.m().to_engineering_string(), "0 m");
+            assert_eq!(
+                Quantity::<_, Micro<Second>>::new(1_500_000).to_engineering_string(),
+                "1.5 s"
+            );
+            // The unit of the quantity is preferred over the base one
+            assert_eq!(3.wh().to_engineering_string(), "3 Wh");
+            assert_eq!(3000.wh().to_engineering_string(), "3 kWh");
+            assert_eq!(
+                Quantity::<_, Kilo<crate::units::Joule>>::new(10.8).to_engineering_string(),
+                "10.8 kJ"
+            );
+            assert_eq!(
+                Quantity::<_, crate::units::Minute>::new(90).to_engineering_string(),
+                "5.4 ks"
+            );
+            assert_eq!(
+                Quantity::<_, Milli<Gram>>::new(2).to_engineering_string(),
+                "2 mg"
+            );
+            assert_eq!(
+                Quantity::<_, Watt>::new(2.0e30).to_engineering_string(),
+                "2 QW"
+            );
+            assert_eq!(
+                Quantity::<_, Watt>::new(2.0e36).to_engineering_string(),
+                "2000000 QW"
+            );
+            assert_eq!((3.m() * 1.s()).to_engineering_string(), "3 m * s");
+        }
+    }
+}
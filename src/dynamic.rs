@@ -0,0 +1,589 @@
+use core::fmt::{self, Display};
+
+use crate::{
+    rt::{explain_mismatch, RtUnit},
+    Quantity, UnitTrait,
+};
+
+/// Quantity with unit that is known only at runtime.
+///
+/// This is useful when the unit comes from outside of the program (e.g. from
+/// a configuration file). `DynQuantity` can be checked against a typed
+/// target with [`try_into_quantity`].
+///
+/// With `deser` feature `DynQuantity` is (de)serialized as
+/// `{ "value": 10, "unit": "km/h" }`, see also [`compact`] for string form.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     dynamic::DynQuantity,
+///     rt::RtUnit,
+///     units::{KiloMetrePerHour, Metre},
+///     IntExt, Quantity,
+/// };
+///
+/// let q = DynQuantity::new(10, RtUnit::parse("km/h").unwrap());
+/// assert_eq!(q.to_string(), "10 km/h");
+///
+/// let typed: Quantity<_, KiloMetrePerHour> = q.try_into_quantity().unwrap();
+/// assert_eq!(typed, 10.kmph());
+///
+/// // Units must be the same
+/// assert!(q.try_into_quantity::<Metre>().is_err());
+///
+/// // Typed quantities can be converted to dynamic ones
+/// assert_eq!(DynQuantity::from(10.kmph()), q);
+/// ```
+///
+/// [`try_into_quantity`]: DynQuantity::try_into_quantity
+/// [`compact`]: crate::dynamic::compact
+#[cfg_attr(feature = "deser", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynQuantity<S> {
+    value: S,
+    unit: RtUnit,
+}
+
+impl<S> DynQuantity<S> {
+    /// Creates new quantity from the given value and unit.
+    ///
+    /// The ratio of the unit is reduced, so e.g. ratio `2000/2` is the same as
+    /// `1000/1` (and as ratio of [`RtUnit::of`] `Kilo<_>`).
+    #[inline]
+    pub const fn new(value: S, unit: RtUnit) -> Self {
+        let unit = RtUnit {
+            dimensions: unit.dimensions,
+            ratio: unit.ratio.reduced(),
+        };
+        Self { value, unit }
+    }
+
+    /// Returns the unit of the quantity.
+    #[inline]
+    pub fn unit(&self) -> RtUnit {
+        self.unit
+    }
+
+    /// Return inner value.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.value
+    }
+
+    /// Converts self into typed quantity, returns error if the unit isn't
+    /// the same as `U`.
+    ///
+    /// Note: units are compared by dimensions and by value of the ratio, so
+    /// e.g. `Kilo<Gram>` and `KiloGram` are the same.
+    #[inline]
+    pub fn try_into_quantity<U>(self) -> Result<Quantity<S, U>, UnitMismatch>
+    where
+        U: UnitTrait,
+    {
+        let expected = RtUnit::of::<U>();
+        if self.unit == expected {
+            Ok(Quantity::new(self.value))
+        } else {
+            Err(UnitMismatch {
+                expected,
+                found: self.unit,
+            })
+        }
+    }
+}
+
+impl<S, U> From<Quantity<S, U>> for DynQuantity<S>
+where
+    U: UnitTrait,
+{
+    #[inline]
+    fn from(quantity: Quantity<S, U>) -> Self {
+        Self::new(quantity.into_inner(), RtUnit::of::<U>())
+    }
+}
+
+impl<S> Display for DynQuantity<S>
+where
+    S: Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{} {}", self.value, self.unit))
+    }
+}
+
+/// Error returned by [`DynQuantity::try_into_quantity`] when the units are
+/// different.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{dynamic::DynQuantity, units::MetrePerSecond, IntExt};
+///
+/// let err = DynQuantity::from(10.m())
+///     .try_into_quantity::<MetrePerSecond>()
+///     .unwrap_err();
+///
+/// assert_eq!(
+///     err.to_string(),
+///     "expected `m/s`, found `m`: left has s^-1, right has s^0"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitMismatch {
+    /// The unit of the target type
+    pub expected: RtUnit,
+    /// The unit of the dynamic quantity
+    pub found: RtUnit,
+}
+
+impl Display for UnitMismatch {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "expected `{}`, found `{}`: {}",
+            self.expected,
+            self.found,
+            explain_mismatch(self.expected, self.found),
+        ))
+    }
+}
+
+/// (De)serialization of [`DynQuantity`] in compact string form (e.g.
+/// `"10 km/h"`). Use with `#[serde(with = "typed_phy::dynamic::compact")]`.
+///
+/// ## Examples
+///
+/// ```
+/// # #[cfg(feature = "deser")] {
+/// use serde::{Deserialize, Serialize};
+/// use typed_phy::dynamic::DynQuantity;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "typed_phy::dynamic::compact")]
+///     speed_limit: DynQuantity<u32>,
+/// }
+/// # }
+/// ```
+#[cfg(feature = "deser")]
+pub mod compact {
+    use core::{fmt, marker::PhantomData, str::FromStr};
+
+    use serde::{de, Deserializer, Serializer};
+
+    use super::DynQuantity;
+    use crate::rt::RtUnit;
+
+    /// Serializes quantity as `"{value} {unit}"`.
+    #[inline]
+    pub fn serialize<S, Ser>(
+        quantity: &DynQuantity<S>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        S: fmt::Display,
+        Ser: Serializer,
+    {
+        if !quantity.unit.is_parsable() {
+            return Err(serde::ser::Error::custom(format_args!(
+                "unit `{}` can't be represented as a string",
+                quantity.unit
+            )));
+        }
+
+        serializer.collect_str(quantity)
+    }
+
+    /// Deserializes quantity from `"{value} {unit}"` (value and unit are
+    /// separated by whitespace).
+    #[inline]
+    pub fn deserialize<'de, S, D>(deserializer: D) -> Result<DynQuantity<S>, D::Error>
+    where
+        S: FromStr,
+        S::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        struct Visitor<S>(PhantomData<S>);
+
+        impl<'de, S> de::Visitor<'de> for Visitor<S>
+        where
+            S: FromStr,
+            S::Err: fmt::Display,
+        {
+            type Value = DynQuantity<S>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a quantity (like `10 km/h`)")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let v = v.trim();
+                let (value, unit) = v
+                    .find(char::is_whitespace)
+                    .map(|i| v.split_at(i))
+                    .ok_or_else(|| E::custom("expected value and unit separated by whitespace"))?;
+
+                Ok(DynQuantity::new(
+                    value.parse().map_err(E::custom)?,
+                    RtUnit::parse(unit.trim()).map_err(E::custom)?,
+                ))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor(PhantomData))
+    }
+}
+
+/// Helpers for columnar data (e.g. CSV) where the unit is declared once per
+/// column and cells contain only values.
+///
+/// Use with `#[serde(with = "typed_phy::dynamic::column")]` on
+/// `Vec<Quantity<_, _>>` fields: the column is (de)serialized as
+/// `{ "unit": "km/h", "values": [10, 20] }`, the unit is checked once for the
+/// whole column. For formats with headers, [`header`] and [`parse_header`]
+/// attach/strip the unit to/from the column name (`"speed [km/h]"`).
+///
+/// ## Examples
+///
+/// ```
+/// # #[cfg(all(feature = "deser", feature = "alloc"))] {
+/// use serde::{Deserialize, Serialize};
+/// use typed_phy::{
+///     dynamic::column,
+///     units::{KiloMetrePerHour, Second},
+///     Quantity,
+/// };
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Log {
+///     #[serde(with = "typed_phy::dynamic::column")]
+///     speed: Vec<Quantity<u32, KiloMetrePerHour>>,
+/// }
+///
+/// assert_eq!(column::header::<KiloMetrePerHour>("speed"), "speed [km/h]");
+/// assert_eq!(
+///     column::parse_header::<KiloMetrePerHour>("speed [km/h]"),
+///     Ok("speed")
+/// );
+/// assert!(column::parse_header::<Second>("speed [km/h]").is_err());
+/// # }
+/// ```
+#[cfg(all(feature = "deser", feature = "alloc"))]
+pub mod column {
+    use alloc::{format, string::String, vec::Vec};
+    use core::fmt::{self, Display};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::UnitMismatch;
+    use crate::{
+        rt::{ParseUnitError, RtUnit},
+        Quantity, UnitTrait,
+    };
+
+    /// Serializes values as `{ "unit": "{unit}", "values": [...] }`.
+    #[inline]
+    pub fn serialize<S, U, Ser>(
+        values: &[Quantity<S, U>],
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        S: Serialize,
+        U: UnitTrait,
+        Ser: Serializer,
+    {
+        struct UnitStr(RtUnit);
+
+        impl Serialize for UnitStr {
+            fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+                serializer.collect_str(&self.0)
+            }
+        }
+
+        #[derive(Serialize)]
+        struct Column<'a, S> {
+            unit: UnitStr,
+            values: &'a [S],
+        }
+
+        let unit = RtUnit::of::<U>();
+        if !unit.is_parsable() {
+            return Err(serde::ser::Error::custom(format_args!(
+                "unit `{}` can't be represented as a string",
+                unit
+            )));
+        }
+
+        Column {
+            unit: UnitStr(unit),
+            values,
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes values from `{ "unit": "{unit}", "values": [...] }`,
+    /// returns error if the unit isn't the same as `U`.
+    #[inline]
+    pub fn deserialize<'de, S, U, D>(deserializer: D) -> Result<Vec<Quantity<S, U>>, D::Error>
+    where
+        S: Deserialize<'de>,
+        U: UnitTrait,
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Column<S> {
+            unit: String,
+            values: Vec<S>,
+        }
+
+        let Column { unit, values } = Column::deserialize(deserializer)?;
+        check_unit::<U>(&unit).map_err(serde::de::Error::custom)?;
+        Ok(values.into_iter().map(Quantity::new).collect())
+    }
+
+    /// Returns column header with the unit attached: `"{name} [{unit}]"`.
+    #[inline]
+    pub fn header<U: UnitTrait>(name: &str) -> String {
+        format!("{} [{}]", name, RtUnit::of::<U>())
+    }
+
+    /// Parses header made by [`header`], returns the column name if the unit
+    /// is the same as `U`.
+    #[inline]
+    pub fn parse_header<U: UnitTrait>(header: &str) -> Result<&str, HeaderError> {
+        let (name, unit) = header
+            .strip_suffix(']')
+            .and_then(|rest| rest.rsplit_once('['))
+            .ok_or(HeaderError::MissingUnit)?;
+
+        check_unit::<U>(unit.trim())?;
+        Ok(name.trim_end())
+    }
+
+    fn check_unit<U: UnitTrait>(unit: &str) -> Result<(), HeaderError> {
+        let found = RtUnit::parse(unit).map_err(HeaderError::Parse)?;
+        let found = RtUnit {
+            ratio: found.ratio.reduced(),
+            ..found
+        };
+        let expected = RtUnit::of::<U>();
+        if found == expected {
+            Ok(())
+        } else {
+            Err(HeaderError::Mismatch(UnitMismatch { expected, found }))
+        }
+    }
+
+    /// Error returned when the unit of a column is missing or wrong.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum HeaderError {
+        /// Header doesn't end with `[{unit}]`
+        MissingUnit,
+        /// Unit can't be parsed
+        Parse(ParseUnitError),
+        /// Unit isn't the expected one
+        Mismatch(UnitMismatch),
+    }
+
+    impl Display for HeaderError {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                HeaderError::MissingUnit => {
+                    f.write_str("missing unit (`[unit]`) in the column header")
+                },
+                HeaderError::Parse(err) => f.write_fmt(format_args!("invalid unit: {}", err)),
+                HeaderError::Mismatch(err) => Display::fmt(err, f),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynQuantity;
+    use crate::{
+        prefixes::{Kilo, Milli},
+        rt::{RtFraction, RtUnit},
+        units::{Gram, KiloGram, Metre, Second},
+        IntExt, Quantity,
+    };
+
+    #[test]
+    fn try_into_quantity() {
+        let q = DynQuantity::from(Quantity::<_, Kilo<Gram>>::new(3));
+        assert_eq!(q.try_into_quantity::<KiloGram>(), Ok(3.kg()));
+        assert!(q.try_into_quantity::<Gram>().is_err());
+
+        let q = DynQuantity::new(1.5, RtUnit::parse("ms").unwrap());
+        assert_eq!(
+            q.try_into_quantity::<Milli<Second>>(),
+            Ok(Quantity::new(1.5))
+        );
+        assert_eq!(q.to_string(), "1.5 ms");
+        assert_eq!(
+            q.try_into_quantity::<Second>().unwrap_err().to_string(),
+            "expected `s`, found `ms`: ratios differ by 1000"
+        );
+        assert!(q.try_into_quantity::<Metre>().is_err());
+
+        // Unreduced ratio
+        let mut unit = RtUnit::of::<Metre>();
+        unit.ratio = RtFraction {
+            numerator: 2000,
+            divisor: 2,
+        };
+        let q = DynQuantity::new(7, unit);
+        assert_eq!(q.try_into_quantity::<Kilo<Metre>>(), Ok(7.km()));
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "deser"), ignore)]
+    fn serde() {
+        #[cfg(feature = "deser")]
+        {
+            use serde_test::{
+                assert_de_tokens_error, assert_ser_tokens_error, assert_tokens, Token,
+            };
+
+            assert_tokens(
+                &DynQuantity::from(10.kmph()),
+                &[
+                    Token::Struct {
+                        name: "DynQuantity",
+                        len: 2,
+                    },
+                    Token::Str("value"),
+                    Token::I32(10),
+                    Token::Str("unit"),
+                    Token::Str("km/h"),
+                    Token::StructEnd,
+                ],
+            );
+
+            assert_ser_tokens_error(
+                &DynQuantity::new(1, RtUnit::parse("m/h").unwrap()),
+                &[
+                    Token::Struct {
+                        name: "DynQuantity",
+                        len: 2,
+                    },
+                    Token::Str("value"),
+                    Token::I32(1),
+                    Token::Str("unit"),
+                ],
+                "unit `m * s^-1 (ratio: 1 / 3600)` can't be represented as a string",
+            );
+
+            assert_de_tokens_error::<DynQuantity<i32>>(
+                &[
+                    Token::Struct {
+                        name: "DynQuantity",
+                        len: 2,
+                    },
+                    Token::Str("value"),
+                    Token::I32(1),
+                    Token::Str("unit"),
+                    Token::Str("parsec"),
+                ],
+                "unknown unit symbol",
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "deser"), ignore)]
+    fn serde_compact() {
+        #[cfg(feature = "deser")]
+        {
+            use serde::{Deserialize, Serialize};
+            use serde_test::{assert_tokens, Token};
+
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            #[serde(transparent)]
+            struct Compact(#[serde(with = "super::compact")] DynQuantity<f64>);
+
+            assert_tokens(
+                &Compact(DynQuantity::from(2.5.m() / 1.0.s())),
+                &[Token::Str("2.5 m/s")],
+            );
+            assert_tokens(
+                &Compact(DynQuantity::new(
+                    9.81,
+                    RtUnit::parse("kg*m*s^-2/kg").unwrap(),
+                )),
+                &[Token::Str("9.81 m/s^2")],
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(all(feature = "deser", feature = "alloc")), ignore)]
+    fn serde_column() {
+        #[cfg(all(feature = "deser", feature = "alloc"))]
+        {
+            use alloc::{vec, vec::Vec};
+
+            use serde::{Deserialize, Serialize};
+            use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+            use super::column::{header, parse_header, HeaderError};
+            use crate::units::KiloMetrePerHour;
+
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            #[serde(transparent)]
+            struct Column(#[serde(with = "super::column")] Vec<Quantity<u32, KiloMetrePerHour>>);
+
+            assert_tokens(
+                &Column(vec![10.kmph(), 20.kmph()]),
+                &[
+                    Token::Struct {
+                        name: "Column",
+                        len: 2,
+                    },
+                    Token::Str("unit"),
+                    Token::Str("km/h"),
+                    Token::Str("values"),
+                    Token::Seq { len: Some(2) },
+                    Token::U32(10),
+                    Token::U32(20),
+                    Token::SeqEnd,
+                    Token::StructEnd,
+                ],
+            );
+            assert_de_tokens_error::<Column>(
+                &[
+                    Token::Struct {
+                        name: "Column",
+                        len: 2,
+                    },
+                    Token::Str("unit"),
+                    Token::Str("m/s"),
+                    Token::Str("values"),
+                    Token::Seq { len: Some(0) },
+                    Token::SeqEnd,
+                    Token::StructEnd,
+                ],
+                "expected `km/h`, found `m/s`: ratios differ by 18/5",
+            );
+
+            assert_eq!(header::<Metre>("x"), "x [m]");
+            assert_eq!(parse_header::<Kilo<Gram>>("mass [kg]"), Ok("mass"));
+            assert_eq!(
+                parse_header::<Second>("t[ms]").map_err(|e| e.to_string()),
+                Err("expected `s`, found `ms`: ratios differ by 1000".into())
+            );
+            assert_eq!(parse_header::<Second>("t"), Err(HeaderError::MissingUnit));
+            assert!(matches!(
+                parse_header::<Second>("t [xyz]"),
+                Err(HeaderError::Parse(_))
+            ));
+        }
+    }
+}
@@ -4,8 +4,7 @@ pub trait UnitEq<Rhs>: sealed::UnitEq<Rhs> {}
 
 impl<U: sealed::UnitEq<Rhs>, Rhs> UnitEq<Rhs> for U {}
 
-/// Represent equality of 2 units by equality of their exponents (dimensions)
-/// and equality of their ratios
+/// Represent equality of 2 dimensions by equality of their exponents
 pub trait DimensionsEq<Rhs>: sealed::DimensionsEq<Rhs> {}
 
 impl<D: sealed::DimensionsEq<Rhs>, Rhs> DimensionsEq<Rhs> for D {}
@@ -15,10 +14,25 @@ pub trait FractionEq<Rhs>: sealed::FractionEq<Rhs> {}
 
 impl<T: sealed::FractionEq<Rhs>, Rhs> FractionEq<Rhs> for T {}
 
+/// Represent that 2 units are the same type (units of quantities that can be
+/// added or subtracted)
+#[diagnostic::on_unimplemented(
+    message = "quantities of different units can't be added or subtracted",
+    label = "expected a quantity of the same unit",
+    note = "use `Quantity::into_unit` to convert between units of the same dimensions, or `Quantity::into_same_unit` for equal units"
+)]
+pub trait SameUnit<Rhs>: sealed::SameUnit<Rhs> {}
+
+impl<U: sealed::SameUnit<Rhs>, Rhs> SameUnit<Rhs> for U {}
+
 mod sealed {
     use crate::{fraction::Fraction, DimensionsTrait, UnitTrait};
     use core::ops::Mul;
 
+    pub trait SameUnit<Rhs> {}
+
+    impl<U> SameUnit<U> for U {}
+
     pub trait UnitEq<Rhs> {}
 
     impl<U, Rhs> UnitEq<Rhs> for U
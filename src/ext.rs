@@ -1,10 +1,21 @@
+use core::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul},
+};
+
 use crate::{
-    prefixes::{Deci, Kilo},
+    checked::CheckedFrom,
+    eq::DimensionsEq,
+    fraction::FractionTrait,
+    from_int::{FromUnsigned, TotalCmp, Zero},
+    prefixes::{Deci, Kilo, Micro, Milli},
     units::{
-        Dimensionless, Hour, KiloGram, KiloMetrePerHour, Metre, MetrePerSecond, Minute, Second,
-        SquareMetre,
+        AmpereHour, Carat, Dalton, Dimensionless, Hour, JulianYear, KiloGram, KiloMetrePerHour,
+        KiloWattHour, Knot, LitrePerMinute, Metre, MetrePerSecond, MilePerHour, MilliAmpereHour,
+        Minute, RevolutionsPerMinute, Second, SievertPerHour, SquareMetre, Tonne, Volt, WattHour,
+        Week,
     },
-    Quantity,
+    Quantity, UnitTrait,
 };
 
 /// Extension for integers for creating quantities of common units.
@@ -49,6 +60,21 @@ pub trait IntExt: Sized {
         self.quantity()
     }
 
+    #[inline]
+    fn t(self) -> Quantity<Self, Tonne> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn ct(self) -> Quantity<Self, Carat> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn dalton(self) -> Quantity<Self, Dalton> {
+        self.quantity()
+    }
+
     #[inline]
     fn mps(self) -> Quantity<Self, MetrePerSecond> {
         self.quantity()
@@ -74,16 +100,81 @@ pub trait IntExt: Sized {
         self.quantity()
     }
 
+    #[inline]
+    fn wk(self) -> Quantity<Self, Week> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn yr(self) -> Quantity<Self, JulianYear> {
+        self.quantity()
+    }
+
     #[inline]
     fn kmph(self) -> Quantity<Self, KiloMetrePerHour> {
         self.quantity()
     }
 
+    #[inline]
+    fn kn(self) -> Quantity<Self, Knot> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn mph(self) -> Quantity<Self, MilePerHour> {
+        self.quantity()
+    }
+
     #[inline]
     fn dm(self) -> Quantity<Self, Deci<Metre>> {
         self.quantity()
     }
 
+    #[inline]
+    fn wh(self) -> Quantity<Self, WattHour> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn kwh(self) -> Quantity<Self, KiloWattHour> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn ah(self) -> Quantity<Self, AmpereHour> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn mah(self) -> Quantity<Self, MilliAmpereHour> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn rpm(self) -> Quantity<Self, RevolutionsPerMinute> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn v(self) -> Quantity<Self, Volt> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn mv(self) -> Quantity<Self, Milli<Volt>> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn lpm(self) -> Quantity<Self, LitrePerMinute> {
+        self.quantity()
+    }
+
+    #[inline]
+    fn usvph(self) -> Quantity<Self, Micro<SievertPerHour>> {
+        self.quantity()
+    }
+
     // TODO: other shortcuts
 }
 
@@ -108,3 +199,255 @@ impl IntExt for f32 {}
 impl IntExt for f64 {}
 
 // TODO BigInt support?
+
+/// Extension for slices of quantities (the common shape of measurements).
+///
+/// Methods those compare quantities use total order (see [`TotalCmp`]), so
+/// they are safe to use with floats (even with `NaN`s).
+///
+/// ## Examples
+/// ```
+/// use typed_phy::{IntExt, QuantitySliceExt};
+///
+/// let mut distances = [3.0.m(), 1.0.m(), 2.0.m()];
+///
+/// assert_eq!(distances.sum(), 6.0.m());
+/// assert_eq!(distances.mean(), Some(2.0.m()));
+/// assert_eq!(distances.min_value(), Some(1.0.m()));
+/// assert_eq!(distances.max_value(), Some(3.0.m()));
+///
+/// distances.sort_unstable_by_value();
+/// assert_eq!(distances, [1.0.m(), 2.0.m(), 3.0.m()]);
+/// ```
+pub trait QuantitySliceExt<S, U> {
+    /// Returns sum of all quantities in the slice (zero if the slice is empty)
+    fn sum(&self) -> Quantity<S, U>
+    where
+        S: Add<Output = S> + Zero + Copy;
+
+    /// Returns arithmetic mean of the quantities in the slice or `None` if the
+    /// slice is empty or its length doesn't fit into `S` (e.g. 256 `u8`s)
+    fn mean(&self) -> Option<Quantity<S, U>>
+    where
+        S: Add<Output = S> + Div<Output = S> + CheckedFrom<usize> + Zero + Copy;
+
+    /// Returns the smallest quantity in the slice or `None` if the slice is
+    /// empty
+    fn min_value(&self) -> Option<Quantity<S, U>>
+    where
+        S: TotalCmp + Copy;
+
+    /// Returns the biggest quantity in the slice or `None` if the slice is
+    /// empty
+    fn max_value(&self) -> Option<Quantity<S, U>>
+    where
+        S: TotalCmp + Copy;
+
+    /// Sorts the slice by value (in ascending order), but might not preserve
+    /// the order of equal elements
+    fn sort_unstable_by_value(&mut self)
+    where
+        S: TotalCmp;
+}
+
+impl<S, U> QuantitySliceExt<S, U> for [Quantity<S, U>] {
+    #[inline]
+    fn sum(&self) -> Quantity<S, U>
+    where
+        S: Add<Output = S> + Zero + Copy,
+    {
+        self.iter().sum()
+    }
+
+    #[inline]
+    fn mean(&self) -> Option<Quantity<S, U>>
+    where
+        S: Add<Output = S> + Div<Output = S> + CheckedFrom<usize> + Zero + Copy,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let len = S::checked_from(self.len())?;
+        Some(QuantitySliceExt::sum(self).map(|s| s / len))
+    }
+
+    #[inline]
+    fn min_value(&self) -> Option<Quantity<S, U>>
+    where
+        S: TotalCmp + Copy,
+    {
+        self.iter()
+            .copied()
+            .min_by(|a, b| a.storage_ref().total_cmp(b.storage_ref()))
+    }
+
+    #[inline]
+    fn max_value(&self) -> Option<Quantity<S, U>>
+    where
+        S: TotalCmp + Copy,
+    {
+        self.iter()
+            .copied()
+            .max_by(|a, b| a.storage_ref().total_cmp(b.storage_ref()))
+    }
+
+    #[inline]
+    fn sort_unstable_by_value(&mut self)
+    where
+        S: TotalCmp,
+    {
+        self.sort_unstable_by(|a, b| a.storage_ref().total_cmp(b.storage_ref()))
+    }
+}
+
+/// Extension for iterators of quantities.
+///
+/// ## Examples
+/// ```
+/// use typed_phy::{prefixes::Deci, units::Metre, IntExt, QuantityIterExt};
+///
+/// let sources = [1.km(), 20.km(), 300.km()];
+/// let normalized: Vec<_> = sources
+///     .iter()
+///     .copied()
+///     .convert_units::<Deci<Metre>>()
+///     .collect();
+///
+/// assert_eq!(normalized, [10_000.dm(), 200_000.dm(), 3_000_000.dm()]);
+/// ```
+pub trait QuantityIterExt: Iterator + Sized {
+    /// Lazily converts every quantity to the unit `T` (see
+    /// [`Quantity::into_unit`])
+    #[inline]
+    fn convert_units<T>(self) -> ConvertUnits<Self, T> {
+        ConvertUnits {
+            iter: self,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator> QuantityIterExt for I {}
+
+/// Iterator returned by [`QuantityIterExt::convert_units`].
+#[derive(Debug, Clone)]
+pub struct ConvertUnits<I, T> {
+    iter: I,
+    _unit: PhantomData<fn() -> T>,
+}
+
+impl<I, S, U, T> Iterator for ConvertUnits<I, T>
+where
+    I: Iterator<Item = Quantity<S, U>>,
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+    U: UnitTrait,
+    U::Ratio: FractionTrait,
+    T: UnitTrait,
+    T::Dimensions: DimensionsEq<U::Dimensions>,
+{
+    type Item = Quantity<S, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Quantity::into_unit)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, S, U, T> DoubleEndedIterator for ConvertUnits<I, T>
+where
+    I: DoubleEndedIterator<Item = Quantity<S, U>>,
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+    U: UnitTrait,
+    U::Ratio: FractionTrait,
+    T: UnitTrait,
+    T::Dimensions: DimensionsEq<U::Dimensions>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Quantity::into_unit)
+    }
+}
+
+impl<I, S, U, T> ExactSizeIterator for ConvertUnits<I, T>
+where
+    I: ExactSizeIterator<Item = Quantity<S, U>>,
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+    U: UnitTrait,
+    U::Ratio: FractionTrait,
+    T: UnitTrait,
+    T::Dimensions: DimensionsEq<U::Dimensions>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prefixes::Kilo,
+        units::{Hour, Metre, Second},
+        IntExt, Quantity, QuantityIterExt, QuantitySliceExt,
+    };
+
+    #[test]
+    fn slice() {
+        let empty: [Quantity<u32, Second>; 0] = [];
+        assert_eq!(empty.sum(), 0.s());
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.min_value(), None);
+        assert_eq!(empty.max_value(), None);
+
+        let mut ints = [5.s(), 1.s(), 4.s()];
+        assert_eq!(ints.sum(), 10.s());
+        assert_eq!(ints.mean(), Some(3.s())); // truncated
+        assert_eq!(ints.min_value(), Some(1.s()));
+        assert_eq!(ints.max_value(), Some(5.s()));
+        ints.sort_unstable_by_value();
+        assert_eq!(ints, [1.s(), 4.s(), 5.s()]);
+
+        // 256 doesn't fit into `u8`
+        assert_eq!([0u8.s(); 255].mean(), Some(0.s()));
+        assert_eq!([0u8.s(); 256].mean(), None);
+    }
+
+    #[test]
+    fn slice_nan() {
+        let mut floats = [1.0.m(), f64::NAN.m(), (-1.0).m()];
+        assert_eq!(floats.min_value(), Some((-1.0).m()));
+        assert!(floats.max_value().unwrap().into_inner().is_nan());
+
+        floats.sort_unstable_by_value();
+        assert_eq!(floats[..2], [(-1.0).m(), 1.0.m()]);
+        assert!(floats[2].into_inner().is_nan());
+    }
+
+    #[test]
+    fn convert_units() {
+        let metres = [1500.m(), 2000.m(), 500.m()];
+        let mut km = metres.iter().copied().convert_units::<Kilo<Metre>>();
+        assert_eq!(km.len(), 3);
+        assert_eq!(km.next_back(), Some(0.km())); // truncated
+        assert_eq!(km.next(), Some(1.km()));
+        assert_eq!(km.next(), Some(2.km()));
+        assert_eq!(km.next(), None);
+
+        // Sources with different units
+        let minutes = [30.min_(), 90.min_()];
+        let hours = [1.h()];
+        let total: Quantity<_, Second> = minutes
+            .iter()
+            .copied()
+            .convert_units::<Second>()
+            .chain(hours.iter().copied().convert_units())
+            .sum();
+        assert_eq!(total, 10_800.s());
+
+        let hours: Vec<Quantity<f64, Hour>> =
+            [5400.0.s()].iter().copied().convert_units().collect();
+        assert_eq!(hours, [1.5.h()]);
+    }
+}
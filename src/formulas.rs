@@ -0,0 +1,276 @@
+//! Dimension-checked physical formulas (requires `formulas` feature).
+//!
+//! Every helper accepts quantities of any unit with the right dimensions (so
+//! e.g. both `N·m` and `mN·m` are accepted as torque) and returns the unit
+//! produced by the multiplication/division of the arguments, i.e. no
+//! conversions (and so no truncation) are made. Passing a quantity of wrong
+//! dimensions doesn't compile:
+//!
+//! ```compile_fail
+//! use typed_phy::{formulas::kinetic_energy, IntExt};
+//!
+//! // Speed is expected, not length
+//! let _ = kinetic_energy(2.kg(), 3.m());
+//! ```
+use core::ops::{Div, Mul};
+
+use typenum::{Prod, Quot, U2};
+
+use crate::{
+    from_int::FromUnsigned,
+    strict::{StorageDiv, StorageMul},
+    units::{Joule, KiloGram, MetrePerSecond, NewtonMetre, RadianPerSecond, Watt},
+    DimensionsEq, Quantity, UnitTrait,
+};
+
+/// Mechanical power of a rotating shaft: `P = τ * ω`.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     formulas::power_from_torque,
+///     units::{NewtonMetre, RadianPerSecond, Watt},
+///     Quantity,
+/// };
+///
+/// let torque = Quantity::<f64, NewtonMetre>::new(2.0);
+/// let omega = Quantity::<f64, RadianPerSecond>::new(50.0);
+/// assert_eq!(power_from_torque(torque, omega), Quantity::<f64, Watt>::new(100.0));
+/// ```
+#[inline]
+pub fn power_from_torque<S, T, W>(
+    torque: Quantity<S, T>,
+    angular_velocity: Quantity<S, W>,
+) -> Quantity<S, Prod<T, W>>
+where
+    S: StorageMul<T, W, Output = S>,
+    T: UnitTrait + Mul<W>,
+    W: UnitTrait,
+    T::Dimensions: DimensionsEq<<NewtonMetre as UnitTrait>::Dimensions>,
+    W::Dimensions: DimensionsEq<<RadianPerSecond as UnitTrait>::Dimensions>,
+{
+    torque * angular_velocity
+}
+
+/// Kinetic energy of a moving body: `E = m * v^2 / 2`.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{formulas::kinetic_energy, units::Joule, IntExt, Quantity};
+///
+/// assert_eq!(kinetic_energy(2.kg(), 3.mps()), Quantity::<_, Joule>::new(9));
+/// ```
+#[inline]
+pub fn kinetic_energy<S, M, V>(
+    mass: Quantity<S, M>,
+    velocity: Quantity<S, V>,
+) -> Quantity<S, Prod<Prod<M, V>, V>>
+where
+    S: FromUnsigned
+        + StorageMul<M, V, Output = S>
+        + StorageMul<Prod<M, V>, V, Output = S>
+        + Div<Output = S>
+        + Copy,
+    M: UnitTrait + Mul<V>,
+    V: UnitTrait,
+    Prod<M, V>: UnitTrait + Mul<V>,
+    M::Dimensions: DimensionsEq<<KiloGram as UnitTrait>::Dimensions>,
+    V::Dimensions: DimensionsEq<<MetrePerSecond as UnitTrait>::Dimensions>,
+{
+    (mass * velocity * velocity).map(|e| e / S::from_unsigned::<U2>())
+}
+
+/// Ohm's law (`V = I * R`) and electrical power (`P = V * I`).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     formulas::ohms_law,
+///     prefixes::{Kilo, Milli},
+///     units::{Ohm, Volt},
+///     Quantity,
+/// };
+///
+/// let v = Quantity::<u32, Milli<Volt>>::new(3300);
+/// let r = Quantity::<u32, Kilo<Ohm>>::new(1);
+///
+/// // mV / kΩ = μA
+/// let i = ohms_law::current(v, r);
+/// assert_eq!(format!("{}", i), "3300 μA");
+/// assert_eq!(ohms_law::voltage(i, r), v);
+/// ```
+pub mod ohms_law {
+    use core::ops::{Div, Mul};
+
+    use typenum::{Prod, Quot};
+
+    use crate::{
+        strict::{StorageDiv, StorageMul},
+        units::{Ampere, Ohm, Volt},
+        DimensionsEq, Quantity, UnitTrait,
+    };
+
+    /// Current through a resistor: `I = V / R`.
+    #[inline]
+    pub fn current<S, V, R>(
+        voltage: Quantity<S, V>,
+        resistance: Quantity<S, R>,
+    ) -> Quantity<S, Quot<V, R>>
+    where
+        S: StorageDiv<V, R, Output = S>,
+        V: UnitTrait + Div<R>,
+        R: UnitTrait,
+        V::Dimensions: DimensionsEq<<Volt as UnitTrait>::Dimensions>,
+        R::Dimensions: DimensionsEq<<Ohm as UnitTrait>::Dimensions>,
+    {
+        voltage / resistance
+    }
+
+    /// Voltage across a resistor: `V = I * R`.
+    #[inline]
+    pub fn voltage<S, I, R>(
+        current: Quantity<S, I>,
+        resistance: Quantity<S, R>,
+    ) -> Quantity<S, Prod<I, R>>
+    where
+        S: StorageMul<I, R, Output = S>,
+        I: UnitTrait + Mul<R>,
+        R: UnitTrait,
+        I::Dimensions: DimensionsEq<<Ampere as UnitTrait>::Dimensions>,
+        R::Dimensions: DimensionsEq<<Ohm as UnitTrait>::Dimensions>,
+    {
+        current * resistance
+    }
+
+    /// Resistance of a resistor: `R = V / I`.
+    #[inline]
+    pub fn resistance<S, V, I>(
+        voltage: Quantity<S, V>,
+        current: Quantity<S, I>,
+    ) -> Quantity<S, Quot<V, I>>
+    where
+        S: StorageDiv<V, I, Output = S>,
+        V: UnitTrait + Div<I>,
+        I: UnitTrait,
+        V::Dimensions: DimensionsEq<<Volt as UnitTrait>::Dimensions>,
+        I::Dimensions: DimensionsEq<<Ampere as UnitTrait>::Dimensions>,
+    {
+        voltage / current
+    }
+
+    /// Dissipated power: `P = V * I`.
+    #[inline]
+    pub fn power<S, V, I>(
+        voltage: Quantity<S, V>,
+        current: Quantity<S, I>,
+    ) -> Quantity<S, Prod<V, I>>
+    where
+        S: StorageMul<V, I, Output = S>,
+        V: UnitTrait + Mul<I>,
+        I: UnitTrait,
+        V::Dimensions: DimensionsEq<<Volt as UnitTrait>::Dimensions>,
+        I::Dimensions: DimensionsEq<<Ampere as UnitTrait>::Dimensions>,
+    {
+        voltage * current
+    }
+}
+
+/// Battery runtime: `t = E / P`.
+///
+/// The output unit is the quotient of the input units, so `Wh / W` gives
+/// hours and `kWh / W` gives kilohours, use `into_unit` to get the time unit
+/// you need.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     formulas::battery_runtime,
+///     prefixes::Milli,
+///     units::{Hour, Minute, Watt},
+///     IntExt, Quantity,
+/// };
+///
+/// let load = Quantity::<u32, Watt>::new(5);
+/// assert_eq!(battery_runtime(10.wh(), load), 2.h());
+///
+/// // `Wh / mW` is in kilohours
+/// let load = Quantity::<f64, Milli<Watt>>::new(500.0);
+/// let t = battery_runtime(1.0.wh(), load);
+/// assert_eq!(t.into_unit::<Hour>(), 2.0.h());
+/// assert_eq!(t.into_unit::<Minute>(), 120.0.min_());
+/// ```
+#[inline]
+pub fn battery_runtime<S, E, P>(
+    capacity: Quantity<S, E>,
+    load: Quantity<S, P>,
+) -> Quantity<S, Quot<E, P>>
+where
+    S: StorageDiv<E, P, Output = S>,
+    E: UnitTrait + Div<P>,
+    P: UnitTrait,
+    E::Dimensions: DimensionsEq<<Joule as UnitTrait>::Dimensions>,
+    P::Dimensions: DimensionsEq<<Watt as UnitTrait>::Dimensions>,
+{
+    capacity / load
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{battery_runtime, kinetic_energy, ohms_law, power_from_torque};
+    use crate::{
+        prefixes::{Kilo, Milli},
+        units::{
+            Ampere, Hour, KiloGram, NewtonMetre, Ohm, RevolutionsPerMinute, Second, Volt, Watt,
+            WattHourPerKiloGram, WattPerKiloGram,
+        },
+        IntExt, Quantity,
+    };
+
+    #[test]
+    fn mechanics() {
+        // Ratio of the inputs is kept
+        let p = power_from_torque(
+            Quantity::<f64, Milli<NewtonMetre>>::new(500.0),
+            Quantity::<f64, RevolutionsPerMinute>::new(60.0).to_angular_velocity(),
+        );
+        assert!((p.into_unit::<Watt>().into_inner() - core::f64::consts::PI).abs() < 1e-12);
+
+        assert_eq!(kinetic_energy(1.0.kg(), 2.0.mps()), 2.0.quantity());
+        assert_eq!(
+            kinetic_energy(Quantity::<_, Kilo<KiloGram>>::new(1), 2.mps()).into_base(),
+            2000.quantity()
+        );
+    }
+
+    #[test]
+    fn electrics() {
+        let v = Quantity::<i32, Volt>::new(12);
+        let r = Quantity::<i32, Ohm>::new(4);
+        let i = ohms_law::current(v, r);
+        assert_eq!(i, Quantity::<_, Ampere>::new(3));
+        assert_eq!(ohms_law::resistance(v, i), r);
+        assert_eq!(ohms_law::power(v, i), Quantity::<_, Watt>::new(36));
+    }
+
+    #[test]
+    fn battery() {
+        // 3000 mAh at 3.7 V
+        let capacity = (3000.0f64.mah() * 3.7.v()).into_unit::<crate::units::WattHour>();
+        assert!((capacity.into_inner() - 11.1).abs() < 1e-9);
+
+        let t: Quantity<_, Hour> = battery_runtime(capacity, 2.22.quantity::<Watt>());
+        assert!((t.into_inner() - 5.0).abs() < 1e-9);
+        assert!((t.into_unit::<Second>().into_inner() - 18_000.0).abs() < 1e-6);
+
+        // Specific energy and power of a 50 g cell
+        let mass = 50.0f64.quantity::<crate::units::Gram>();
+        let specific: Quantity<_, WattHourPerKiloGram> = (capacity / mass).into_unit();
+        assert!((specific.into_inner() - 222.0).abs() < 1e-9);
+        let power: Quantity<_, WattPerKiloGram> = (specific / t).into_unit();
+        assert!((power.into_inner() - 44.4).abs() < 1e-9);
+    }
+}
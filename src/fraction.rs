@@ -1,9 +1,12 @@
 use core::{
     fmt::{self, Write},
-    ops::{Div, Mul},
+    ops::{Add, Div, Mul, Sub},
 };
 
-use typenum::{Prod, UInt, Unsigned, U0, U1};
+use typenum::{
+    Cmp, Compare, Diff, Exp, Integer, IsLessOrEqual, LeEq, NInt, NonZero, PInt, Pow, Prod, Quot,
+    Sum, UInt, Unsigned, U0, U1, U10, Z0,
+};
 
 use crate::{eq::FractionEq, from_int::FromUnsigned};
 
@@ -72,6 +75,40 @@ pub trait FractionTrait {
     /// The divisor of the fraction
     type Divisor: Unsigned;
 
+    /// The numerator of the fraction as a runtime value
+    const NUMERATOR: u128 = Self::Numerator::U128;
+
+    /// The divisor of the fraction as a runtime value
+    const DIVISOR: u128 = Self::Divisor::U128;
+
+    /// Returns value of this fraction as `f64`.
+    ///
+    /// Useful for reading scale of a unit at runtime (e.g. for GUIs or
+    /// serializers).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{
+    ///     fraction::FractionTrait,
+    ///     prefixes::{Kilo, Milli},
+    ///     units::Metre,
+    ///     Frac, UnitTrait,
+    /// };
+    /// use typenum::{U1, U4};
+    ///
+    /// assert_eq!(<Frac![U1 / U4]>::NUMERATOR, 1);
+    /// assert_eq!(<Frac![U1 / U4]>::DIVISOR, 4);
+    /// assert_eq!(<Frac![U1 / U4]>::to_f64(), 0.25);
+    ///
+    /// assert_eq!(<Kilo<Metre> as UnitTrait>::Ratio::to_f64(), 1000.0);
+    /// assert_eq!(<Milli<Metre> as UnitTrait>::Ratio::to_f64(), 0.001);
+    /// ```
+    #[inline]
+    fn to_f64() -> f64 {
+        Self::NUMERATOR as f64 / Self::DIVISOR as f64
+    }
+
     // Note: I would like to remove mul/div and instead use Mul/Div traits, but I
     // can't make both       `impl<T: FromInteger + ...> Mul/Div<T> for
     // Fraction<>` and all the type level Mul/Divs       at the same time. It is
@@ -191,6 +228,140 @@ where
     }
 }
 
+/// `(n/d) ^ p = (n ^ p)/(d ^ p)`
+impl<N, D, P> Pow<PInt<P>> for Fraction<N, D>
+where
+    P: Unsigned + NonZero,
+    N: Pow<P>,
+    D: Pow<P>,
+{
+    type Output = Fraction<Exp<N, P>, Exp<D, P>>;
+
+    #[inline]
+    fn powi(self, _exp: PInt<P>) -> Self::Output {
+        Self::Output::new()
+    }
+}
+
+/// `(n/d) ^ -p = (d ^ p)/(n ^ p)`
+impl<N, D, P> Pow<NInt<P>> for Fraction<N, D>
+where
+    P: Unsigned + NonZero,
+    N: Pow<P>,
+    D: Pow<P>,
+{
+    type Output = Fraction<Exp<D, P>, Exp<N, P>>;
+
+    #[inline]
+    fn powi(self, _exp: NInt<P>) -> Self::Output {
+        Self::Output::new()
+    }
+}
+
+/// `(n/d) ^ 0 = 1/1`
+impl<N, D> Pow<Z0> for Fraction<N, D> {
+    type Output = One;
+
+    #[inline]
+    fn powi(self, _exp: Z0) -> Self::Output {
+        One::new()
+    }
+}
+
+/// `(n/d) + (a/b) = (n * b + a * d)/(d * b)`
+///
+/// Note: the result isn't simplified, use [`Simplify`] if you need this.
+///
+/// [`Simplify`]: crate::simplify::Simplify
+impl<N, D, A, B> Add<Fraction<A, B>> for Fraction<N, D>
+where
+    N: Mul<B>,
+    A: Mul<D>,
+    D: Mul<B>,
+    Prod<N, B>: Add<Prod<A, D>>,
+{
+    type Output = Fraction<Sum<Prod<N, B>, Prod<A, D>>, Prod<D, B>>;
+
+    #[inline]
+    fn add(self, _rhs: Fraction<A, B>) -> Self::Output {
+        Self::Output::new()
+    }
+}
+
+/// `(n/d) - (a/b) = (n * b - a * d)/(d * b)`
+///
+/// Fractions are unsigned, so `a/b` must be less than or equal to `n/d`,
+/// otherwise this impl doesn't apply.
+///
+/// Note: the result isn't simplified, use [`Simplify`] if you need this.
+///
+/// [`Simplify`]: crate::simplify::Simplify
+impl<N, D, A, B> Sub<Fraction<A, B>> for Fraction<N, D>
+where
+    N: Mul<B>,
+    A: Mul<D>,
+    D: Mul<B>,
+    Prod<N, B>: Sub<Prod<A, D>>,
+{
+    type Output = Fraction<Diff<Prod<N, B>, Prod<A, D>>, Prod<D, B>>;
+
+    #[inline]
+    fn sub(self, _rhs: Fraction<A, B>) -> Self::Output {
+        Self::Output::new()
+    }
+}
+
+/// Type-level comparison of fractions. `(n/d) <=> (a/b)` is the same as
+/// `(n * b) <=> (a * d)` (both divisors are positive, so the multiplication
+/// doesn't change the order), so this uses [`typenum`]'s [`Compare`] and
+/// [`LeEq`] of the cross products.
+///
+/// See also [`FracCompare`] and [`FracLeEq`] aliases.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     fraction::{FracCompare, FracLeEq},
+///     Frac,
+/// };
+/// use typenum::{assert_type_eq, Equal, False, Greater, Less, True, U1, U2, U3, U4};
+///
+/// assert_type_eq!(FracCompare<Frac![U1 / U2], Frac![U2 / U3]>, Less);
+/// assert_type_eq!(FracCompare<Frac![U1 / U2], Frac![U2 / U4]>, Equal);
+/// assert_type_eq!(FracCompare<Frac![U3 / U4], Frac![U2 / U3]>, Greater);
+/// assert_type_eq!(FracLeEq<Frac![U1 / U3], Frac![U1 / U2]>, True);
+/// assert_type_eq!(FracLeEq<Frac![U1 / U2], Frac![U1 / U3]>, False);
+/// ```
+///
+/// [`typenum`]: https://docs.rs/typenum
+pub trait FractionCmp<Rhs> {
+    /// Result of the comparison: [`Less`](typenum::Less),
+    /// [`Equal`](typenum::Equal) or [`Greater`](typenum::Greater)
+    type Output;
+
+    /// [`True`](typenum::True) if `Self <= Rhs`, [`False`](typenum::False)
+    /// otherwise
+    type LessOrEqual;
+}
+
+impl<N, D, A, B> FractionCmp<Fraction<A, B>> for Fraction<N, D>
+where
+    N: Mul<B>,
+    A: Mul<D>,
+    Prod<N, B>: Cmp<Prod<A, D>> + IsLessOrEqual<Prod<A, D>>,
+{
+    type Output = Compare<Prod<N, B>, Prod<A, D>>;
+    type LessOrEqual = LeEq<Prod<N, B>, Prod<A, D>>;
+}
+
+/// Result of the comparison of fractions `A` and `B` (see [`FractionCmp`])
+pub type FracCompare<A, B> = <A as FractionCmp<B>>::Output;
+
+/// [`True`](typenum::True) if fraction `A` is less than or equal to fraction
+/// `B` (see [`FractionCmp`])
+pub type FracLeEq<A, B> = <A as FractionCmp<B>>::LessOrEqual;
+
 impl<N, D, A, B> PartialEq<Fraction<A, B>> for Fraction<N, D>
 where
     Self: FractionEq<Fraction<A, B>>,
@@ -212,8 +383,8 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_fmt(format_args!(
             "Fraction<{numerator}/{divisor}>",
-            numerator = N::U64,
-            divisor = D::U64,
+            numerator = N::U128,
+            divisor = D::U128,
         ))
     }
 }
@@ -225,8 +396,8 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let numerator = N::U64;
-        let divisor = D::U64;
+        let numerator = N::U128;
+        let divisor = D::U128;
 
         if !f.alternate() {
             f.write_fmt(format_args!(
@@ -251,10 +422,175 @@ where
     }
 }
 
+/// **Type-level** fraction `Residual * 10^Exponent`, where `Exponent` is a
+/// type-level integer and `Residual` is a (small) [`Fraction`].
+///
+/// Big powers of ten (like `10^24` of yotta) are enormous types when written
+/// as [`Fraction`], which slows down compilation and makes type errors
+/// unreadable. `Pow10Fraction` keeps the exponent separately, so
+/// multiplication and division only add/subtract exponents and the residuals
+/// stay small. The full numerator and divisor are only computed when
+/// [`FractionTrait`] is used.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     fraction::{FractionTrait, Pow10Fraction},
+///     simplify::Simplify,
+///     Frac,
+/// };
+/// use typenum::{assert_type_eq, Prod, Quot, N3, P21, P24, P3, U1, U2, U3, U6, Z0};
+///
+/// type Yotta = Pow10Fraction<P24, Frac![U1]>;
+/// type Milli = Pow10Fraction<N3, Frac![U1]>;
+///
+/// assert_type_eq!(Prod<Yotta, Milli>, Pow10Fraction<P21, Frac![U1]>);
+/// assert_type_eq!(Quot<Milli, Milli>, Pow10Fraction<Z0, Frac![U1]>);
+///
+/// type X = Pow10Fraction<P3, Frac![U2 / U6]>;
+/// assert_type_eq!(<X as Simplify>::Output, Pow10Fraction<P3, Frac![U1 / U3]>);
+/// assert_eq!(<X as FractionTrait>::NUMERATOR, 2000);
+/// assert_eq!(<X as FractionTrait>::DIVISOR, 6);
+/// ```
+pub struct Pow10Fraction<Exponent, Residual>(phantasm::Invariant<(Exponent, Residual)>);
+
+impl<E, F> Pow10Fraction<E, F> {
+    /// Create new fraction
+    #[inline]
+    pub const fn new() -> Self {
+        Self(phantasm::Invariant)
+    }
+}
+
+impl<E, F> Default for Pow10Fraction<E, F> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Type-level integer `E` that can be split into `10^E = Numerator / Divisor`
+/// (e.g. `10^-3 = 1 / 1000`).
+pub trait Pow10: Integer {
+    /// Numerator of `10^Self`
+    type Numerator: Unsigned;
+
+    /// Divisor of `10^Self`
+    type Divisor: Unsigned;
+}
+
+impl Pow10 for Z0 {
+    type Divisor = U1;
+    type Numerator = U1;
+}
+
+impl<U> Pow10 for PInt<U>
+where
+    U: Unsigned + NonZero,
+    U10: Pow<U>,
+    Exp<U10, U>: Unsigned,
+{
+    type Divisor = U1;
+    type Numerator = Exp<U10, U>;
+}
+
+impl<U> Pow10 for NInt<U>
+where
+    U: Unsigned + NonZero,
+    U10: Pow<U>,
+    Exp<U10, U>: Unsigned,
+{
+    type Divisor = Exp<U10, U>;
+    type Numerator = U1;
+}
+
+impl<E, N, D> FractionTrait for Pow10Fraction<E, Fraction<N, D>>
+where
+    E: Pow10,
+    N: Mul<E::Numerator>,
+    D: Mul<E::Divisor>,
+    Prod<N, E::Numerator>: Unsigned,
+    Prod<D, E::Divisor>: Unsigned,
+{
+    type Divisor = Prod<D, E::Divisor>;
+    type Numerator = Prod<N, E::Numerator>;
+}
+
+/// `(a * 10^x) * (b * 10^y) = (a * b) * 10^(x + y)`
+impl<E0, F0, E1, F1> Mul<Pow10Fraction<E1, F1>> for Pow10Fraction<E0, F0>
+where
+    E0: Add<E1>,
+    F0: Mul<F1>,
+{
+    type Output = Pow10Fraction<Sum<E0, E1>, Prod<F0, F1>>;
+
+    #[inline]
+    fn mul(self, _rhs: Pow10Fraction<E1, F1>) -> Self::Output {
+        Self::Output::new()
+    }
+}
+
+/// `(a * 10^x) / (b * 10^y) = (a / b) * 10^(x - y)`
+impl<E0, F0, E1, F1> Div<Pow10Fraction<E1, F1>> for Pow10Fraction<E0, F0>
+where
+    E0: Sub<E1>,
+    F0: Div<F1>,
+{
+    type Output = Pow10Fraction<Diff<E0, E1>, Quot<F0, F1>>;
+
+    #[inline]
+    fn div(self, _rhs: Pow10Fraction<E1, F1>) -> Self::Output {
+        Self::Output::new()
+    }
+}
+
+/// `(a * 10^x) ^ p = (a ^ p) * 10^(x * p)`
+impl<E, F, P> Pow<P> for Pow10Fraction<E, F>
+where
+    E: Mul<P>,
+    F: Pow<P>,
+{
+    type Output = Pow10Fraction<Prod<E, P>, Exp<F, P>>;
+
+    #[inline]
+    fn powi(self, _exp: P) -> Self::Output {
+        Self::Output::new()
+    }
+}
+
+impl<E, N, D> fmt::Debug for Pow10Fraction<E, Fraction<N, D>>
+where
+    E: Integer,
+    N: Unsigned,
+    D: Unsigned,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "Pow10Fraction<{numerator}/{divisor} * 10^{exp}>",
+            numerator = N::U128,
+            divisor = D::U128,
+            exp = E::I64,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::ops::Mul;
-    use typenum::{U0, U1, U10, U100, U1000, U3, U36};
+    use typenum::{
+        assert_type_eq, Diff, Equal, Exp, Greater, Less, Prod, Quot, Sum, N2, N21, N24, N27, N3,
+        P2, P24, P3, P6, U0, U1, U10, U100, U1000, U2, U27, U3, U36, U4, U5, U6, U8, U9, Z0,
+    };
+
+    use super::{FracCompare, FractionTrait, Pow10Fraction};
+    use crate::{
+        prefixes::{Kilo as KiloM, Ronna, Yotta},
+        simplify::Simplify,
+        units::{Metre, Second},
+        IntExt, Quantity, Unit, UnitTrait,
+    };
 
     type U3600 = <U36 as Mul<U100>>::Output;
 
@@ -288,4 +624,96 @@ mod tests {
             "1000 / 3600"
         );
     }
+
+    #[test]
+    fn cmp() {
+        assert_type_eq!(FracCompare<Frac![U1], Frac![U1 / U1]>, Equal);
+        assert_type_eq!(FracCompare<Frac![U10 / U100], Frac![U100 / U1000]>, Equal);
+        assert_type_eq!(FracCompare<Frac![U1000 / U3600], Frac![U1]>, Less);
+        assert_type_eq!(FracCompare<Frac![U3600 / U1000], Frac![U3]>, Greater);
+        assert_type_eq!(FracCompare<Frac![U0 / U3], Frac![U0 / U10]>, Equal);
+
+        assert_frac_le!(Frac![U1000 / U3600], Frac![U1]);
+        assert_frac_le!(Frac![U3 / U10], Frac![U3 / U10]);
+    }
+
+    #[test]
+    fn add() {
+        assert_type_eq!(Sum<Frac![U1 / U2], Frac![U1 / U3]>, Frac![U5 / U6]);
+        assert_type_eq!(Sum<Frac![U0 / U3], Frac![U2 / U3]>, Frac![U6 / U9]);
+        assert_type_eq!(
+            <Sum<Frac![U1 / U4], Frac![U1 / U4]> as Simplify>::Output,
+            Frac![U1 / U2]
+        );
+    }
+
+    #[test]
+    fn sub() {
+        assert_type_eq!(Diff<Frac![U1 / U2], Frac![U1 / U3]>, Frac![U1 / U6]);
+        assert_type_eq!(Diff<Frac![U3 / U3], Frac![U1]>, Frac![U0 / U3]);
+        assert_eq!(
+            <Diff<Frac![U3 / U4], Frac![U1 / U4]>>::new(),
+            <Frac![U1 / U2]>::new()
+        );
+    }
+
+    #[test]
+    fn big_ratio() {
+        type Ym = <Yotta<Metre> as UnitTrait>::Ratio;
+        type Rkm2 = <Unit![Ronna<Metre> * KiloM<Metre>] as UnitTrait>::Ratio;
+
+        assert_eq!(<Ym as FractionTrait>::NUMERATOR, 10u128.pow(24));
+        assert_eq!(<Rkm2 as FractionTrait>::NUMERATOR, 10u128.pow(30));
+        assert_eq!(<Rkm2 as FractionTrait>::to_f64(), 1e30);
+        assert_eq!(format!("{:#}", Ym::default()), "1000000000000000000000000");
+    }
+
+    #[test]
+    fn pow10() {
+        type Kilo = Pow10Fraction<P3, Frac![U1]>;
+        type Yocto = Pow10Fraction<N24, Frac![U1]>;
+
+        assert_type_eq!(Prod<Kilo, Yocto>, Pow10Fraction<N21, Frac![U1]>);
+        assert_type_eq!(Quot<Yocto, Kilo>, Pow10Fraction<N27, Frac![U1]>);
+        assert_type_eq!(Exp<Kilo, P2>, Pow10Fraction<P6, Frac![U1]>);
+        assert_type_eq!(
+            Prod<Pow10Fraction<P3, Frac![U3 / U4]>, Pow10Fraction<Z0, Frac![U2]>>,
+            Pow10Fraction<P3, Frac![U6 / U4]>
+        );
+
+        assert_eq!(<Kilo as FractionTrait>::NUMERATOR, 1000);
+        assert_eq!(<Exp<Kilo, N2> as FractionTrait>::DIVISOR, 1_000_000);
+        assert_eq!(<Kilo as FractionTrait>::mul(7), 7000);
+
+        // Works as ratio of a unit
+        let km = Quantity::<u32, Unit<<Metre as UnitTrait>::Dimensions, Kilo>>::new(2);
+        assert_eq!(km.into_base(), 2000.m());
+
+        // Arithmetic of units with huge ratios only adds the exponents
+        type Ym = Unit<<Metre as UnitTrait>::Dimensions, Pow10Fraction<P24, Frac![U1]>>;
+        type Ys = Unit<<Second as UnitTrait>::Dimensions, Pow10Fraction<P24, Frac![U1]>>;
+        type Ycm = Unit<<Metre as UnitTrait>::Dimensions, Yocto>;
+        assert_type_eq!(<Quot<Ym, Ys> as UnitTrait>::Ratio, Pow10Fraction<Z0, Frac![U1]>);
+        assert_eq!(
+            (Quantity::<u64, Ym>::new(6) / Quantity::<u64, Ys>::new(2)).into_base(),
+            3.mps()
+        );
+        assert_eq!(
+            (Quantity::<u64, Ym>::new(2) * Quantity::<u64, Ycm>::new(5)).into_base(),
+            10.sqm()
+        );
+
+        assert_eq!(
+            format!("{:?}", Pow10Fraction::<N3, Frac![U1 / U2]>::new()),
+            "Pow10Fraction<1/2 * 10^-3>"
+        );
+    }
+
+    #[test]
+    fn pow() {
+        assert_type_eq!(Exp<Frac![U2 / U3], P3>, Frac![U8 / U27]);
+        assert_type_eq!(Exp<Frac![U2 / U3], N2>, Frac![U9 / U4]);
+        assert_type_eq!(Exp<Frac![U2 / U3], Z0>, Frac![U1]);
+        assert_type_eq!(Exp<Frac![U10], P3>, Frac![U1000]);
+    }
 }
@@ -1,3 +1,5 @@
+use core::cmp::Ordering;
+
 use typenum::{Integer, Unsigned};
 
 /// Type that can be created from compile-time integer
@@ -12,6 +14,48 @@ pub trait FromUnsigned {
     fn from_unsigned<U: Unsigned>() -> Self;
 }
 
+/// Type that has an additive identity (zero)
+pub trait Zero {
+    /// Returns zero
+    fn zero() -> Self;
+}
+
+/// Type that has a total order (for floats it's the one of `total_cmp`, so
+/// e.g. `NaN`s are ordered too)
+pub trait TotalCmp {
+    /// Compares self with `other` using total order
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+/// Type that has a square root (implemented for floats with `float-math`
+/// feature)
+pub trait Sqrt {
+    /// Returns square root of self (`NaN` for negative floats)
+    fn sqrt(self) -> Self;
+}
+
+/// Type that can be (possibly lossy) converted into `f64`
+pub trait ToF64 {
+    /// Convert self into `f64` (like `self as f64`)
+    fn to_f64(self) -> f64;
+}
+
+/// Unsigned integer that can be losslessly converted into a signed one (e.g.
+/// `u32` into `i64`)
+pub trait ToSigned {
+    /// Signed integer that can hold all values of `Self`
+    type Signed;
+
+    /// Convert self into the signed integer
+    fn to_signed(self) -> Self::Signed;
+}
+
+/// Type that can be (possibly lossy) converted into `T` with `as`
+pub trait AsPrimitive<T> {
+    /// Convert self into `T` (like `self as T`)
+    fn as_(self) -> T;
+}
+
 macro_rules! impls_int {
     (
         $( $Int:ident => $Const:ident),+ $(,)?
@@ -71,7 +115,9 @@ impl FromInteger for f32 {
 impl FromUnsigned for f32 {
     #[inline]
     fn from_unsigned<I: Unsigned>() -> Self {
-        I::U64 as f32
+        // `U128` so ratios of prefixed astronomical units (e.g. `Mpc`) don't
+        // overflow
+        I::U128 as f32
     }
 }
 
@@ -85,6 +131,124 @@ impl FromInteger for f64 {
 impl FromUnsigned for f64 {
     #[inline]
     fn from_unsigned<I: Unsigned>() -> Self {
-        I::U64 as f64
+        I::U128 as f64
     }
 }
+
+macro_rules! impls_zero {
+    ($( $T:ty => $zero:literal ),+ $(,)?) => {
+        $(
+            impl Zero for $T {
+                #[inline]
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+        )+
+    };
+}
+
+impls_zero! {
+    i8 => 0, i16 => 0, i32 => 0, i64 => 0, i128 => 0, isize => 0,
+    u8 => 0, u16 => 0, u32 => 0, u64 => 0, u128 => 0, usize => 0,
+    f32 => 0.0, f64 => 0.0,
+}
+
+macro_rules! impls_total_cmp {
+    (ord: $( $T:ty ),+; float: $( $F:ty ),+ $(,)?) => {
+        $(
+            impl TotalCmp for $T {
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    Ord::cmp(self, other)
+                }
+            }
+        )+
+        $(
+            impl TotalCmp for $F {
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$F>::total_cmp(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impls_total_cmp! {
+    ord: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize;
+    float: f32, f64,
+}
+
+// `f32::sqrt`/`f64::sqrt` are only available in `std`, so `libm` is used
+// instead (the same as for the functions in `math`)
+#[cfg(feature = "float-math")]
+impl Sqrt for f32 {
+    #[inline]
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+}
+
+#[cfg(feature = "float-math")]
+impl Sqrt for f64 {
+    #[inline]
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+}
+
+macro_rules! impls_to_f64 {
+    ($( $T:ty ),+ $(,)?) => {
+        $(
+            impl ToF64 for $T {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )+
+    };
+}
+
+impls_to_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+macro_rules! impls_to_signed {
+    ($( $T:ty => $S:ty ),+ $(,)?) => {
+        $(
+            impl ToSigned for $T {
+                type Signed = $S;
+
+                #[inline]
+                fn to_signed(self) -> $S {
+                    <$S>::from(self)
+                }
+            }
+        )+
+    };
+}
+
+impls_to_signed!(u8 => i16, u16 => i32, u32 => i64, u64 => i128);
+
+macro_rules! impls_as {
+    ($( $T:ty ),+ $(,)?) => {
+        impls_as!(@outer [$( $T ),+] $( $T ),+);
+    };
+    (@outer $all:tt $( $T:ty ),+) => {
+        $(
+            impls_as!(@inner $T => $all);
+        )+
+    };
+    (@inner $T:ty => [$( $U:ty ),+]) => {
+        $(
+            impl AsPrimitive<$U> for $T {
+                #[inline]
+                fn as_(self) -> $U {
+                    self as $U
+                }
+            }
+        )+
+    };
+}
+
+impls_as!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
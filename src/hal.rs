@@ -0,0 +1,111 @@
+//! [`embedded-hal`] integration (requires `embedded-hal` feature).
+//!
+//! Extension traits that let HAL call sites stay typed: delays accept time
+//! quantities and PWM duty cycle accepts [`Percent`].
+//!
+//! [`embedded-hal`]: https://docs.rs/embedded-hal
+//! [`Percent`]: crate::units::Percent
+use embedded_hal::{delay::DelayNs, pwm::SetDutyCycle};
+
+use crate::{
+    prefixes::{Micro, Milli, Nano},
+    units::{Percent, Second},
+    Quantity,
+};
+
+/// Extension for [`DelayNs`] to delay for a time quantity.
+///
+/// ## Examples
+///
+/// ```
+/// use embedded_hal::delay::DelayNs;
+/// use typed_phy::{
+///     hal::DelayExt,
+///     prefixes::{Micro, Milli, Nano},
+///     units::Second,
+///     Quantity,
+/// };
+///
+/// struct Delay(u64);
+///
+/// impl DelayNs for Delay {
+///     fn delay_ns(&mut self, ns: u32) {
+///         self.0 += u64::from(ns);
+///     }
+/// }
+///
+/// let mut delay = Delay(0);
+/// delay.delay_ms_for(Quantity::<u32, Milli<Second>>::new(2));
+/// delay.delay_us_for(Quantity::<u32, Micro<Second>>::new(5));
+/// delay.delay_ns_for(Quantity::<u32, Nano<Second>>::new(7));
+/// assert_eq!(delay.0, 2_005_007);
+/// ```
+pub trait DelayExt: DelayNs {
+    /// Pauses execution for at minimum `duration` nanoseconds, see
+    /// [`DelayNs::delay_ns`].
+    #[inline]
+    fn delay_ns_for(&mut self, duration: Quantity<u32, Nano<Second>>) {
+        self.delay_ns(duration.into_inner())
+    }
+
+    /// Pauses execution for at minimum `duration` microseconds, see
+    /// [`DelayNs::delay_us`].
+    #[inline]
+    fn delay_us_for(&mut self, duration: Quantity<u32, Micro<Second>>) {
+        self.delay_us(duration.into_inner())
+    }
+
+    /// Pauses execution for at minimum `duration` milliseconds, see
+    /// [`DelayNs::delay_ms`].
+    #[inline]
+    fn delay_ms_for(&mut self, duration: Quantity<u32, Milli<Second>>) {
+        self.delay_ms(duration.into_inner())
+    }
+}
+
+impl<D: DelayNs + ?Sized> DelayExt for D {}
+
+/// Extension for [`SetDutyCycle`] to set the duty cycle from a [`Percent`]
+/// quantity.
+///
+/// ## Examples
+///
+/// ```
+/// use core::convert::Infallible;
+///
+/// use embedded_hal::pwm::{ErrorType, SetDutyCycle};
+/// use typed_phy::{hal::SetDutyCycleExt, units::Percent, Quantity};
+///
+/// struct Pwm(u16);
+///
+/// impl ErrorType for Pwm {
+///     type Error = Infallible;
+/// }
+///
+/// impl SetDutyCycle for Pwm {
+///     fn max_duty_cycle(&self) -> u16 {
+///         1000
+///     }
+///
+///     fn set_duty_cycle(&mut self, duty: u16) -> Result<(), Self::Error> {
+///         self.0 = duty;
+///         Ok(())
+///     }
+/// }
+///
+/// let mut pwm = Pwm(0);
+/// pwm.set_duty(Quantity::<u8, Percent>::new(25)).unwrap();
+/// assert_eq!(pwm.0, 250);
+/// ```
+///
+/// [`Percent`]: crate::units::Percent
+pub trait SetDutyCycleExt: SetDutyCycle {
+    /// Sets the duty cycle to `duty` (values above `100%` are a logic error,
+    /// like in [`SetDutyCycle::set_duty_cycle_percent`]).
+    #[inline]
+    fn set_duty(&mut self, duty: Quantity<u8, Percent>) -> Result<(), Self::Error> {
+        self.set_duty_cycle_percent(duty.into_inner())
+    }
+}
+
+impl<P: SetDutyCycle + ?Sized> SetDutyCycleExt for P {}
@@ -11,7 +11,28 @@
 //!
 //! ## cargo features
 //!
+//! - `alloc` - enables helpers those need allocation (like
+//!   [`Quantity::to_si_string`])
+//! - `control` - enables controllers with dimension-checked gains (see
+//!   [`control`](crate::control) module)
+//! - `complex` - enables complex-valued storage (`Quantity<Complex<_>, _>`)
+//!   via [`num-complex`] (see [`complex`](crate::complex) module)
+//! - `embedded-hal` - enables typed adapters for [`embedded-hal`] traits (see
+//!   [`hal`](crate::hal) module)
+//! - `float-math` - enables trigonometric (and other transcendental) functions
+//!   and square roots (e.g. propagation of uncertainty, norms of vectors) of
+//!   float quantities via [`libm`] (works on `no_std`) and sound pressure
+//!   levels (see [`acoustics`](crate::acoustics) module)
+//! - `formulas` - enables dimension-checked physical formulas (see
+//!   [`formulas`](crate::formulas) module)
 //! - `deser` - enables support of (de)serializing [`Quantity`] via [`serde`]
+//!   (together with `alloc` also enables columnar helpers, see
+//!   [`dynamic::column`](crate::dynamic::column))
+//! - `strict` - makes `+`, `-`, `*` and `/` of quantities with integer
+//!   storages panic on overflow (in release builds too) with a message that
+//!   includes the units (see [`strict`](crate::strict) module)
+//! - `sqlx`, `diesel` - enable storing [`Quantity`] in databases via [`sqlx`]
+//!   and [`diesel`] (see [`db`](crate::db) module)
 //! - `nightly` - enables features those require nightly compiler. Currently
 //!   those are:
 //!   - ~~[`impl core::iter::Step for Quantity`](crate::Quantity#impl-Step)~~
@@ -21,6 +42,11 @@
 //!
 //! [`Quantity`]: crate::Quantity
 //! [`serde`]: https://docs.rs/serde
+//! [`num-complex`]: https://docs.rs/num-complex
+//! [`libm`]: https://docs.rs/libm
+//! [`embedded-hal`]: https://docs.rs/embedded-hal
+//! [`sqlx`]: https://docs.rs/sqlx
+//! [`diesel`]: https://docs.rs/diesel
 //!
 //! ## Project goals
 //!
@@ -35,7 +61,9 @@
 //!
 //! ### Non-goals
 //!
-//! - Support non-SI
+//! - Support non-SI systems of units (e.g. CGS or imperial as base units).
+//!   Non-SI units like hours, degrees or watt-hours are supported, but only as
+//!   SI units scaled by a ratio
 //!
 //! ## See also
 //!
@@ -74,36 +102,89 @@
 // And I like inline
 #![warn(clippy::missing_inline_in_public_items)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 mod macros;
 pub use macros::NoOpMul;
 
-mod rt;
-
+/// Points (as opposed to differences)
+pub mod absolute;
+#[cfg(feature = "float-math")]
+pub mod acoustics;
+/// Analog-digital conversion
+pub mod adc;
+/// Angles wrapping around the full turn
+pub mod angle;
+/// Atomic quantities
+pub mod atomic;
+/// Quantities with limited range
+pub mod bounded;
 pub mod checked;
+#[cfg(feature = "complex")]
+pub mod complex;
+#[cfg(feature = "control")]
+pub mod control;
+pub mod consts;
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+pub mod db;
+/// Formatting adapters
+pub mod display;
+/// Quantities with units known at runtime
+pub mod dynamic;
+#[cfg(feature = "formulas")]
+pub mod formulas;
 /// Type-level fraction (`A / B`)
 pub mod fraction;
 /// Trait for integers
 pub mod from_int;
+#[cfg(feature = "embedded-hal")]
+pub mod hal;
+/// Lookup tables
+pub mod lookup;
 /// Unit prefixes
+///
+/// Note: unlike unit multiplication and division, prefixes don't simplify the
+/// ratio (`typenum` can't compute GCD of big powers of ten), so a prefixed
+/// unit may be a different type than the same unit written otherwise. E.g.
+/// `Kilo<Gram>` has ratio `1000/1000` and isn't [`KiloGram`]. Such units are
+/// still equal by [`UnitEq`], see [`Quantity::into_same_unit`].
+///
+/// [`KiloGram`]: units::KiloGram
 pub mod prefixes;
+/// Intervals of quantities
+pub mod range;
+pub mod ratio;
+pub mod rt;
+/// Signal processing helpers
+pub mod signal;
 /// Simplify fractions
 pub mod simplify;
+/// Statistics
+pub mod stats;
+pub mod strict;
+/// Values with uncertainty
+pub mod uncertain;
 /// Aliases to units
 pub mod units;
+/// Small vectors of quantities
+pub mod vector;
 
 /* private, but reexported */
 mod dimensions;
 mod eq;
 mod ext;
 mod id;
+#[cfg(feature = "float-math")]
+mod math;
 mod quantity;
 mod unit;
 
 pub use self::{
     dimensions::{Dimensions, DimensionsTrait},
-    eq::{FractionEq, UnitEq},
-    ext::IntExt,
+    eq::{DimensionsEq, FractionEq, SameUnit, UnitEq},
+    ext::{ConvertUnits, IntExt, QuantityIterExt, QuantitySliceExt},
     id::Id,
     quantity::Quantity,
     unit::{Unit, UnitTrait},
@@ -131,5 +212,11 @@ pub struct ReadmeDocTests;
 /// Reexport for macros
 #[doc(hidden)]
 pub mod reexport {
-    pub use typenum::U1;
+    pub use crate::macros::{assert_quantity, assert_quantity_tol, ConvertExact};
+    pub use typenum::{Pow, Same, True, P2, P3, P4, U1};
+
+    // Aliases instead of reexports to not change paths in error messages
+    pub type PosExp<const N: usize> = typenum::PInt<typenum::U<N>>;
+    pub type NegExp<const N: usize> = typenum::NInt<typenum::U<N>>;
+    pub type ZeroExp = typenum::Z0;
 }
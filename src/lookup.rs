@@ -0,0 +1,199 @@
+use crate::Quantity;
+
+/// Table of sorted breakpoints `In -> Out` with linear interpolation between
+/// them (e.g. thermistor curve or calibration map).
+///
+/// Inputs outside of the table are clamped to the first/last breakpoint.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     lookup::LookupTable,
+///     units::{Kelvin, Metre},
+///     IntExt, Quantity,
+/// };
+///
+/// // Position of a sensor -> temperature
+/// let table = LookupTable::new([
+///     (0.m(), 300.quantity::<Kelvin>()),
+///     (10.m(), 320.quantity::<Kelvin>()),
+///     (20.m(), 310.quantity::<Kelvin>()),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(table.lookup(5.m()), 310.quantity::<Kelvin>());
+/// assert_eq!(table.lookup(15.m()), 315.quantity::<Kelvin>());
+///
+/// // Clamping
+/// assert_eq!(table.lookup((-5).m()), 300.quantity::<Kelvin>());
+/// assert_eq!(table.lookup(100.m()), 310.quantity::<Kelvin>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LookupTable<In, Out, const N: usize> {
+    points: [(In, Out); N],
+}
+
+impl<In, Out, const N: usize> LookupTable<In, Out, N> {
+    /// Returns breakpoints of the table.
+    #[inline]
+    pub fn points(&self) -> &[(In, Out); N] {
+        &self.points
+    }
+}
+
+impl<S, UIn, UOut, const N: usize> LookupTable<Quantity<S, UIn>, Quantity<S, UOut>, N>
+where
+    S: PartialOrd + Copy,
+{
+    /// Creates new table from the given breakpoints.
+    ///
+    /// Returns `None` if the table is empty or the inputs aren't strictly
+    /// increasing.
+    #[inline]
+    pub fn new(points: [(Quantity<S, UIn>, Quantity<S, UOut>); N]) -> Option<Self> {
+        let sorted = points.windows(2).all(|w| w[0].0 < w[1].0);
+        if N == 0 || !sorted {
+            return None;
+        }
+
+        Some(Self { points })
+    }
+
+    /// Returns linearly interpolated output for the given input.
+    ///
+    /// Inputs outside of the table are clamped.
+    #[inline]
+    pub fn lookup(&self, x: Quantity<S, UIn>) -> Quantity<S, UOut>
+    where
+        S: LerpStorage,
+    {
+        // Index of the first breakpoint greater than `x`
+        let i = self.points.partition_point(|&(p, _)| p <= x);
+        if i == 0 {
+            return self.points[0].1;
+        }
+        if i == N {
+            return self.points[N - 1].1;
+        }
+
+        let (x0, y0) = self.points[i - 1];
+        let (x1, y1) = self.points[i];
+
+        Quantity::new(S::lerp(
+            x.into_inner(),
+            (x0.into_inner(), y0.into_inner()),
+            (x1.into_inner(), y1.into_inner()),
+        ))
+    }
+}
+
+/// Storage that can be used in [`LookupTable::lookup`].
+///
+/// This trait is implemented for floats and integers of up to 64 bits (the
+/// intermediate product of integers is computed in 128 bits, so it can't
+/// overflow).
+pub trait LerpStorage: PartialOrd + Copy {
+    /// Returns `y` on the line through `(x0, y0)` and `(x1, y1)` at `x`
+    /// (`x0 <= x < x1`).
+    fn lerp(x: Self, p0: (Self, Self), p1: (Self, Self)) -> Self;
+}
+
+macro_rules! impls_lerp_storage {
+    (int: $( $I:ty ),+; float: $( $F:ty ),+ $(,)?) => {
+        $(
+            impl LerpStorage for $I {
+                #[inline]
+                fn lerp(x: Self, (x0, y0): (Self, Self), (x1, y1): (Self, Self)) -> Self {
+                    // Differences of 64-bit integers fit into `i128` and their
+                    // product fits into `u128`
+                    let dy = (y1 as i128 - y0 as i128).unsigned_abs();
+                    let dx = (x as i128 - x0 as i128) as u128;
+                    let width = (x1 as i128 - x0 as i128) as u128;
+
+                    // `step <= dy`, so the result is between `y0` and `y1`
+                    let step = (dy * dx / width) as i128;
+                    let y = if y0 <= y1 {
+                        y0 as i128 + step
+                    } else {
+                        y0 as i128 - step
+                    };
+
+                    y as $I
+                }
+            }
+        )+
+        $(
+            impl LerpStorage for $F {
+                #[inline]
+                fn lerp(x: Self, (x0, y0): (Self, Self), (x1, y1): (Self, Self)) -> Self {
+                    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+                }
+            }
+        )+
+    };
+}
+
+impls_lerp_storage! {
+    int: i8, i16, i32, i64, isize, u8, u16, u32, u64, usize;
+    float: f32, f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LookupTable;
+    use crate::{prefixes::Milli, units::Second, IntExt, Quantity};
+
+    #[test]
+    fn new() {
+        assert!(LookupTable::<Quantity<i32, Second>, Quantity<i32, Second>, 0>::new([]).is_none());
+        assert!(LookupTable::new([(1.s(), 1.m()), (1.s(), 2.m())]).is_none());
+        assert!(LookupTable::new([(2.s(), 1.m()), (1.s(), 2.m())]).is_none());
+        assert!(LookupTable::new([(1.s(), 1.m())]).is_some());
+    }
+
+    #[test]
+    fn lookup() {
+        let table = LookupTable::new([(1.0.s(), 10.0.m()), (3.0.s(), 20.0.m())]).unwrap();
+        assert_eq!(table.lookup(0.0.s()), 10.0.m());
+        assert_eq!(table.lookup(1.0.s()), 10.0.m());
+        assert_eq!(table.lookup(1.5.s()), 12.5.m());
+        assert_eq!(table.lookup(3.0.s()), 20.0.m());
+        assert_eq!(table.lookup(4.0.s()), 20.0.m());
+
+        let single = LookupTable::new([(1.s(), 1.m())]).unwrap();
+        assert_eq!(single.lookup(0.s()), 1.m());
+        assert_eq!(single.lookup(2.s()), 1.m());
+    }
+
+    #[test]
+    fn unsigned_decreasing() {
+        // ADC reading (in ms of charge time) -> distance
+        let table = LookupTable::new([
+            (Quantity::<u16, Milli<Second>>::new(0), 100u16.m()),
+            (Quantity::new(100), 0u16.m()),
+        ])
+        .unwrap();
+
+        assert_eq!(table.lookup(Quantity::new(25)), 75.m());
+        assert_eq!(table.lookup(Quantity::new(200)), 0.m());
+    }
+
+    #[test]
+    fn no_overflow() {
+        // `(y1 - y0) * (x - x0)` doesn't fit into `u16`
+        let table = LookupTable::new([
+            (0u16.mv(), 60_000u16.m()),
+            (50_000.mv(), 0.m()),
+            (60_000.mv(), 65_000.m()),
+        ])
+        .unwrap();
+
+        assert_eq!(table.lookup(25_000.mv()), 30_000.m());
+        assert_eq!(table.lookup(55_000.mv()), 32_500.m());
+
+        let table =
+            LookupTable::new([(i64::MIN.s(), i64::MIN.m()), (i64::MAX.s(), i64::MAX.m())]).unwrap();
+        assert_eq!(table.lookup(0.s()), 0.m());
+    }
+}
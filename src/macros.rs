@@ -1,6 +1,18 @@
+use core::{
+    fmt::Display,
+    ops::{Div, Mul, Sub},
+};
+
 use typenum::Quot;
 
-use crate::units::Dimensionless;
+use crate::{
+    eq::DimensionsEq,
+    fraction::FractionTrait,
+    from_int::{FromUnsigned, Zero},
+    simplify::{Simplified, Simplify},
+    units::Dimensionless,
+    Quantity, UnitTrait,
+};
 
 /// Declare (?) [`Unit`] _type_ depending on other units.
 ///
@@ -133,7 +145,7 @@ macro_rules! Unit {
     // Those next 7 branches expand exponents
     // 1) expand `* X ^ -n` => `/ X ^ n`
     // 2) expand `/ X ^ -n` => `* X ^ n`
-    // 3..6) expand `$op ^ n` for n 1, 2, 3, 4
+    // 3..6) expand `$op ^ n` for n 1, 2, 3, 4 (using `Pow`)
     // 7) compile error for exponents > 4
     (@exec [ $acc:ty ] [* $x:ty] ^ -$n:tt $( $( $rest:tt )+ )? ) => {
         $crate::Unit![@exec [ $acc ] [/ $x] ^ $n $( $( $rest )+ )? ]
@@ -145,13 +157,13 @@ macro_rules! Unit {
         $crate::Unit![@exec [ $crate::Unit!(@ty_op $acc {$op} $x) ] [] $( $( $rest )+ )? ]
     };
     (@exec [ $acc:ty ] [$op:tt $x:ty] ^ 2 $( $( $rest:tt )+ )? ) => {
-        $crate::Unit![@exec [ $crate::Unit!(@ty_op $crate::Unit!(@ty_op $acc {$op} $x) {$op} $x) ] [] $( $( $rest )+ )? ]
+        $crate::Unit![@exec [ $crate::Unit!(@ty_op $acc {$op} <$x as $crate::reexport::Pow<$crate::reexport::P2>>::Output) ] [] $( $( $rest )+ )? ]
     };
     (@exec [ $acc:ty ] [$op:tt $x:ty] ^ 3 $( $( $rest:tt )+ )? ) => {
-        $crate::Unit![@exec [ $crate::Unit!(@ty_op $crate::Unit!(@ty_op $crate::Unit!(@ty_op $acc {$op} $x) {$op} $x) {$op} $x) ] [] $( $( $rest )+ )? ]
+        $crate::Unit![@exec [ $crate::Unit!(@ty_op $acc {$op} <$x as $crate::reexport::Pow<$crate::reexport::P3>>::Output) ] [] $( $( $rest )+ )? ]
     };
     (@exec [ $acc:ty ] [$op:tt $x:ty] ^ 4 $( $( $rest:tt )+ )? ) => {
-        $crate::Unit![@exec [ $crate::Unit!(@ty_op $crate::Unit!(@ty_op $crate::Unit!(@ty_op $crate::Unit!(@ty_op $acc {$op} $x) {$op} $x) {$op} $x) {$op} $x) ] [] $( $( $rest )+ )? ]
+        $crate::Unit![@exec [ $crate::Unit!(@ty_op $acc {$op} <$x as $crate::reexport::Pow<$crate::reexport::P4>>::Output) ] [] $( $( $rest )+ )? ]
     };
     (@exec [ $acc:ty ] [$op:tt $x:ty] ^ $n:tt $( $( $rest:tt )+ )? ) => {
         compile_error!(
@@ -256,6 +268,92 @@ bug in the macro. In the second case please open an issue on github. Input: `",
     };
 }
 
+/// Declare [`Unit`] _type_ from exponents of the base dimensions.
+///
+/// Unlike [`Unit!`] this doesn't parse operator expressions, so it's
+/// friendlier for generated code and isn't limited to exponents in `[-4; 4]`.
+/// Dimensions are named `L` (length), `M` (mass), `T` (time), `I` (electric
+/// current), `Th` (thermodynamic temperature), `N` (amount of substance) and
+/// `J` (luminous intensity), omitted dimensions have exponent `0`. The ratio
+/// of the unit is `1`.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     dim,
+///     units::{Dimensionless, MetrePerSecondSquared, Watt},
+///     Id, Unit,
+/// };
+///
+/// /// Assert that types `A` and `B` are equal
+/// fn type_eq<A: Id<This = B>, B>() {}
+///
+/// type_eq::<dim![L = 1, T = -2], MetrePerSecondSquared>();
+/// type_eq::<dim![M = 1, L = 2, T = -3], Watt>();
+/// type_eq::<dim![], Dimensionless>();
+///
+/// // Exponents out of `[-4; 4]` are fine too
+/// type_eq::<dim![L = 6], Unit![dim![L = 3] * dim![L = 3]]>();
+/// ```
+///
+/// ```compile_fail
+/// use typed_phy::dim;
+///
+/// // Unknown dimension
+/// type X = dim![Q = 1];
+/// ```
+///
+/// [`Unit`]: struct@crate::Unit
+/// [`Unit!`]: crate::Unit!
+#[macro_export]
+macro_rules! dim {
+    () => {
+        $crate::units::Dimensionless
+    };
+
+    // Multiply accumulator by units of single dimensions (`@one`) one by one
+    (@acc [$acc:ty]) => {
+        $acc
+    };
+    (@acc [$acc:ty] $name:ident = - $n:literal $(, $( $rest:tt )* )?) => {
+        $crate::dim![@acc [<$acc as ::core::ops::Mul<$crate::dim![@one $name $crate::reexport::NegExp<$n>]>>::Output] $( $( $rest )* )?]
+    };
+    (@acc [$acc:ty] $name:ident = 0 $(, $( $rest:tt )* )?) => {
+        $crate::dim![@acc [$acc] $( $( $rest )* )?]
+    };
+    (@acc [$acc:ty] $name:ident = $n:literal $(, $( $rest:tt )* )?) => {
+        $crate::dim![@acc [<$acc as ::core::ops::Mul<$crate::dim![@one $name $crate::reexport::PosExp<$n>]>>::Output] $( $( $rest )* )?]
+    };
+
+    (@one L $e:ty) => { $crate::dim![@unit $e, _, _, _, _, _, _] };
+    (@one M $e:ty) => { $crate::dim![@unit _, $e, _, _, _, _, _] };
+    (@one T $e:ty) => { $crate::dim![@unit _, _, $e, _, _, _, _] };
+    (@one I $e:ty) => { $crate::dim![@unit _, _, _, $e, _, _, _] };
+    (@one Th $e:ty) => { $crate::dim![@unit _, _, _, _, $e, _, _] };
+    (@one N $e:ty) => { $crate::dim![@unit _, _, _, _, _, $e, _] };
+    (@one J $e:ty) => { $crate::dim![@unit _, _, _, _, _, _, $e] };
+    (@one $name:ident $e:ty) => {
+        compile_error!(
+            concat!(
+                "Expected one of dimensions `L`, `M`, `T`, `I`, `Th`, `N`, `J`, found `",
+                stringify!($name),
+                "`"
+            )
+        )
+    };
+
+    (@unit $( $e:tt ),+) => {
+        $crate::Unit<$crate::Dimensions<$( $crate::dim![@exp $e] ),+>>
+    };
+    (@exp _) => { $crate::reexport::ZeroExp };
+    (@exp $e:ty) => { $e };
+
+    ($( $anything:tt )+) => {
+        $crate::dim![@acc [$crate::units::Dimensionless] $( $anything )+]
+    };
+}
+
 /// Helper for `Unit` macro
 ///
 /// This stru^W enum is needed to do things in a more generic way.
@@ -293,9 +391,7 @@ fn unit() {
         };
     }
 
-    use core::ops::Mul;
-
-    use typenum::{N1, P1, U100, U1000, U36, Z0};
+    use typenum::{N1, P1, U18, U5, Z0};
 
     use crate::{
         fraction::Fraction,
@@ -304,11 +400,10 @@ fn unit() {
         Dimensions, IntExt, Quantity, Unit,
     };
 
-    type U3600 = <U36 as Mul<U100>>::Output;
-
+    // Ratio is simplified: `1000/3600 = 5/18`
     typenum::assert_type_eq!(
         Unit![Kilo<Metre> / Hour],
-        Unit<Dimensions<P1, Z0, N1, Z0, Z0, Z0, Z0>, Fraction<U1000, U3600>>
+        Unit<Dimensions<P1, Z0, N1, Z0, Z0, Z0, Z0>, Fraction<U5, U18>>
     );
 
     type Simple = Unit![
@@ -333,6 +428,24 @@ fn unit() {
     // TODO: more tests
 }
 
+#[test]
+fn dim() {
+    use typenum::{N1, N7, P1, P2, P5, Z0};
+
+    use crate::{
+        units::{Dimensionless, Joule, Kelvin},
+        Dimensions, Unit,
+    };
+
+    typenum::assert_type_eq!(dim![M = 1, L = 2, T = -2], Joule);
+    typenum::assert_type_eq!(dim![Th = 1,], Kelvin);
+    typenum::assert_type_eq!(dim![L = 0, T = 0], Dimensionless);
+    typenum::assert_type_eq!(
+        dim![J = 5, N = -7, I = 2, Th = -1, T = 1],
+        Unit<Dimensions<Z0, Z0, P1, P2, N1, N7, P5>>
+    );
+}
+
 /// Shortcut for creating [`Fraction`], see it's doc for more.
 ///
 /// [`Fraction`]: crate::fraction::Fraction
@@ -349,3 +462,274 @@ macro_rules! Frac {
         $crate::fraction::Fraction::<$a, $crate::reexport::U1>
     };
 }
+
+/// Asserts at compile time that fraction `A` is less than or equal to fraction
+/// `B` (see [`FractionCmp`]).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{assert_frac_le, Frac};
+/// use typenum::{U1, U2, U3, U1000};
+///
+/// assert_frac_le!(Frac![U1 / U3], Frac![U1 / U2]);
+/// assert_frac_le!(Frac![U2 / U3], Frac![U2 / U3]);
+/// assert_frac_le!(Frac![/ U1000], Frac![U1]);
+/// ```
+///
+/// ```compile_fail
+/// use typed_phy::{assert_frac_le, Frac};
+/// use typenum::{U1, U2, U3};
+///
+/// assert_frac_le!(Frac![U1 / U2], Frac![U1 / U3]);
+/// ```
+///
+/// [`FractionCmp`]: crate::fraction::FractionCmp
+#[macro_export]
+macro_rules! assert_frac_le {
+    ($a:ty, $b:ty $(,)?) => {
+        const _:
+            ::core::marker::PhantomData<
+                <$crate::fraction::FracLeEq<$a, $b> as $crate::reexport::Same<
+                    $crate::reexport::True,
+                >>::Output,
+            > = ::core::marker::PhantomData;
+    };
+}
+
+/// Asserts that two quantities are equal (or differ by no more than `tol`).
+///
+/// `left`, `right` and `tol` can have any units with the same dimensions, they
+/// are converted to the finest of these units, so integers aren't truncated
+/// (e.g. `1 m` isn't equal to `1500 mm`). On failure both values, their
+/// difference and the tolerance are printed in this unit (via [`Display`]).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{assert_quantity_eq, IntExt};
+///
+/// assert_quantity_eq!(1.km(), 1000.m());
+/// assert_quantity_eq!(1000.m(), 1.km());
+/// assert_quantity_eq!(1.0.m(), 1.05.m(), tol = 0.1.m());
+/// assert_quantity_eq!(60.0.s(), 1.01.min_(), tol = 1.0.s());
+/// ```
+///
+/// ```should_panic
+/// use typed_phy::{assert_quantity_eq, IntExt};
+///
+/// // panics with:
+/// // assertion failed: `left == right` (tolerance: 0.1 m)
+/// //   left: 1 m
+/// //  right: 1.5 m
+/// //   diff: 0.5 m
+/// assert_quantity_eq!(1.0.m(), 1.5.m(), tol = 0.1.m());
+/// ```
+///
+/// ```should_panic
+/// use typed_phy::{assert_quantity_eq, IntExt};
+///
+/// // `1 m` is compared as `10 dm`
+/// assert_quantity_eq!(1.m(), 15.dm());
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+#[macro_export]
+macro_rules! assert_quantity_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::reexport::assert_quantity($left, $right, true)
+    };
+    ($left:expr, $right:expr, tol = $tol:expr $(,)?) => {
+        $crate::reexport::assert_quantity_tol($left, $right, $tol, true)
+    };
+}
+
+/// Asserts that two quantities aren't equal (or differ by more than `tol`).
+///
+/// This is the opposite of [`assert_quantity_eq`], see its docs for more.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{assert_quantity_ne, IntExt};
+///
+/// assert_quantity_ne!(1001.m(), 1.km());
+/// assert_quantity_ne!(1.0.m(), 1.5.m(), tol = 0.1.m());
+/// ```
+///
+/// [`assert_quantity_eq`]: crate::assert_quantity_eq
+#[macro_export]
+macro_rules! assert_quantity_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::reexport::assert_quantity($left, $right, false)
+    };
+    ($left:expr, $right:expr, tol = $tol:expr $(,)?) => {
+        $crate::reexport::assert_quantity_tol($left, $right, $tol, false)
+    };
+}
+
+/// Exact conversion between units used by `assert_quantity_eq!` and
+/// `assert_quantity_ne!`.
+///
+/// Unlike [`Quantity::into_unit`] this doesn't convert through the base unit,
+/// so e.g. integer millimetres can be converted to micrometres.
+#[doc(hidden)]
+pub trait ConvertExact<T>: UnitTrait {
+    fn convert<S>(storage: S) -> S
+    where
+        S: FromUnsigned + Mul<Output = S> + Div<Output = S>;
+}
+
+impl<U, T> ConvertExact<T> for U
+where
+    U: UnitTrait,
+    T: UnitTrait,
+    T::Dimensions: DimensionsEq<U::Dimensions>,
+    U::Ratio: Div<T::Ratio>,
+    Quot<U::Ratio, T::Ratio>: Simplify,
+    Simplified<Quot<U::Ratio, T::Ratio>>: FractionTrait,
+{
+    #[inline]
+    fn convert<S>(storage: S) -> S
+    where
+        S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+    {
+        <Simplified<Quot<U::Ratio, T::Ratio>>>::mul(storage)
+    }
+}
+
+/// Implementation of `assert_quantity_eq!` and `assert_quantity_ne!`.
+#[doc(hidden)]
+#[track_caller]
+#[inline]
+pub fn assert_quantity<S, L, R>(left: Quantity<S, L>, right: Quantity<S, R>, expect_eq: bool)
+where
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S> + Zero,
+    S: Sub<Output = S> + PartialOrd + Display + Copy,
+    L: ConvertExact<R> + Display + Default,
+    R: ConvertExact<L> + Display + Default,
+{
+    // Compare in the finer unit, so integers aren't truncated
+    if L::Ratio::to_f64() <= R::Ratio::to_f64() {
+        compare(left, convert(right), Quantity::zero(), expect_eq)
+    } else {
+        compare(convert(left), right, Quantity::zero(), expect_eq)
+    }
+}
+
+/// Implementation of `assert_quantity_eq!` and `assert_quantity_ne!` with
+/// tolerance.
+#[doc(hidden)]
+#[track_caller]
+#[inline]
+pub fn assert_quantity_tol<S, L, R, T>(
+    left: Quantity<S, L>,
+    right: Quantity<S, R>,
+    tol: Quantity<S, T>,
+    expect_eq: bool,
+) where
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+    S: Sub<Output = S> + PartialOrd + Display + Copy,
+    L: ConvertExact<R> + ConvertExact<T> + Display + Default,
+    R: ConvertExact<L> + ConvertExact<T> + Display + Default,
+    T: ConvertExact<L> + ConvertExact<R> + Display + Default,
+{
+    // Compare in the finest unit, so integers aren't truncated
+    let (l, r, t) = (L::Ratio::to_f64(), R::Ratio::to_f64(), T::Ratio::to_f64());
+    if l <= r && l <= t {
+        compare(left, convert(right), convert(tol), expect_eq)
+    } else if r <= t {
+        compare(convert(left), right, convert(tol), expect_eq)
+    } else {
+        compare(convert(left), convert(right), tol, expect_eq)
+    }
+}
+
+#[inline]
+fn convert<S, U, T>(q: Quantity<S, U>) -> Quantity<S, T>
+where
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+    U: ConvertExact<T>,
+{
+    Quantity::new(U::convert(q.into_inner()))
+}
+
+#[track_caller]
+#[inline]
+fn compare<S, U>(left: Quantity<S, U>, right: Quantity<S, U>, tol: Quantity<S, U>, expect_eq: bool)
+where
+    S: Sub<Output = S> + PartialOrd + Display + Copy,
+    U: Display + Default,
+{
+    let (l, r) = (left.into_inner(), right.into_inner());
+    // Not using `abs` to support unsigned integers
+    let diff = Quantity::<_, U>::new(if l > r { l - r } else { r - l });
+
+    if (diff.into_inner() <= tol.into_inner()) != expect_eq {
+        panic!(
+            "assertion failed: `left {op} right` (tolerance: {tol})\n  left: {left}\n right: \
+             {right}\n  diff: {diff}",
+            op = if expect_eq { "==" } else { "!=" },
+            tol = tol,
+            left = left,
+            right = right,
+            diff = diff,
+        );
+    }
+}
+
+#[test]
+fn assert_quantity_macros() {
+    use crate::{prefixes::Milli, units::Metre, IntExt, Quantity};
+
+    assert_quantity_eq!(2.km(), 2000.m());
+    assert_quantity_eq!(2000.m(), 2.km(),);
+    assert_quantity_eq!(5u32.m(), 7.m(), tol = 2.m());
+    assert_quantity_eq!(7u32.m(), 5.m(), tol = 2.m());
+    assert_quantity_eq!(
+        1.0.m(),
+        1.0005.m(),
+        tol = Quantity::<_, Milli<Metre>>::new(1.0)
+    );
+
+    assert_quantity_ne!(2001.m(), 2.km());
+    assert_quantity_ne!(5u32.m(), 8.m(), tol = 2.m());
+    assert_quantity_ne!(1.0.m(), f64::NAN.m(), tol = 1.0.m());
+
+    // Compared in the finest unit
+    let mm = Quantity::<i32, Milli<Metre>>::new;
+    assert_quantity_ne!(1.m(), mm(1500));
+    assert_quantity_ne!(mm(1500), 1.m());
+    assert_quantity_eq!(1.m(), mm(1500), tol = mm(500));
+    assert_quantity_ne!(1.m(), 2.m(), tol = mm(999));
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: `left == right` (tolerance: 0 m)\n  left: 1001 m\n right: 1000 \
+                m\n  diff: 1 m"
+)]
+fn assert_quantity_eq_fail() {
+    use crate::IntExt;
+
+    assert_quantity_eq!(1001.m(), 1.km());
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: `left == right` (tolerance: 0 mm)\n  left: 1000 mm\n right: 1500 \
+                mm\n  diff: 500 mm"
+)]
+fn assert_quantity_eq_fail_finer() {
+    use crate::{prefixes::Milli, units::Metre, IntExt, Quantity};
+
+    assert_quantity_eq!(1.m(), Quantity::<_, Milli<Metre>>::new(1500));
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `left != right` (tolerance: 60 min)")]
+fn assert_quantity_ne_fail() {
+    use crate::IntExt;
+
+    assert_quantity_ne!(1.h(), 120.min_(), tol = 60.min_());
+}
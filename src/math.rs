@@ -0,0 +1,246 @@
+//! Transcendental functions on float quantities (requires `float-math`
+//! feature). Implemented via [`libm`], so they work on `no_std` too.
+//!
+//! [`libm`]: https://docs.rs/libm
+use crate::{
+    angle::AngleUnit,
+    units::{Dimensionless, Radian},
+    Quantity, UnitTrait,
+};
+
+macro_rules! trig_impls {
+    ($( $S:ident => { $sin:ident, $cos:ident, $tan:ident, $asin:ident, $acos:ident, $atan:ident, $atan2:ident } ),+ $(,)?) => {
+        $(
+            /// Trigonometric functions of angles.
+            ///
+            /// Only [`Radian`] and [`Degree`](crate::units::Degree) quantities
+            /// are accepted (see [`AngleUnit`]), degrees are converted to radians
+            /// first. Other dimensionless quantities don't compile:
+            ///
+            /// ```compile_fail
+            /// use typed_phy::{units::Percent, Quantity};
+            ///
+            /// let _ = Quantity::<f64, Percent>::new(50.0).sin();
+            /// ```
+            impl<U> Quantity<$S, U>
+            where
+                U: AngleUnit<Dimensions = <Dimensionless as UnitTrait>::Dimensions>,
+            {
+                /// Sine of the angle.
+                ///
+                /// ## Examples
+                ///
+                /// ```
+                /// use typed_phy::{
+                ///     units::{Degree, Radian},
+                ///     Quantity,
+                /// };
+                ///
+                /// let right = Quantity::<f64, Radian>::new(core::f64::consts::FRAC_PI_2);
+                /// assert_eq!(right.sin().into_inner(), 1.0);
+                ///
+                /// let right = Quantity::<f64, Degree>::new(90.0);
+                /// assert_eq!(right.sin().into_inner(), 1.0);
+                /// ```
+                #[inline]
+                pub fn sin(self) -> Quantity<$S, Dimensionless> {
+                    Quantity::new(libm::$sin(self.into_unit::<Radian>().into_inner()))
+                }
+
+                /// Cosine of the angle.
+                #[inline]
+                pub fn cos(self) -> Quantity<$S, Dimensionless> {
+                    Quantity::new(libm::$cos(self.into_unit::<Radian>().into_inner()))
+                }
+
+                /// Tangent of the angle.
+                #[inline]
+                pub fn tan(self) -> Quantity<$S, Dimensionless> {
+                    Quantity::new(libm::$tan(self.into_unit::<Radian>().into_inner()))
+                }
+            }
+
+            /// Inverse trigonometric functions.
+            ///
+            /// The ratio of the unit is respected (e.g. `Milli<Dimensionless>` is
+            /// converted to plain dimensionless first).
+            impl<U> Quantity<$S, U>
+            where
+                U: UnitTrait<Dimensions = <Dimensionless as UnitTrait>::Dimensions>,
+            {
+
+                /// Arcsine, returns angle in `[-π/2, π/2]` radians.
+                #[inline]
+                pub fn asin(self) -> Quantity<$S, Radian> {
+                    Quantity::new(libm::$asin(self.into_base().into_inner()))
+                }
+
+                /// Arccosine, returns angle in `[0, π]` radians.
+                #[inline]
+                pub fn acos(self) -> Quantity<$S, Radian> {
+                    Quantity::new(libm::$acos(self.into_base().into_inner()))
+                }
+
+                /// Arctangent, returns angle in `[-π/2, π/2]` radians.
+                #[inline]
+                pub fn atan(self) -> Quantity<$S, Radian> {
+                    Quantity::new(libm::$atan(self.into_base().into_inner()))
+                }
+            }
+
+            impl<U> Quantity<$S, U>
+            where
+                U: UnitTrait,
+            {
+                /// Four-quadrant arctangent of `self / x` (`self` is `y`), returns
+                /// angle in `[-π, π]` radians.
+                ///
+                /// Both quantities have the same unit, so e.g. components of a
+                /// velocity give its heading.
+                ///
+                /// ## Examples
+                ///
+                /// ```
+                /// use typed_phy::IntExt;
+                ///
+                /// let heading = 1.0f64.mps().atan2(1.0.mps());
+                /// assert_eq!(heading.into_inner(), core::f64::consts::FRAC_PI_4);
+                /// ```
+                #[inline]
+                pub fn atan2(self, x: Self) -> Quantity<$S, Radian> {
+                    Quantity::new(libm::$atan2(self.into_inner(), x.into_inner()))
+                }
+            }
+        )+
+    };
+}
+
+trig_impls! {
+    f32 => { sinf, cosf, tanf, asinf, acosf, atanf, atan2f },
+    f64 => { sin, cos, tan, asin, acos, atan, atan2 },
+}
+
+macro_rules! exp_impls {
+    ($( $S:ident => { $exp:ident, $ln:ident, $log10:ident, $log2:ident, $pow:ident } ),+ $(,)?) => {
+        $(
+            /// Exponential and logarithmic functions of dimensionless quantities.
+            ///
+            /// The ratio of the unit is respected (e.g. `Milli<Dimensionless>` is
+            /// converted to plain dimensionless first). These functions don't make
+            /// sense for dimensioned quantities, so they don't compile for them:
+            ///
+            /// ```compile_fail
+            /// use typed_phy::IntExt;
+            ///
+            /// let _ = 1.0f64.m().exp();
+            /// ```
+            impl<U> Quantity<$S, U>
+            where
+                U: UnitTrait<Dimensions = <Dimensionless as UnitTrait>::Dimensions>,
+            {
+                /// `e^self`
+                ///
+                /// ## Examples
+                ///
+                /// ```
+                /// use typed_phy::IntExt;
+                ///
+                /// assert_eq!(0.0f64.dimensionless().exp(), 1.0.dimensionless());
+                /// assert_eq!(1.0f64.dimensionless().exp().ln(), 1.0.dimensionless());
+                /// assert_eq!(1000.0f64.dimensionless().log10(), 3.0.dimensionless());
+                /// assert_eq!(2.0f64.dimensionless().powf(10.0), 1024.0.dimensionless());
+                /// ```
+                #[inline]
+                pub fn exp(self) -> Quantity<$S, Dimensionless> {
+                    Quantity::new(libm::$exp(self.into_base().into_inner()))
+                }
+
+                /// Natural logarithm.
+                #[inline]
+                pub fn ln(self) -> Quantity<$S, Dimensionless> {
+                    Quantity::new(libm::$ln(self.into_base().into_inner()))
+                }
+
+                /// Base 10 logarithm.
+                #[inline]
+                pub fn log10(self) -> Quantity<$S, Dimensionless> {
+                    Quantity::new(libm::$log10(self.into_base().into_inner()))
+                }
+
+                /// Base 2 logarithm.
+                #[inline]
+                pub fn log2(self) -> Quantity<$S, Dimensionless> {
+                    Quantity::new(libm::$log2(self.into_base().into_inner()))
+                }
+
+                /// Raises the quantity to a floating point power.
+                ///
+                /// Note: for dimensioned quantities use integer (type-level)
+                /// powers instead, see [`Pow`](typenum::Pow).
+                #[inline]
+                pub fn powf(self, exp: $S) -> Quantity<$S, Dimensionless> {
+                    Quantity::new(libm::$pow(self.into_base().into_inner(), exp))
+                }
+            }
+        )+
+    };
+}
+
+exp_impls! {
+    f32 => { expf, logf, log10f, log2f, powf },
+    f64 => { exp, log, log10, log2, pow },
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+
+    use crate::{
+        prefixes::Milli,
+        units::{Degree, Dimensionless, Radian},
+        IntExt, Quantity,
+    };
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn trig() {
+        let a = Quantity::<f64, Radian>::new(PI / 6.0);
+        assert_close(a.sin().into_inner(), 0.5);
+        assert_close(a.cos().into_inner(), 3f64.sqrt() / 2.0);
+        assert_close(a.tan().into_inner(), 1.0 / 3f64.sqrt());
+
+        // Degrees are converted to radians
+        let b = Quantity::<f64, Degree>::new(30.0);
+        assert_close(b.sin().into_inner(), 0.5);
+        assert_close(Quantity::<f64, Degree>::new(60.0).cos().into_inner(), 0.5);
+        assert_close(Quantity::<f64, Degree>::new(45.0).tan().into_inner(), 1.0);
+
+        let half = Quantity::<f64, Dimensionless>::new(0.5);
+        assert_close(half.asin().into_inner(), PI / 6.0);
+        assert_close(half.acos().into_inner(), PI / 3.0);
+        assert_close(1.0f64.dimensionless().atan().into_inner(), PI / 4.0);
+
+        assert_close((-1.0f64).m().atan2((-1.0).m()).into_inner(), -0.75 * PI);
+        assert!((Quantity::<f32, Radian>::new(0.0).cos().into_inner() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn exp() {
+        let x = 2.0f64.dimensionless();
+        assert_close(
+            x.exp().into_inner(),
+            core::f64::consts::E * core::f64::consts::E,
+        );
+        assert_close(x.ln().into_inner(), core::f64::consts::LN_2);
+        assert_close(x.log2().into_inner(), 1.0);
+        assert_close(x.powf(0.5).into_inner(), core::f64::consts::SQRT_2);
+
+        // Ratio is respected: 500 m(dimensionless) = 0.5
+        let half = Quantity::<f64, Milli<Dimensionless>>::new(500.0);
+        assert_close(half.log2().into_inner(), -1.0);
+        assert!((100.0f32.dimensionless().log10().into_inner() - 2.0).abs() < 1e-6);
+    }
+}
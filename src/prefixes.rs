@@ -1,6 +1,6 @@
 use core::ops::{Div, Mul};
 
-use typenum::{Exp, U1, U10, U12, U15, U18, U2, U21, U24, U3, U6, U9};
+use typenum::{Exp, U1, U10, U12, U15, U18, U2, U21, U24, U27, U3, U30, U6, U9};
 
 use crate::{Unit, UnitTrait};
 
@@ -9,6 +9,14 @@ pub(crate) type MulPow10<U, E> = MulBy<U, Exp<U10, E>>;
 /// Divides ratio of `U` by `X`
 pub(crate) type DivPow10<U, E> = DivBy<U, Exp<U10, E>>;
 
+/// quetta prefix. `Q`. (Base 10: `10^30`, decimal:
+/// `1000000000000000000000000000000`, word: nonillion/quintillion, adoption:
+/// 2022)
+pub type Quetta<U> = MulPow10<U, U30>;
+/// ronna prefix. `R`. (Base 10: `10^27`, decimal:
+/// `1000000000000000000000000000`, word: octillion/quadrilliard, adoption:
+/// 2022)
+pub type Ronna<U> = MulPow10<U, U27>;
 /// yotta prefix. `Y`. (Base 10: `10^24`, decimal: `1000000000000000000000000`,
 /// word: septillion/quadrillion, adoption: 1991)
 pub type Yotta<U> = MulPow10<U, U24>;
@@ -71,6 +79,53 @@ pub type Zepto<U> = DivPow10<U, U21>;
 /// `0.000000000000000000000001`, word: septillionth/quadrillionth, adoption:
 /// 1991)
 pub type Yocto<U> = DivPow10<U, U24>;
+/// ronto prefix. `r`. (Base 10: `10^-27`, decimal:
+/// `0.000000000000000000000000001`, word: octillionth/quadrilliardth,
+/// adoption: 2022)
+pub type Ronto<U> = DivPow10<U, U27>;
+/// quecto prefix. `q`. (Base 10: `10^-30`, decimal:
+/// `0.000000000000000000000000000001`, word: nonillionth/quintillionth,
+/// adoption: 2022)
+pub type Quecto<U> = DivPow10<U, U30>;
+
+/// Returns symbol of the prefix `10^exp` (e.g. `k` for `3`)
+pub(crate) fn symbol(exp: i32) -> Option<&'static str> {
+    let symbol = match exp {
+        30 => "Q",
+        27 => "R",
+        24 => "Y",
+        21 => "Z",
+        18 => "E",
+        15 => "P",
+        12 => "T",
+        9 => "G",
+        6 => "M",
+        3 => "k",
+        2 => "h",
+        1 => "da",
+        0 => "",
+        -1 => "d",
+        -2 => "c",
+        -3 => "m",
+        -6 => "μ",
+        -9 => "n",
+        -12 => "p",
+        -15 => "f",
+        -18 => "a",
+        -21 => "z",
+        -24 => "y",
+        -27 => "r",
+        -30 => "q",
+        _ => return None,
+    };
+
+    Some(symbol)
+}
+
+// Note: unlike `Unit` operations, `MulBy`/`DivBy` don't simplify the ratio:
+// big powers of ten (e.g. for yotta) can't be simplified anyway (see
+// `Simplify`). Prefixes of units with ratio `1` are already simplified anyway, the
+// rest (like `Kilo<Gram>`) are documented in the module docs.
 
 /// Multiplies ratio of `U` by `X`
 pub(crate) type MulBy<U, X> =
@@ -1,23 +1,34 @@
 use core::{
     cmp::Ordering,
+    convert::TryFrom,
     fmt::{self, Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex},
+    hash::{Hash, Hasher},
     iter::Sum,
     marker::PhantomData,
+    num::TryFromIntError,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
 };
 
 // #[cfg(feature = "nightly")]
 // use core::iter::Step;
 
-use typenum::{Prod, Quot};
+use typenum::{Exp, Integer, Pow, Prod, Quot, U1, U10, U3, U6, U9, Z0};
 
 use crate::{
-    checked::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub},
+    checked::{
+        CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, SaturatingAdd, SaturatingFrom,
+        SaturatingSub, WrappingAdd, WrappingNeg, WrappingSub,
+    },
+    dimensions::Dimensions,
+    eq::{DimensionsEq, SameUnit, UnitEq},
     fraction::{FractionTrait, One},
-    from_int::FromUnsigned,
+    from_int::{AsPrimitive, FromUnsigned, ToF64, ToSigned, TotalCmp, Zero},
     id::Id,
+    prefixes::{Micro, Milli, Nano},
+    rt::FractionRtExt,
+    strict::{StorageAdd, StorageDiv, StorageMul, StorageSub},
     unit::UnitTrait,
-    units::Dimensionless,
+    units::{Dimensionless, Hertz, RadianPerSecond, RevolutionsPerMinute, Second},
     Unit,
 };
 
@@ -58,26 +69,39 @@ use crate::{
 ///
 /// | Trait                                           | rhs Unit | Output                          | Call way            | Description                                                                                  |
 /// |-------------------------------------------------|----------|---------------------------------|---------------------|----------------------------------------------------------------------------------------------|
-/// | [`Add`](core::ops::Add)                         | Same     | `Self`                          | `a + b`             | sum of 2 quantities, works only with the same units                                          |
-/// | [`Sub`](core::ops::Sub)                         | Same     | `Self`                          | `a - b`             | diff of 2 quantities, works only with the same units                                         |
+/// | [`Add`](core::ops::Add)                         | Same     | `Self`                          | `a + b`             | sum of 2 quantities, works only with the same units (storages may differ)                    |
+/// | [`Sub`](core::ops::Sub)                         | Same     | `Self`                          | `a - b`             | diff of 2 quantities, works only with the same units (storages may differ)                   |
 /// | [`Mul`](core::ops::Mul)                         | Any      | `Quantity<S, U * URhs>`         | `a * b'`            | production of 2 quantities, changes unit                                                     |
 /// | [`Div`](core::ops::Div)                         | Any      | `Quantity<S, U / URhs>`         | `a / b'`            | quotation of 2 quantities, changes unit                                                      |
 /// | [`Mul`](core::ops::Mul)`<S>`                    | n/a      | `Self`                          | `a * s`             | production of quantity and an integer                                                        |
 /// | [`Div`](core::ops::Div)`<S>`                    | n/a      | `Self`                          | `a / s`             | quotation of quantity and an integer                                                         |
+/// | [`Mul`](core::ops::Mul)`<Quantity>` for `S`     | n/a      | `Self`                          | `s * a`             | production of an integer and quantity                                                        |
+/// | [`Div`](core::ops::Div)`<Quantity>` for `S`     | n/a      | `Quantity<S, Dimensionless / U>`| `s / a`             | quotation of an integer and quantity, changes unit                                           |
 /// | [`Neg`](core::ops::Neg)                         | n/a      | `Self`                          | `-a`                | negation of quantity                                                                         |
+/// | [`Pow`](typenum::Pow)`<P>`                      | n/a      | `Quantity<S, U ^ P>`            | `a.powi::<P>()`     | raising quantity to the type-level integer power, changes unit                               |
 /// | [`CheckedAdd`](crate::checked::CheckedAdd)      | Same     | `Option<Self>`                  | `a.checked_add(b)`  | sum of 2 quantities, works only with the same units, checks for overflow and underflow       |
 /// | [`CheckedSub`](crate::checked::CheckedSub)      | Same     | `Option<Self>`                  | `a.checked_sub(b)`  | diff of 2 quantities, works only with the same units, checks for overflow and underflow      |
 /// | [`CheckedMul`](crate::checked::CheckedMul)      | Any      | `Option<Quantity<S, U * URhs>>` | `a.checked_mul(b')` | production of 2 quantities, changes unit, checks for overflow and underflow                  |
 /// | [`CheckedDiv`](crate::checked::CheckedDiv)      | Any      | `Option<Quantity<S, U / URhs>>` | `a.checked_div(b')` | quotation of 2 quantities, changes unit, checks for overflow, underflow and division by zero |
 /// | [`CheckedMul`](crate::checked::CheckedMul)`<S>` | n/a      | `Option<Self>`                  | `a.checked_mul(s)`  | production of quantity and an integer, checks for overflow and underflow                     |
 /// | [`CheckedDiv`](crate::checked::CheckedDiv)`<S>` | n/a      | `Option<Self>`                  | `a.checked_div(s)`  | quotation of quantity and an integer, checks for overflow, underflow and division by zero    |
+/// | [`WrappingAdd`](crate::checked::WrappingAdd)    | Same     | `Self`                          | `a.wrapping_add(b)` | sum of 2 quantities, works only with the same units, wraps around on overflow                |
+/// | [`WrappingSub`](crate::checked::WrappingSub)    | Same     | `Self`                          | `a.wrapping_sub(b)` | diff of 2 quantities, works only with the same units, wraps around on overflow               |
+/// | [`SaturatingAdd`](crate::checked::SaturatingAdd)| Same     | `Self`                          | `a.saturating_add(b)`| sum of 2 quantities, works only with the same units, saturates at the numeric bounds         |
+/// | [`SaturatingSub`](crate::checked::SaturatingSub)| Same     | `Self`                          | `a.saturating_sub(b)`| diff of 2 quantities, works only with the same units, saturates at the numeric bounds        |
+/// | [`CheckedNeg`](crate::checked::CheckedNeg)      | n/a      | `Option<Self>`                  | `a.checked_neg()`   | negation of quantity, checks for overflow (e.g. non-zero unsigned quantities)                |
+/// | [`WrappingNeg`](crate::checked::WrappingNeg)    | n/a      | `Self`                          | `a.wrapping_neg()`  | negation of quantity, wraps around on overflow                                               |
 /// | [`AddAssign`](core::ops::AddAssign)             | Same     | `()`                            | `a += b`            | adds one quantity to another mutating the destination (`a`)                                  |
 /// | [`SubAssign`](core::ops::SubAssign)             | Same     | `()`                            | `a -= b`            | subtracts one quantity from another mutating the destination (`a`)                           |
 /// | [`MulAssign`](core::ops::MulAssign)`<S>`        | n/a      | `()`                            | `a *= s`            | multiplies quantity by an integer mutating the destination (`a`)                             |
 /// | [`DivAssign`](core::ops::DivAssign)`<S>`        | n/a      | `()`                            | `a /= s`            | divides quantity by an integer mutating the destination (`a`)                                |
+/// | [`MulAssign`](core::ops::MulAssign)`<Quantity>` | Dimensionless| `()`                            | `a *= g`            | multiplies quantity by a dimensionless quantity (ratio is respected) mutating the destination|
+/// | [`DivAssign`](core::ops::DivAssign)`<Quantity>` | Dimensionless| `()`                            | `a /= g`            | divides quantity by a dimensionless quantity (ratio is respected) mutating the destination   |
 /// | [`Rem`](core::ops::Rem)                         | Any      | `Quantity<S, U / URhs>`         | `a % b'`            | remainder of the division of 2 quantities                                                    |
 /// | [`Rem`](core::ops::Rem)`<S>`                    | n/a      | `Self`                          | `a % s`             | remainder of the division quantity by an integer                                             |
 /// | [`RemAssign`](core::ops::RemAssign)`<S>`        | n/a      | `()`                            | `a %= s`            | sets `a` to the remainder of division `a` by an integer                                    |
+/// | [`PartialEq`]`<S>`                              | n/a      | `bool`                          | `g == s`            | compares dimensionless quantity with an integer (also `s == g`)                              |
+/// | [`PartialOrd`]`<S>`                             | n/a      | `Option<Ordering>`              | `g < s`             | compares dimensionless quantity with an integer (also `s < g`)                               |
 // to edit such a big table, it's recommended to use smt like https://www.tablesgenerator.com/markdown_tables
 ///
 /// ## Formatting
@@ -110,9 +134,16 @@ use crate::{
 /// assert_eq!(format!("{:e}", quantity), "1.02e3 m"); // LowerExp
 /// assert_eq!(format!("{:E}", quantity), "1.02E3 m"); // UpperExp
 /// ```
+///
+/// ## Layout
+///
+/// `Quantity<S, U>` is `#[repr(transparent)]`, so it's guaranteed to have the
+/// same layout and ABI as `S`. This makes it possible to use quantities in
+/// FFI structs and to view buffers of raw values as typed slices without
+/// copying (see [`from_raw_slice`](Quantity::from_raw_slice)).
 #[cfg_attr(feature = "deser", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "deser", serde(transparent))]
-#[derive(Hash)]
+#[repr(transparent)]
 pub struct Quantity<S, U> {
     storage: S,
     // TODO: think a bit more about the serialization. Currently only the Inner storage is
@@ -171,12 +202,186 @@ impl<S, U> Quantity<S, U> {
     /// assert_eq!(perimeter, 26.m());
     /// ```
     ///
-    /// See also: [`value`](Quantity::value)
+    /// See also: [`value`](Quantity::value), [`get`](Quantity::get) (for const
+    /// context)
     #[inline]
     pub fn into_inner(self) -> S {
         self.storage
     }
 
+    /// Same as [`into_inner`], but can be used in const context (this requires
+    /// `S: Copy`, since otherwise the quantity would need to be dropped).
+    ///
+    /// ## Examples
+    /// ```
+    /// use typed_phy::{prefixes::Milli, units::Second, Quantity};
+    ///
+    /// const TIMEOUT: Quantity<u32, Milli<Second>> = Quantity::new(250);
+    /// const TIMEOUTS: [u32; 2] = [TIMEOUT.get(), TIMEOUT.get() * 2];
+    ///
+    /// assert_eq!(TIMEOUTS, [250, 500]);
+    /// ```
+    ///
+    /// [`into_inner`]: Quantity::into_inner
+    #[inline]
+    pub const fn get(self) -> S
+    where
+        S: Copy,
+    {
+        self.storage
+    }
+
+    /// Return reference to the inner value.
+    ///
+    /// Note: this is an escape hatch just like [`into_inner`], so the same
+    /// warnings apply. It's useful when the storage is big (e.g. arrays or
+    /// bigints) and you don't want to move it out of the quantity.
+    ///
+    /// ## Examples
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// let x = 10.m();
+    /// assert_eq!(x.storage_ref(), &10);
+    /// ```
+    ///
+    /// [`into_inner`]: Quantity::into_inner
+    #[inline]
+    pub const fn storage_ref(&self) -> &S {
+        &self.storage
+    }
+
+    /// Return mutable reference to the inner value.
+    ///
+    /// Note: this is an escape hatch just like [`into_inner`], so the same
+    /// warnings apply. It's useful for in-place mutation of big storages
+    /// (e.g. arrays or bigints) without moving them out of the quantity and
+    /// back.
+    ///
+    /// ## Examples
+    /// ```
+    /// use typed_phy::{units::Metre, Quantity};
+    ///
+    /// let mut samples = Quantity::<[i32; 4], Metre>::new([1, 2, 3, 4]);
+    /// samples.storage_mut()[2] = 10;
+    /// assert_eq!(samples.into_inner(), [1, 2, 10, 4]);
+    /// ```
+    ///
+    /// [`into_inner`]: Quantity::into_inner
+    #[inline]
+    pub fn storage_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    /// Takes the quantity out, leaving zero (`S::default()`) in its place.
+    /// The same as [`core::mem::take`], but the unit stays visible at the
+    /// call site.
+    ///
+    /// ## Examples
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// let mut travelled = 0.m();
+    /// travelled += 120.m();
+    /// travelled += 30.m();
+    ///
+    /// // e.g. report and reset an accumulator
+    /// assert_eq!(travelled.take(), 150.m());
+    /// assert_eq!(travelled, 0.m());
+    /// ```
+    #[inline]
+    pub fn take(&mut self) -> Self
+    where
+        S: Default,
+    {
+        Self::new(core::mem::take(&mut self.storage))
+    }
+
+    /// Replaces the quantity with `new`, returning the old one. The same as
+    /// [`core::mem::replace`], but the unit stays visible at the call site.
+    ///
+    /// ## Examples
+    /// ```
+    /// use typed_phy::{units::Metre, Quantity};
+    ///
+    /// let mut path = Quantity::<[i32; 2], Metre>::new([1, 2]);
+    /// let old = path.replace(Quantity::new([3, 4]));
+    ///
+    /// assert_eq!(old.into_inner(), [1, 2]);
+    /// assert_eq!(path.into_inner(), [3, 4]);
+    /// ```
+    #[inline]
+    pub fn replace(&mut self, new: Self) -> Self {
+        core::mem::replace(self, new)
+    }
+
+    /// Views slice of raw values as a slice of quantities (no copies are
+    /// made).
+    ///
+    /// ## Examples
+    /// ```
+    /// use typed_phy::{prefixes::Milli, units::Volt, Quantity};
+    ///
+    /// // e.g. a buffer filled by a C driver
+    /// let raw: [u16; 3] = [3300, 1650, 0];
+    /// let samples = Quantity::<_, Milli<Volt>>::from_raw_slice(&raw);
+    ///
+    /// assert_eq!(samples[1], Quantity::new(1650));
+    /// assert_eq!(Quantity::as_raw_slice(samples), &raw);
+    /// ```
+    #[inline]
+    pub fn from_raw_slice(slice: &[S]) -> &[Self] {
+        // SAFETY: `Quantity<S, U>` is `#[repr(transparent)]` over `S`, so
+        //         `[S]` and `[Quantity<S, U>]` have the same layout. The
+        //         lifetime is preserved.
+        unsafe { &*(slice as *const [S] as *const [Self]) }
+    }
+
+    /// Views mutable slice of raw values as a mutable slice of quantities (no
+    /// copies are made).
+    ///
+    /// ## Examples
+    /// ```
+    /// use typed_phy::{units::Metre, IntExt, Quantity};
+    ///
+    /// let mut raw = [1, 2, 3];
+    /// for q in Quantity::<_, Metre>::from_raw_slice_mut(&mut raw) {
+    ///     *q += 1.m();
+    /// }
+    /// assert_eq!(raw, [2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn from_raw_slice_mut(slice: &mut [S]) -> &mut [Self] {
+        // SAFETY: see `from_raw_slice`, the slice is borrowed uniquely
+        unsafe { &mut *(slice as *mut [S] as *mut [Self]) }
+    }
+
+    /// Views slice of quantities as a slice of raw values (no copies are
+    /// made).
+    ///
+    /// Note: this is an escape hatch just like [`into_inner`], so the same
+    /// warnings apply.
+    ///
+    /// [`into_inner`]: Quantity::into_inner
+    #[inline]
+    pub fn as_raw_slice(slice: &[Self]) -> &[S] {
+        // SAFETY: see `from_raw_slice`
+        unsafe { &*(slice as *const [Self] as *const [S]) }
+    }
+
+    /// Views mutable slice of quantities as a mutable slice of raw values (no
+    /// copies are made).
+    ///
+    /// Note: this is an escape hatch just like [`into_inner`], so the same
+    /// warnings apply.
+    ///
+    /// [`into_inner`]: Quantity::into_inner
+    #[inline]
+    pub fn as_raw_slice_mut(slice: &mut [Self]) -> &mut [S] {
+        // SAFETY: see `from_raw_slice`, the slice is borrowed uniquely
+        unsafe { &mut *(slice as *mut [Self] as *mut [S]) }
+    }
+
     /// Applies the given function to the raw value.
     ///
     /// Actually not sure if this function even need to exist.
@@ -188,6 +393,55 @@ impl<S, U> Quantity<S, U> {
         Self::new(f(self.storage))
     }
 
+    /// Applies the given function to the raw value, allowing to change the
+    /// storage type. The unit is preserved.
+    ///
+    /// See also: [`map`](Quantity::map)
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{units::Second, IntExt, Quantity};
+    ///
+    /// let ticks: Quantity<i32, Second> = 10.s();
+    /// let secs: Quantity<f64, Second> = ticks.map_storage(f64::from);
+    /// assert_eq!(secs, 10.0.s());
+    /// ```
+    #[inline]
+    pub fn map_storage<F, T>(self, f: F) -> Quantity<T, U>
+    where
+        F: FnOnce(S) -> T,
+    {
+        Quantity::new(f(self.storage))
+    }
+
+    /// Converts the storage to `T` using `as`-style conversion. The unit is
+    /// preserved.
+    ///
+    /// The conversion may be lossy (e.g. `f64` -> `i32` truncates the value
+    /// and saturates on overflow, `i32` -> `u8` wraps), so prefer `From`/
+    /// `TryFrom` impls when they are available. This method exists to make the
+    /// intent visible at the call site.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{units::Metre, IntExt, Quantity};
+    ///
+    /// let x: Quantity<i32, Metre> = 10.7f64.m().cast();
+    /// assert_eq!(x, 10.m());
+    ///
+    /// assert_eq!(300i32.m().cast::<u8>(), 44u8.m());
+    /// assert_eq!(3u8.s().cast::<f32>(), 3.0f32.s());
+    /// ```
+    #[inline]
+    pub fn cast<T>(self) -> Quantity<T, U>
+    where
+        S: AsPrimitive<T>,
+    {
+        Quantity::new(self.storage.as_())
+    }
+
     /// Sets unit to the same unit. It may seem useless, but it (hopefully) can
     /// help IDE understand right type of the expression (e.g. with type
     /// alias)
@@ -199,11 +453,99 @@ impl<S, U> Quantity<S, U> {
         self.id_cast()
     }
 
-    pub(crate) fn set_unit_unchecked<T>(self) -> Quantity<S, T> {
+    /// Changes the unit to `T` keeping the stored value as is, **without**
+    /// checking dimensions or converting the ratio.
+    ///
+    /// This is the escape hatch for FFI/driver layers where the unit of a
+    /// value is known only by convention (e.g. a register holds
+    /// millivolts, but the generic driver returns dimensionless counts).
+    /// Prefer it to `Quantity::new(q.into_inner())`, so all such places can be
+    /// found with a single search. Use [`into_unit`] or [`set_unit`] when
+    /// possible.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{prefixes::Milli, units::Volt, IntExt, Quantity};
+    ///
+    /// // The driver reports raw counts, 1 count = 1 mV by the datasheet
+    /// let raw = 1500u16.dimensionless();
+    /// let v: Quantity<u16, Milli<Volt>> = raw.reinterpret_unit();
+    /// assert_eq!(v.into_inner(), 1500);
+    /// ```
+    ///
+    /// [`into_unit`]: Quantity::into_unit
+    /// [`set_unit`]: Quantity::set_unit
+    #[inline]
+    pub fn reinterpret_unit<T>(self) -> Quantity<S, T> {
         Quantity::new(self.storage)
     }
 }
 
+impl<S, U> Quantity<S, U>
+where
+    S: Zero,
+{
+    /// Creates quantity equal to zero.
+    ///
+    /// See also: `ZERO` constants for primitive storages (e.g.
+    /// `Quantity::<i32, _>::ZERO`), which can be used in `const` contexts.
+    ///
+    /// ## Examples
+    /// ```
+    /// use typed_phy::{units::Metre, IntExt, Quantity};
+    ///
+    /// assert_eq!(Quantity::<i32, Metre>::zero(), 0.m());
+    /// ```
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(S::zero())
+    }
+
+    /// Returns `true` if the quantity is equal to zero.
+    ///
+    /// ## Examples
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// assert!(0.m().is_zero());
+    /// assert!(!1.m().is_zero());
+    /// assert!((-0.0).s().is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool
+    where
+        S: PartialEq,
+    {
+        self.storage == S::zero()
+    }
+}
+
+macro_rules! zero_consts {
+    ($( $S:ty => $zero:literal ),+ $(,)?) => {
+        $(
+            impl<U> Quantity<$S, U> {
+                /// Quantity equal to zero.
+                ///
+                /// ## Examples
+                /// ```
+                /// use typed_phy::{units::Second, IntExt, Quantity};
+                ///
+                /// const NO_DELAY: Quantity<u32, Second> = Quantity::<u32, _>::ZERO;
+                /// assert_eq!(NO_DELAY, 0.s());
+                /// ```
+                pub const ZERO: Self = Self::new($zero);
+            }
+        )+
+    };
+}
+
+zero_consts! {
+    i8 => 0, i16 => 0, i32 => 0, i64 => 0, i128 => 0, isize => 0,
+    u8 => 0, u16 => 0, u32 => 0, u64 => 0, u128 => 0, usize => 0,
+    f32 => 0.0, f64 => 0.0,
+}
+
 impl<S, U> Quantity<S, U>
 where
     U: UnitTrait,
@@ -225,14 +567,48 @@ where
     /// assert_eq!(m, 1.m());
     /// assert_eq!(2.km().set_unit::<Metre>(), 2.m());
     /// ```
-    /// ```compile_fail,E0271
+    /// ```compile_fail,E0277
     /// # use typed_phy::{IntExt, units::Second};
     /// 1.m().set_unit::<Second>();
     /// ```
     #[inline]
     pub fn set_unit<T>(self) -> Quantity<S, T>
     where
-        T: UnitTrait<Dimensions = U::Dimensions>,
+        T: UnitTrait,
+        T::Dimensions: DimensionsEq<U::Dimensions>,
+    {
+        Quantity::new(self.storage)
+    }
+
+    /// Changes the unit to an equal one, i.e. a unit with the same dimensions
+    /// and an equal (but maybe not structurally the same) ratio, e.g.
+    /// `1000/1000` and `1/1`.
+    ///
+    /// Unlike [`into_unit`] this doesn't touch the value at all, and unlike
+    /// [`set_unit`] this can't change the ratio. This is useful to make
+    /// arithmetic (which requires the same unit type) work with such units.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{prefixes::Kilo, units::{Gram, KiloGram}, IntExt, Quantity};
+    ///
+    /// // `Kilo<Gram>` has ratio `1000/1000`, while `KiloGram` has `1/1`
+    /// let a = Quantity::<_, Kilo<Gram>>::new(3);
+    /// assert_eq!(a.into_same_unit::<KiloGram>() + 2.kg(), 5.kg());
+    /// ```
+    ///
+    /// ```compile_fail,E0277
+    /// # use typed_phy::{IntExt, units::Metre};
+    /// 1.km().into_same_unit::<Metre>();
+    /// ```
+    ///
+    /// [`into_unit`]: Self::into_unit
+    /// [`set_unit`]: Self::set_unit
+    #[inline]
+    pub fn into_same_unit<T>(self) -> Quantity<S, T>
+    where
+        U: UnitEq<T>,
     {
         Quantity::new(self.storage)
     }
@@ -245,6 +621,34 @@ where
     pub fn set_ratio<T>(self) -> Quantity<S, Unit<U::Dimensions, T>> {
         Quantity::new(self.storage)
     }
+
+    /// Returns value of the quantity in the 'base' unit (with ratio = 1) as
+    /// `f64`.
+    ///
+    /// This is handy for logging/plotting, but note that conversion to `f64`
+    /// may be lossy. To convert quantity without changing the storage use
+    /// [`into_base`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// assert_eq!(10.km().to_base_f64(), 10_000.0);
+    /// assert_eq!(15.dm().to_base_f64(), 1.5);
+    /// assert_eq!(2.h().to_base_f64(), 7200.0);
+    /// assert_eq!(0.5f32.m().to_base_f64(), 0.5);
+    /// ```
+    ///
+    /// [`into_base`]: Self::into_base
+    #[inline]
+    pub fn to_base_f64(self) -> f64
+    where
+        S: ToF64,
+    {
+        let ratio = U::Ratio::RT;
+        self.storage.to_f64() * ratio.numerator.to_f64() / ratio.divisor.to_f64()
+    }
 }
 
 impl<S> Quantity<S, Dimensionless> {
@@ -280,6 +684,38 @@ impl<S> Quantity<S, Dimensionless> {
     }
 }
 
+impl<S, U> Quantity<S, U>
+where
+    U: UnitTrait<Dimensions = <Dimensionless as UnitTrait>::Dimensions>,
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+{
+    /// Folds the ratio of a dimensionless unit into the value, returning a
+    /// plain [`Dimensionless`] quantity (with ratio `1/1`).
+    ///
+    /// E.g. `1 km / 1 m` is `1` of unit with ratio `1000`, normalizing it
+    /// gives `1000`. Normalized quantities can be used with [`value`] and with
+    /// each other regardless of how they were computed.
+    ///
+    /// Note: with integer storages ratios less than `1` truncate (e.g. `50%`
+    /// becomes `0`), use float storages for percent/ppm math.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{units::Percent, IntExt, Quantity};
+    ///
+    /// assert_eq!((1.km() / 1.m()).normalize().value(), 1000);
+    /// assert_eq!(Quantity::<f64, Percent>::new(50.0).normalize().value(), 0.5);
+    /// assert_eq!((3.0.m() / 2.0.km()).normalize().value(), 0.0015);
+    /// ```
+    ///
+    /// [`value`]: Quantity::value
+    #[inline]
+    pub fn normalize(self) -> Quantity<S, Dimensionless> {
+        self.into_unit()
+    }
+}
+
 impl<S, U> Quantity<S, U>
 where
     U: UnitTrait,
@@ -306,6 +742,31 @@ where
         self.into_unit()
     }
 
+    /// Multiplies the value by the type-level fraction `F` without changing
+    /// the unit (`value * N / D`).
+    ///
+    /// The multiplication is done first, so integer storages only lose the
+    /// fractional part of the result. This is useful for exact ratios of
+    /// gearboxes, clock dividers, voltage dividers, etc.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{Frac, IntExt};
+    /// use typenum::{U2, U3, U7};
+    ///
+    /// assert_eq!(10.m().scale::<Frac![U3 / U2]>(), 15.m());
+    /// // `3/7` isn't representable with floats, but is exact here
+    /// assert_eq!(700u32.rpm().scale::<Frac![U3 / U7]>(), 300.rpm());
+    /// ```
+    #[inline]
+    pub fn scale<F>(self) -> Self
+    where
+        F: FractionTrait,
+    {
+        Self::new(F::mul(self.storage))
+    }
+
     /// Convert self to other unit _saving_ the quantity. (So `1000 m` becomes
     /// `1 km`, not `1000 km`)
     ///
@@ -325,11 +786,14 @@ where
     ///
     /// assert_eq!(3600.s().into_unit::<Hour>(), 1.h());
     /// assert_eq!(5.h().into_unit::<Minute>(), 300.min_());
+    /// assert_eq!(2.wk().into_unit::<Hour>(), 336.h());
+    /// assert_eq!(1i64.yr().into_base(), 31_557_600.s());
     /// ```
     #[inline]
     pub fn into_unit<T>(self) -> Quantity<S, T>
     where
-        T: UnitTrait<Dimensions = U::Dimensions>,
+        T: UnitTrait,
+        T::Dimensions: DimensionsEq<U::Dimensions>,
     {
         Quantity::new(T::Ratio::div(U::Ratio::mul(self.storage)))
     }
@@ -353,133 +817,1082 @@ where
     pub fn into_base(self) -> Quantity<S, Unit<U::Dimensions, One>> {
         self.into_unit()
     }
-}
 
-impl<S, U> Default for Quantity<S, U>
-where
-    S: Default,
-{
+    /// Hashes the quantity converted to the base unit (see [`into_base`]), so
+    /// e.g. `1 km` and `1000 m` have the same hash.
+    ///
+    /// This is useful for implementing [`Hash`] of keys that compare
+    /// quantities of different prefixes by their base values:
+    ///
+    /// ```
+    /// use std::{
+    ///     collections::HashSet,
+    ///     hash::{Hash, Hasher},
+    /// };
+    ///
+    /// use typed_phy::{prefixes::Kilo, units::Metre, IntExt, Quantity};
+    ///
+    /// #[derive(Clone, Copy)]
+    /// enum Distance {
+    ///     M(Quantity<i64, Metre>),
+    ///     Km(Quantity<i64, Kilo<Metre>>),
+    /// }
+    ///
+    /// impl Distance {
+    ///     fn base(self) -> Quantity<i64, Metre> {
+    ///         match self {
+    ///             Distance::M(m) => m,
+    ///             Distance::Km(km) => km.into_base(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl PartialEq for Distance {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.base() == other.base()
+    ///     }
+    /// }
+    ///
+    /// impl Eq for Distance {}
+    ///
+    /// impl Hash for Distance {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         match *self {
+    ///             Distance::M(m) => m.hash_canonical(state),
+    ///             Distance::Km(km) => km.hash_canonical(state),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let set: HashSet<_> = [Distance::Km(1.km()), Distance::M(1000.m())]
+    ///     .iter()
+    ///     .copied()
+    ///     .collect();
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    ///
+    /// Note: with integer storages conversion to a bigger unit truncates, so
+    /// the base unit should be the smallest one used (e.g. `1500 mm` and
+    /// `1 m` have the same hash).
+    ///
+    /// [`into_base`]: Quantity::into_base
     #[inline]
-    fn default() -> Self {
-        Self::new(S::default())
+    pub fn hash_canonical<H: Hasher>(&self, state: &mut H)
+    where
+        S: Hash + Copy,
+    {
+        (*self).into_base().hash(state)
     }
 }
 
-/// Addition between 2 quantities of the same unit (`U`) and storage (`S`).
-///
-/// ## Examples
-/// ```
-/// use typed_phy::IntExt;
-/// assert_eq!(20.s() + 10.s(), 30.s())
-/// ```
-impl<S, U> Add for Quantity<S, U>
+impl<S, U> Quantity<S, U>
 where
-    S: Add<Output = S>,
+    U: UnitTrait<Dimensions = <Hertz as UnitTrait>::Dimensions>,
+    Dimensionless: Div<U>,
 {
-    type Output = Quantity<S, U>;
-
-    #[inline]
-    fn add(self, rhs: Quantity<S, U>) -> Self::Output {
-        self.map(|s| s + rhs.storage)
-    }
-}
-
-/// Subtraction between 2 quantities of the same unit (`U`) and storage (`S`).
-///
+    /// Returns period (`1 / f`) of the frequency.
+    ///
+    /// The unit of the result is computed with the same machinery as for
+    /// division, so e.g. period of kilohertz is in milliseconds.
+    ///
+    /// Note: with integer storages the result is truncated (e.g. period of
+    /// `2 Hz` is `0 s`), so you may want to use float storages or convert the
+    /// quantity to a smaller unit first.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{
+    ///     prefixes::{Kilo, Milli},
+    ///     units::{Hertz, Second},
+    ///     IntExt,
+    /// };
+    ///
+    /// assert_eq!(0.5.quantity::<Hertz>().period(), 2.0.s());
+    /// assert_eq!(
+    ///     1.quantity::<Kilo<Hertz>>().period(),
+    ///     1.quantity::<Milli<Second>>()
+    /// );
+    /// ```
+    #[inline]
+    pub fn period(self) -> Quantity<S, Quot<Dimensionless, U>>
+    where
+        S: FromUnsigned + Div<Output = S>,
+    {
+        Quantity::new(S::from_unsigned::<U1>() / self.storage)
+    }
+
+    /// Same as [`period`], but returns `None` if the frequency is zero (or on
+    /// overflow).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{units::Hertz, IntExt};
+    ///
+    /// assert_eq!(1.quantity::<Hertz>().checked_period(), Some(1.s()));
+    /// assert_eq!(0.quantity::<Hertz>().checked_period(), None);
+    /// ```
+    ///
+    /// [`period`]: Quantity::period
+    #[inline]
+    pub fn checked_period(self) -> Option<Quantity<S, Quot<Dimensionless, U>>>
+    where
+        S: FromUnsigned + CheckedDiv<Output = S>,
+    {
+        S::from_unsigned::<U1>()
+            .checked_div(self.storage)
+            .map(Quantity::new)
+    }
+}
+
+impl<S, U> Quantity<S, U>
+where
+    U: UnitTrait<Dimensions = <Second as UnitTrait>::Dimensions>,
+    Dimensionless: Div<U>,
+{
+    /// Returns frequency (`1 / T`) of the period.
+    ///
+    /// The unit of the result is computed with the same machinery as for
+    /// division, so e.g. frequency of milliseconds is in kilohertz.
+    ///
+    /// Note: with integer storages the result is truncated (e.g. frequency of
+    /// `2 s` is `0 Hz`), so you may want to use float storages or convert the
+    /// quantity to a smaller unit first.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{units::Hertz, IntExt};
+    ///
+    /// assert_eq!(0.25.s().frequency(), 4.0.quantity::<Hertz>());
+    /// assert_eq!(0.25.s().frequency().period(), 0.25.s());
+    /// ```
+    #[inline]
+    pub fn frequency(self) -> Quantity<S, Quot<Dimensionless, U>>
+    where
+        S: FromUnsigned + Div<Output = S>,
+    {
+        Quantity::new(S::from_unsigned::<U1>() / self.storage)
+    }
+
+    /// Same as [`frequency`], but returns `None` if the period is zero (or on
+    /// overflow).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{units::Hertz, IntExt};
+    ///
+    /// assert_eq!(1.s().checked_frequency(), Some(1.quantity::<Hertz>()));
+    /// assert_eq!(0.s().checked_frequency(), None);
+    /// ```
+    ///
+    /// [`frequency`]: Quantity::frequency
+    #[inline]
+    pub fn checked_frequency(self) -> Option<Quantity<S, Quot<Dimensionless, U>>>
+    where
+        S: FromUnsigned + CheckedDiv<Output = S>,
+    {
+        S::from_unsigned::<U1>()
+            .checked_div(self.storage)
+            .map(Quantity::new)
+    }
+}
+
+impl<S, U> Quantity<S, U>
+where
+    U: UnitTrait,
+    Dimensionless: Div<U>,
+{
+    /// Returns reciprocal (`1 / x`) of the quantity.
+    ///
+    /// The unit of the result is computed with the same machinery as for
+    /// division. This is the general form of [`period`] and [`frequency`].
+    ///
+    /// Note: with integer storages the result is truncated, see
+    /// [`recip_into`] for converting into a specific unit.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{
+    ///     units::{Dimensionless, Ohm},
+    ///     IntExt, Quantity, Unit,
+    /// };
+    ///
+    /// let conductance: Quantity<f64, Unit![Dimensionless / Ohm]> =
+    ///     0.5.quantity::<Ohm>().recip();
+    /// assert_eq!(conductance.into_inner(), 2.0);
+    /// ```
+    ///
+    /// [`period`]: Quantity::period
+    /// [`frequency`]: Quantity::frequency
+    /// [`recip_into`]: Quantity::recip_into
+    #[inline]
+    pub fn recip(self) -> Quantity<S, Quot<Dimensionless, U>>
+    where
+        S: FromUnsigned + Div<Output = S>,
+    {
+        Quantity::new(S::from_unsigned::<U1>() / self.storage)
+    }
+
+    /// Returns reciprocal (`1 / x`) of the quantity converted to the unit
+    /// `T`, e.g. fuel consumption in `L/100km` to fuel economy in `km/L` or
+    /// resistivity in `Ω·m` to conductivity in `S/m`.
+    ///
+    /// Unlike `x.recip().into_unit::<T>()` this applies both ratios at once,
+    /// before dividing, so integer storages only lose the fractional part of
+    /// the final result.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{
+    ///     units::{KiloMetrePerLitre, LitrePerHundredKiloMetre},
+    ///     Quantity,
+    /// };
+    ///
+    /// let consumption = Quantity::<u32, LitrePerHundredKiloMetre>::new(5);
+    /// assert_eq!(
+    ///     consumption.recip_into::<KiloMetrePerLitre>(),
+    ///     Quantity::new(20)
+    /// );
+    ///
+    /// let economy = Quantity::<f64, KiloMetrePerLitre>::new(12.5);
+    /// assert_eq!(
+    ///     economy.recip_into::<LitrePerHundredKiloMetre>(),
+    ///     Quantity::new(8.0)
+    /// );
+    /// ```
+    #[inline]
+    pub fn recip_into<T>(self) -> Quantity<S, T>
+    where
+        T: UnitTrait,
+        T::Dimensions: DimensionsEq<<Quot<Dimensionless, U> as UnitTrait>::Dimensions>,
+        Quot<Dimensionless, U>: UnitTrait,
+        U::Ratio: Mul<T::Ratio>,
+        Prod<U::Ratio, T::Ratio>: FractionTrait,
+        S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+    {
+        // `1 / (x * r_u * r_t) = (1 / (r_u * r_t)) / x`
+        let one = S::from_unsigned::<U1>();
+        Quantity::new(<Prod<U::Ratio, T::Ratio>>::div(one) / self.storage)
+    }
+}
+
+/// Accessors in the shape of [`core::time::Duration`] API, to ease migration
+/// of timing code.
+impl<S, U> Quantity<S, U>
+where
+    U: UnitTrait,
+    U::Dimensions: DimensionsEq<<Second as UnitTrait>::Dimensions>,
+{
+    /// Returns the number of seconds as `f64` (including the fractional part).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{prefixes::Milli, units::Second, IntExt, Quantity};
+    ///
+    /// assert_eq!(Quantity::<u32, Milli<Second>>::new(1500).as_secs_f64(), 1.5);
+    /// assert_eq!(2.min_().as_secs_f64(), 120.0);
+    /// ```
+    #[inline]
+    pub fn as_secs_f64(self) -> f64
+    where
+        S: ToF64,
+    {
+        let ratio = U::Ratio::RT;
+        self.storage.to_f64() * ratio.numerator.to_f64() / ratio.divisor.to_f64()
+    }
+
+    /// Returns the number of seconds as `f32` (including the fractional part).
+    #[inline]
+    pub fn as_secs_f32(self) -> f32
+    where
+        S: ToF64,
+    {
+        self.as_secs_f64() as f32
+    }
+}
+
+impl<S, U> Quantity<S, U>
+where
+    U: UnitTrait,
+    U::Dimensions: DimensionsEq<<Second as UnitTrait>::Dimensions>,
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+{
+    /// Returns the number of whole seconds (for integer storages).
+    ///
+    /// Unlike [`Duration::as_secs`] the storage isn't changed, see
+    /// [`checked_cast`] for changing it.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{prefixes::Milli, units::Second, IntExt, Quantity};
+    ///
+    /// let t = Quantity::<u64, Milli<Second>>::new(5_250);
+    /// assert_eq!(t.as_secs(), 5.s());
+    /// assert_eq!(t.as_micros().into_inner(), 5_250_000);
+    /// assert_eq!(t.subsec_millis().into_inner(), 250);
+    /// ```
+    ///
+    /// [`Duration::as_secs`]: core::time::Duration::as_secs
+    /// [`checked_cast`]: Quantity::checked_cast
+    #[inline]
+    pub fn as_secs(self) -> Quantity<S, Second> {
+        self.into_time_unit()
+    }
+
+    /// Returns the number of whole milliseconds (for integer storages).
+    #[inline]
+    pub fn as_millis(self) -> Quantity<S, Milli<Second>> {
+        self.into_time_unit()
+    }
+
+    /// Returns the number of whole microseconds (for integer storages).
+    #[inline]
+    pub fn as_micros(self) -> Quantity<S, Micro<Second>> {
+        self.into_time_unit()
+    }
+
+    /// Returns the number of whole nanoseconds (for integer storages).
+    ///
+    /// Note: this may overflow small storages, e.g. `u32` can only hold ~4
+    /// seconds in nanoseconds. Conversions between sub-second units overflow
+    /// earlier too (e.g. microseconds are multiplied by `1000` before being
+    /// divided by `10^6`).
+    #[inline]
+    pub fn as_nanos(self) -> Quantity<S, Nano<Second>> {
+        self.into_time_unit()
+    }
+
+    /// Like [`into_unit`], but multiplies first, so e.g. microseconds are
+    /// converted to milliseconds without truncating to whole seconds on the
+    /// way (at the cost of overflowing earlier).
+    ///
+    /// [`into_unit`]: Quantity::into_unit
+    fn into_time_unit<T>(self) -> Quantity<S, T>
+    where
+        T: UnitTrait,
+    {
+        Quantity::new(U::Ratio::mul(T::Ratio::div(self.storage)))
+    }
+
+    /// Returns the fractional part of the time in whole milliseconds.
+    ///
+    /// Like `%`, the result is negative for negative times.
+    #[inline]
+    pub fn subsec_millis(self) -> Quantity<S, Milli<Second>>
+    where
+        S: Rem<Output = S>,
+    {
+        Quantity::new(self.as_millis().storage % S::from_unsigned::<Exp<U10, U3>>())
+    }
+
+    /// Returns the fractional part of the time in whole microseconds.
+    ///
+    /// Like `%`, the result is negative for negative times.
+    #[inline]
+    pub fn subsec_micros(self) -> Quantity<S, Micro<Second>>
+    where
+        S: Rem<Output = S>,
+    {
+        Quantity::new(self.as_micros().storage % S::from_unsigned::<Exp<U10, U6>>())
+    }
+
+    /// Returns the fractional part of the time in whole nanoseconds.
+    ///
+    /// Like `%`, the result is negative for negative times.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{prefixes::Nano, units::Second, IntExt, Quantity};
+    ///
+    /// assert_eq!(
+    ///     1.5f64.s().subsec_nanos(),
+    ///     Quantity::<f64, Nano<Second>>::new(500_000_000.0)
+    /// );
+    /// assert_eq!(3i64.h().subsec_nanos().into_inner(), 0);
+    /// ```
+    #[inline]
+    pub fn subsec_nanos(self) -> Quantity<S, Nano<Second>>
+    where
+        S: Rem<Output = S>,
+    {
+        Quantity::new(self.as_nanos().storage % S::from_unsigned::<Exp<U10, U9>>())
+    }
+}
+
+impl<S, U0> Quantity<S, U0>
+where
+    U0: UnitTrait,
+{
+    /// Returns quotient and remainder of `self / rhs` at once (like `(self /
+    /// rhs, self - quotient * rhs)`).
+    ///
+    /// `rhs` is converted to the unit of `self` first (like [`into_unit`]), so
+    /// the quotient is dimensionless and the remainder has the unit of `self`.
+    /// E.g. time can be split into frames and an offset into the current
+    /// frame. For integers both results are computed by one division.
+    ///
+    /// Note: with integer storages `rhs` is truncated when its unit is smaller
+    /// than the unit of `self` (e.g. in `1.km().div_mod(300.m())` it becomes
+    /// `0 km`), convert `self` to the smaller unit first in such cases.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{prefixes::Milli, units::Second, IntExt, Quantity};
+    ///
+    /// type Ms = Quantity<u32, Milli<Second>>;
+    ///
+    /// let (frames, offset) = Ms::new(1_005).div_mod(Ms::new(40));
+    /// assert_eq!(frames, 25.dimensionless());
+    /// assert_eq!(offset, Ms::new(5));
+    ///
+    /// // Units may differ: 2500 m is 2 km and 500 m
+    /// let (q, r) = 2_500.m().div_mod(1.km());
+    /// assert_eq!(q, 2.dimensionless());
+    /// assert_eq!(r, 500.m());
+    /// ```
+    ///
+    /// [`into_unit`]: Quantity::into_unit
+    #[inline]
+    pub fn div_mod<U1>(self, rhs: Quantity<S, U1>) -> (Quantity<S, Dimensionless>, Self)
+    where
+        S: FromUnsigned + Mul<Output = S> + Div<Output = S> + Rem<Output = S> + Copy,
+        U1: UnitTrait + Div<U0>,
+        U0::Dimensions: DimensionsEq<U1::Dimensions>,
+        Quot<U1, U0>: UnitTrait,
+    {
+        // Apply only the ratio between the units, so `rhs` isn't truncated when
+        // it has the same unit as `self`
+        let rhs = <Quot<U1, U0> as UnitTrait>::Ratio::mul(rhs.storage);
+        (
+            Quantity::new(self.storage / rhs),
+            Self::new(self.storage % rhs),
+        )
+    }
+}
+
+/// Snapping to a grid.
+///
+/// All functions expect `step` to be positive.
+impl<S, U> Quantity<S, U>
+where
+    S: Rem<Output = S> + PartialOrd + Zero + Copy,
+{
+    /// Returns `self % step` in `[0, step)` (unlike `%` which keeps the sign of
+    /// `self`).
+    #[inline]
+    fn grid_offset(self, step: S) -> S
+    where
+        S: Add<Output = S>,
+    {
+        let r = self.storage % step;
+        if r < S::zero() {
+            r + step
+        } else {
+            r
+        }
+    }
+
+    /// Rounds the quantity down to a multiple of `step`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// assert_eq!(17.m().floor_to(5.m()), 15.m());
+    /// assert_eq!((-17).m().floor_to(5.m()), (-20).m());
+    /// ```
+    #[inline]
+    pub fn floor_to(self, step: Self) -> Self
+    where
+        S: Add<Output = S> + Sub<Output = S>,
+    {
+        Self::new(self.storage - self.grid_offset(step.storage))
+    }
+
+    /// Rounds the quantity up to a multiple of `step`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// assert_eq!(17.m().ceil_to(5.m()), 20.m());
+    /// assert_eq!(15.m().ceil_to(5.m()), 15.m());
+    /// ```
+    #[inline]
+    pub fn ceil_to(self, step: Self) -> Self
+    where
+        S: Add<Output = S> + Sub<Output = S>,
+    {
+        let r = self.grid_offset(step.storage);
+        if r == S::zero() {
+            self
+        } else {
+            // `step - r` is in `(0, step)`, so adding it overflows only if the result doesn't fit
+            Self::new(self.storage + (step.storage - r))
+        }
+    }
+
+    /// Rounds the quantity to the nearest multiple of `step` (ties are rounded
+    /// up).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{prefixes::Kilo, units::Hertz, Quantity};
+    ///
+    /// type KHz = Quantity<u32, Kilo<Hertz>>;
+    ///
+    /// // Snap to 25 kHz channel
+    /// assert_eq!(KHz::new(144_812).round_to(KHz::new(25)), KHz::new(144_800));
+    /// assert_eq!(KHz::new(144_813).round_to(KHz::new(25)), KHz::new(144_825));
+    /// ```
+    #[inline]
+    pub fn round_to(self, step: Self) -> Self
+    where
+        S: Add<Output = S> + Sub<Output = S>,
+    {
+        let r = self.grid_offset(step.storage);
+        if step.storage - r <= r {
+            Self::new(self.storage + (step.storage - r))
+        } else {
+            Self::new(self.storage - r)
+        }
+    }
+
+    /// Same as [`floor_to`], but returns `None` if `step` isn't positive or on
+    /// overflow.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// assert_eq!(17.m().checked_floor_to(5.m()), Some(15.m()));
+    /// assert_eq!(17.m().checked_floor_to(0.m()), None);
+    /// assert_eq!(i8::MIN.m().checked_floor_to(3.m()), None);
+    /// ```
+    ///
+    /// [`floor_to`]: Quantity::floor_to
+    #[inline]
+    pub fn checked_floor_to(self, step: Self) -> Option<Self>
+    where
+        S: Add<Output = S> + CheckedSub<Output = S>,
+    {
+        if step.storage <= S::zero() {
+            return None;
+        }
+
+        let r = self.grid_offset(step.storage);
+        self.storage.checked_sub(r).map(Self::new)
+    }
+
+    /// Same as [`ceil_to`], but returns `None` if `step` isn't positive or on
+    /// overflow.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// assert_eq!(17u8.m().checked_ceil_to(5.m()), Some(20.m()));
+    /// assert_eq!(254u8.m().checked_ceil_to(10.m()), None);
+    /// assert_eq!(i8::MIN.m().checked_ceil_to(3.m()), Some((-126).m()));
+    /// ```
+    ///
+    /// [`ceil_to`]: Quantity::ceil_to
+    #[inline]
+    pub fn checked_ceil_to(self, step: Self) -> Option<Self>
+    where
+        S: Add<Output = S> + Sub<Output = S> + CheckedAdd<Output = S>,
+    {
+        if step.storage <= S::zero() {
+            return None;
+        }
+
+        let r = self.grid_offset(step.storage);
+        if r == S::zero() {
+            Some(self)
+        } else {
+            // `step - r` is in `(0, step)`, so it can't overflow
+            self.storage.checked_add(step.storage - r).map(Self::new)
+        }
+    }
+
+    /// Same as [`round_to`], but returns `None` if `step` isn't positive or on
+    /// overflow.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// assert_eq!(250u8.m().checked_round_to(100.m()), None);
+    /// assert_eq!(240u8.m().checked_round_to(100.m()), Some(200.m()));
+    /// assert_eq!(i8::MIN.m().checked_round_to(3.m()), None);
+    /// ```
+    ///
+    /// [`round_to`]: Quantity::round_to
+    #[inline]
+    pub fn checked_round_to(self, step: Self) -> Option<Self>
+    where
+        S: Add<Output = S> + Sub<Output = S> + CheckedAdd<Output = S> + CheckedSub<Output = S>,
+    {
+        if step.storage <= S::zero() {
+            return None;
+        }
+
+        // `r` and `step - r` are in `[0, step]`, so they can't overflow
+        let r = self.grid_offset(step.storage);
+        if step.storage - r <= r {
+            self.storage.checked_add(step.storage - r).map(Self::new)
+        } else {
+            self.storage.checked_sub(r).map(Self::new)
+        }
+    }
+}
+
+/// Total ordering (useful for float storages those don't implement [`Ord`]).
+impl<S, U> Quantity<S, U>
+where
+    S: TotalCmp,
+{
+    /// Compares quantities using total order of the storage (for floats it's
+    /// the one of `f64::total_cmp`, so e.g. `NaN`s are ordered too).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use typed_phy::IntExt;
+    ///
+    /// let mut buf = [3.0.m(), f64::NAN.m(), (-1.0).m(), 2.0.m()];
+    /// buf.sort_by(|a, b| a.total_cmp(b));
+    /// assert_eq!(buf[..3], [(-1.0).m(), 2.0.m(), 3.0.m()]);
+    /// assert!(buf[3].into_inner().is_nan());
+    ///
+    /// assert_eq!((-0.0).m().total_cmp(&0.0.m()), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.storage.total_cmp(&other.storage)
+    }
+
+    /// Returns the maximum of 2 quantities using [`total_cmp`]. If they are
+    /// equal, returns `other`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// assert_eq!(1.5.m().max_by_total(2.5.m()), 2.5.m());
+    /// assert_eq!(1.5.m().min_by_total(2.5.m()), 1.5.m());
+    /// // Positive `NaN` is bigger than any number in total order
+    /// assert!(1.5.m().max_by_total(f64::NAN.m()).into_inner().is_nan());
+    /// ```
+    ///
+    /// [`total_cmp`]: Self::total_cmp
+    #[inline]
+    pub fn max_by_total(self, other: Self) -> Self {
+        match self.total_cmp(&other) {
+            Ordering::Greater => self,
+            Ordering::Less | Ordering::Equal => other,
+        }
+    }
+
+    /// Returns the minimum of 2 quantities using [`total_cmp`]. If they are
+    /// equal, returns `self`.
+    ///
+    /// [`total_cmp`]: Self::total_cmp
+    #[inline]
+    pub fn min_by_total(self, other: Self) -> Self {
+        match self.total_cmp(&other) {
+            Ordering::Greater => other,
+            Ordering::Less | Ordering::Equal => self,
+        }
+    }
+}
+
+macro_rules! angular_velocity_impls {
+    ($( $S:ident ),+) => {
+        $(
+            impl Quantity<$S, RevolutionsPerMinute> {
+                /// Converts rotational frequency in revolutions per minute into
+                /// angular velocity (radians per second), multiplying the value by
+                /// `2π / 60`.
+                ///
+                /// Note: since both revolutions and radians are dimensionless,
+                /// [`RadianPerSecond`] is the same type as [`Hertz`], so the unit system
+                /// can't catch the `2π` for you. That's why this method is only
+                /// available for [`RevolutionsPerMinute`] and not for other units of
+                /// frequency (including radians per second themselves).
+                ///
+                /// ## Examples
+                ///
+                /// ```
+                /// use typed_phy::IntExt;
+                ///
+                /// let omega = 60.0f64.rpm().to_angular_velocity();
+                /// assert_eq!(omega.into_inner(), 2.0 * core::f64::consts::PI);
+                /// ```
+                ///
+                /// ```compile_fail
+                /// use typed_phy::{units::RadianPerSecond, Quantity};
+                ///
+                /// let omega = Quantity::<f64, RadianPerSecond>::new(1.0);
+                /// let _ = omega.to_angular_velocity();
+                /// ```
+                ///
+                /// [`RevolutionsPerMinute`]: crate::units::RevolutionsPerMinute
+                /// [`RadianPerSecond`]: crate::units::RadianPerSecond
+                /// [`Hertz`]: crate::units::Hertz
+                #[inline]
+                pub fn to_angular_velocity(self) -> Quantity<$S, RadianPerSecond> {
+                    Quantity::new(self.storage * (2.0 * core::$S::consts::PI / 60.0))
+                }
+            }
+        )+
+    };
+}
+
+angular_velocity_impls!(f32, f64);
+
+impl<S, U> Default for Quantity<S, U>
+where
+    S: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+/// Addition between 2 quantities of the same unit (`U0`).
+///
+/// Units must be the same type (see [`SameUnit`]), use
+/// [`Quantity::into_same_unit`] for units those are equal, but structurally
+/// different (e.g. ratio `1000/1000` vs `1/1`). The unit of `rhs` must be
+/// known, i.e. `q + Quantity::new(1)` doesn't compile, use
+/// `q + Quantity::<_, Metre>::new(1)` instead.
+///
+/// Storages may differ if `S0: Add<S1>`, the storage of the result is
+/// `S0::Output`. Note that primitive numbers implement operators only for
+/// the same type (e.g. there is no `i64: Add<i32>`, and such impls would break
+/// inference of integer literals), so this is mostly useful for custom
+/// storages.
+///
 /// ## Examples
 /// ```
-/// use typed_phy::IntExt;
-/// assert_eq!(20.s() - 10.s(), 10.s())
+/// use core::ops::Add;
+/// use typed_phy::{units::Second, IntExt, Quantity};
+///
+/// assert_eq!(20.s() + 10.s(), 30.s());
+///
+/// // `i64` accumulator, `i32` samples
+/// #[derive(Debug, PartialEq)]
+/// struct Acc(i64);
+///
+/// impl Add<i32> for Acc {
+///     type Output = Acc;
+///
+///     fn add(self, rhs: i32) -> Acc {
+///         Acc(self.0 + i64::from(rhs))
+///     }
+/// }
+///
+/// // With the `strict` feature operators use the checked traits
+/// # #[cfg(feature = "strict")]
+/// impl typed_phy::checked::CheckedAdd<i32> for Acc {
+///     fn checked_add(self, rhs: i32) -> Option<Acc> {
+///         self.0.checked_add(i64::from(rhs)).map(Acc)
+///     }
+/// }
+///
+/// let total = Quantity::<_, Second>::new(Acc(i32::MAX.into())) + 1.s();
+/// assert_eq!(total, Quantity::new(Acc(1 << 31)));
 /// ```
-impl<S, U> Sub for Quantity<S, U>
+impl<S0, S1, U0, U1> Add<Quantity<S1, U1>> for Quantity<S0, U0>
 where
-    S: Sub<Output = S>,
+    S0: StorageAdd<U0, S1>,
+    U1: SameUnit<U0>,
 {
-    type Output = Quantity<S, U>;
+    type Output = Quantity<S0::Output, U0>;
 
     #[inline]
-    fn sub(self, rhs: Quantity<S, U>) -> Self::Output {
-        self.map(|s| s - rhs.storage)
+    #[cfg_attr(feature = "strict", track_caller)]
+    fn add(self, rhs: Quantity<S1, U1>) -> Self::Output {
+        Quantity::new(self.storage.storage_add(rhs.storage))
     }
 }
 
-/// Multiplication between 2 quantities of the same storage (`S`).
+/// Subtraction between 2 quantities of the same unit (`U`).
+///
+/// Storages may differ if `S0: Sub<S1>`, the storage of the result is
+/// `S0::Output` (see the `Add` impl).
 ///
 /// ## Examples
 /// ```
 /// use typed_phy::IntExt;
-/// assert_eq!(20.m() * 10.m(), 200.sqm()) // TODO example with different units
+/// assert_eq!(20.s() - 10.s(), 10.s())
 /// ```
-impl<S, U0, U1> Mul<Quantity<S, U1>> for Quantity<S, U0>
+impl<S0, S1, U0, U1> Sub<Quantity<S1, U1>> for Quantity<S0, U0>
 where
-    S: Mul<Output = S>,
-    U0: UnitTrait + Mul<U1>,
-    U1: UnitTrait,
+    S0: StorageSub<U0, S1>,
+    U1: SameUnit<U0>,
 {
-    type Output = Quantity<S, Prod<U0, U1>>;
+    type Output = Quantity<S0::Output, U0>;
 
     #[inline]
-    fn mul(self, rhs: Quantity<S, U1>) -> Self::Output {
-        self.map(|s| s * rhs.storage).set_unit_unchecked()
+    #[cfg_attr(feature = "strict", track_caller)]
+    fn sub(self, rhs: Quantity<S1, U1>) -> Self::Output {
+        Quantity::new(self.storage.storage_sub(rhs.storage))
     }
 }
 
-/// Division between 2 quantities of the same storage (`S`).
+/// Multiplication between 2 quantities.
+///
+/// Storages may differ if `S0: Mul<S1>`, the storage of the result is
+/// `S0::Output` (see the `Add` impl).
 ///
 /// ## Examples
 /// ```
 /// use typed_phy::IntExt;
-/// assert_eq!(20.m() / 10.s(), 2.mps())
+/// assert_eq!(20.m() * 10.m(), 200.sqm()) // TODO example with different units
 /// ```
-impl<S, U0, U1> Div<Quantity<S, U1>> for Quantity<S, U0>
+impl<S0, S1, U0, U1> Mul<Quantity<S1, U1>> for Quantity<S0, U0>
 where
-    S: Div<Output = S>,
-    U0: UnitTrait + Div<U1>,
+    S0: StorageMul<U0, U1, S1>,
+    U0: UnitTrait + Mul<U1>,
     U1: UnitTrait,
 {
-    type Output = Quantity<S, Quot<U0, U1>>;
+    type Output = Quantity<S0::Output, Prod<U0, U1>>;
 
     #[inline]
-    fn div(self, rhs: Quantity<S, U1>) -> Self::Output {
-        self.map(|s| s / rhs.storage).set_unit_unchecked()
+    #[cfg_attr(feature = "strict", track_caller)]
+    fn mul(self, rhs: Quantity<S1, U1>) -> Self::Output {
+        Quantity::new(self.storage.storage_mul(rhs.storage))
     }
 }
 
-/// Multiplication between quantity and integer.
+/// Division between 2 quantities.
+///
+/// Storages may differ if `S0: Div<S1>`, the storage of the result is
+/// `S0::Output` (see the `Add` impl).
 ///
 /// ## Examples
 /// ```
 /// use typed_phy::IntExt;
-/// assert_eq!(1.m() * 10, 10.m())
+/// assert_eq!(20.m() / 10.s(), 2.mps())
 /// ```
-impl<S, U> Mul<S> for Quantity<S, U>
+impl<S0, S1, U0, U1> Div<Quantity<S1, U1>> for Quantity<S0, U0>
 where
-    S: Mul<Output = S>,
+    S0: StorageDiv<U0, U1, S1>,
+    U0: UnitTrait + Div<U1>,
+    U1: UnitTrait,
 {
-    type Output = Self;
+    type Output = Quantity<S0::Output, Quot<U0, U1>>;
 
     #[inline]
-    fn mul(self, rhs: S) -> Self::Output {
-        self.map(|s| s * rhs)
+    #[cfg_attr(feature = "strict", track_caller)]
+    fn div(self, rhs: Quantity<S1, U1>) -> Self::Output {
+        Quantity::new(self.storage.storage_div(rhs.storage))
     }
 }
 
-/// Division between quantity and integer.
+// Operators with scalars are implemented for every storage separately (and not
+// as `impl<S, U> Mul<S> for Quantity<S, U>`) because a generic impl would
+// overlap with the impls for 2 quantities of different storages.
+macro_rules! scalar_impls {
+    ($( [$( $gen:ident ),*] $S:ty ),+ $(,)?) => {
+        $(
+            /// Multiplication between quantity and scalar.
+            ///
+            /// ## Examples
+            /// ```
+            /// use typed_phy::IntExt;
+            /// assert_eq!(1.m() * 10, 10.m())
+            /// ```
+            impl<$( $gen, )* U> Mul<$S> for Quantity<$S, U>
+            where
+                $S: Mul<Output = $S>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: $S) -> Self::Output {
+                    self.map(|s| s * rhs)
+                }
+            }
+
+            /// Division between quantity and scalar.
+            ///
+            /// ## Examples
+            /// ```
+            /// use typed_phy::IntExt;
+            /// assert_eq!(20.m() / 2, 10.m())
+            /// ```
+            impl<$( $gen, )* U> Div<$S> for Quantity<$S, U>
+            where
+                $S: Div<Output = $S>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: $S) -> Self::Output {
+                    self.map(|s| s / rhs)
+                }
+            }
+        )+
+    };
+}
+
+scalar_impls!(
+    [] i8, [] i16, [] i32, [] i64, [] i128, [] isize,
+    [] u8, [] u16, [] u32, [] u64, [] u128, [] usize,
+    [] f32, [] f64,
+);
+#[cfg(feature = "complex")]
+scalar_impls!([T] num_complex::Complex<T>);
+
+macro_rules! scalar_lhs_impls {
+    ($( $S:ty ),+ $(,)?) => {
+        $(
+            /// Multiplication between integer and quantity.
+            ///
+            /// ## Examples
+            /// ```
+            /// use typed_phy::IntExt;
+            /// assert_eq!(10 * 1.m(), 10.m())
+            /// ```
+            impl<U> Mul<Quantity<$S, U>> for $S {
+                type Output = Quantity<$S, U>;
+
+                #[inline]
+                fn mul(self, rhs: Quantity<$S, U>) -> Self::Output {
+                    rhs.map(|s| self * s)
+                }
+            }
+
+            /// Division between integer and quantity.
+            ///
+            /// ## Examples
+            /// ```
+            /// use typed_phy::{units::Hertz, IntExt};
+            /// assert_eq!(20 / 2.s(), 10.quantity::<Hertz>())
+            /// ```
+            impl<U> Div<Quantity<$S, U>> for $S
+            where
+                U: UnitTrait,
+                Dimensionless: Div<U>,
+            {
+                type Output = Quantity<$S, Quot<Dimensionless, U>>;
+
+                #[inline]
+                fn div(self, rhs: Quantity<$S, U>) -> Self::Output {
+                    Quantity::new(self / rhs.storage)
+                }
+            }
+        )+
+    };
+}
+
+scalar_lhs_impls!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<S, U> Quantity<S, U> {
+    /// Raises quantity to the (type-level) integer power `P`, changes unit.
+    ///
+    /// Note: for negative `P` the result is `1 / self^|P|`, so with integer
+    /// storages it's truncated (to `0` unless `self` is `±1`), and it panics
+    /// if `self` is `0`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{
+    ///     prefixes::Kilo,
+    ///     units::{Hertz, Metre},
+    ///     IntExt, Unit,
+    /// };
+    /// use typenum::{N1, N2, P2, P3, Z0};
+    ///
+    /// assert_eq!(3.m().powi::<P2>(), 9.sqm());
+    /// assert_eq!(2.km().powi::<P3>(), 8.quantity::<Unit![Kilo<Metre> ^ 3]>());
+    /// assert_eq!(0.5.s().powi::<N1>(), 2.0.quantity::<Hertz>());
+    /// assert_eq!(10.m().powi::<Z0>(), 1.dimensionless());
+    ///
+    /// // Truncated with integer storage
+    /// assert_eq!(2.s().powi::<N2>().into_inner(), 0);
+    /// ```
+    #[inline]
+    pub fn powi<P>(self) -> Quantity<S, <P as sealed::Exponent<U>>::Power>
+    where
+        P: Integer + sealed::Exponent<U>,
+        S: FromUnsigned + Mul<Output = S> + Div<Output = S> + Copy,
+    {
+        let one = S::from_unsigned::<U1>();
+        let mut acc = one;
+        for _ in 0..P::I32.abs() {
+            acc = acc * self.storage;
+        }
+
+        if P::I32 < 0 {
+            acc = one / acc;
+        }
+
+        Quantity::new(acc)
+    }
+}
+
+mod sealed {
+    use typenum::{Exp, Integer, Pow};
+
+    /// Flipped [`Pow`]: `U ^ Self`. The exponent is the self type, so the
+    /// trait solver doesn't try to guess it while resolving
+    /// [`Quantity::powi`](super::Quantity::powi).
+    pub trait Exponent<U> {
+        type Power;
+    }
+
+    impl<P: Integer, U: Pow<P>> Exponent<U> for P {
+        type Power = Exp<U, P>;
+    }
+}
+
+/// Raising quantity to the (type-level) integer power `P`, changes unit. Same
+/// as [`Quantity::powi`], which should be preferred (the trait method can be
+/// called only with the fully qualified syntax).
 ///
 /// ## Examples
+///
 /// ```
-/// use typed_phy::IntExt;
-/// assert_eq!(20.m() / 2, 10.m())
+/// use typed_phy::{units::Metre, IntExt, Quantity};
+/// use typenum::{Pow, P2};
+///
+/// assert_eq!(<Quantity<_, Metre> as Pow<P2>>::powi(3.m(), P2::new()), 9.sqm());
 /// ```
-impl<S, U> Div<S> for Quantity<S, U>
+impl<S, U, P> Pow<P> for Quantity<S, U>
 where
-    S: Div<Output = S>,
+    P: Integer,
+    U: Pow<P>,
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S> + Copy,
 {
-    type Output = Self;
+    type Output = Quantity<S, Exp<U, P>>;
 
     #[inline]
-    fn div(self, rhs: S) -> Self::Output {
-        self.map(|s| s / rhs)
+    fn powi(self, _exp: P) -> Self::Output {
+        Quantity::powi::<P>(self)
     }
 }
 
@@ -497,6 +1910,10 @@ where
 
 /// Addition between 2 quantities of the same unit (`U`) and storage (`S`).
 ///
+/// Units must be the same type, use [`Quantity::into_same_unit`] for units
+/// those are equal, but structurally different (e.g. ratio `1000/1000` vs
+/// `1/1`).
+///
 /// ## Examples
 /// ```
 /// use typed_phy::{checked::CheckedAdd, IntExt};
@@ -505,7 +1922,7 @@ where
 /// ```
 impl<S, U> CheckedAdd for Quantity<S, U>
 where
-    S: CheckedAdd<Output = S>,
+    S: CheckedAdd<Output = S> + StorageAdd<U, Output = S>,
 {
     #[inline]
     fn checked_add(self, rhs: Quantity<S, U>) -> Option<Self::Output> {
@@ -523,7 +1940,7 @@ where
 /// ```
 impl<S, U> CheckedSub for Quantity<S, U>
 where
-    S: CheckedSub<Output = S>,
+    S: CheckedSub<Output = S> + StorageSub<U, Output = S>,
 {
     #[inline]
     fn checked_sub(self, rhs: Quantity<S, U>) -> Option<Self::Output> {
@@ -531,82 +1948,193 @@ where
     }
 }
 
-/// Multiplication between 2 quantities of the same storage (`S`).
+/// Addition between 2 quantities of the same unit (`U`) and storage (`S`)
+/// that wraps around on overflow.
 ///
 /// ## Examples
 /// ```
-/// use typed_phy::{checked::CheckedMul, IntExt};
-/// assert_eq!(20.m().checked_mul(10.m()), Some(200.sqm())); // TODO example with different units
-/// assert_eq!(20.m().checked_mul(107374199.m()), None);
+/// use typed_phy::{checked::WrappingAdd, IntExt};
+/// assert_eq!(250u8.s().wrapping_add(10.s()), 4.s());
 /// ```
-impl<S, U0, U1> CheckedMul<Quantity<S, U1>> for Quantity<S, U0>
+impl<S, U> WrappingAdd for Quantity<S, U>
 where
-    S: CheckedMul<Output = S>,
-    U0: UnitTrait + Mul<U1>,
-    U1: UnitTrait,
+    S: WrappingAdd<Output = S> + StorageAdd<U, Output = S>,
 {
     #[inline]
-    fn checked_mul(self, rhs: Quantity<S, U1>) -> Option<Self::Output> {
-        self.storage.checked_mul(rhs.storage).map(Quantity::new)
+    fn wrapping_add(self, rhs: Quantity<S, U>) -> Self::Output {
+        Self::new(self.storage.wrapping_add(rhs.storage))
     }
 }
 
-/// Division between 2 quantities of the same storage (`S`).
+/// Subtraction between 2 quantities of the same unit (`U`) and storage (`S`)
+/// that wraps around on overflow.
 ///
 /// ## Examples
 /// ```
-/// use typed_phy::{checked::CheckedDiv, IntExt};
-/// assert_eq!(20.m().checked_div(10.s()), Some(2.mps()));
-/// assert_eq!(20.m().checked_div(0.s()), None);
+/// use typed_phy::{checked::WrappingSub, IntExt};
+/// assert_eq!(4u8.s().wrapping_sub(10.s()), 250.s());
 /// ```
-impl<S, U0, U1> CheckedDiv<Quantity<S, U1>> for Quantity<S, U0>
+impl<S, U> WrappingSub for Quantity<S, U>
 where
-    S: CheckedDiv<Output = S>,
-    U0: UnitTrait + Div<U1>,
-    U1: UnitTrait,
+    S: WrappingSub<Output = S> + StorageSub<U, Output = S>,
 {
     #[inline]
-    fn checked_div(self, rhs: Quantity<S, U1>) -> Option<Self::Output> {
-        self.storage.checked_div(rhs.storage).map(Quantity::new)
+    fn wrapping_sub(self, rhs: Quantity<S, U>) -> Self::Output {
+        Self::new(self.storage.wrapping_sub(rhs.storage))
+    }
+}
+
+/// Addition between 2 quantities of the same unit (`U`) and storage (`S`)
+/// that saturates at the numeric bounds.
+///
+/// ## Examples
+/// ```
+/// use typed_phy::{checked::SaturatingAdd, IntExt};
+/// assert_eq!(250u8.s().saturating_add(10.s()), 255.s());
+/// ```
+impl<S, U> SaturatingAdd for Quantity<S, U>
+where
+    S: SaturatingAdd<Output = S> + StorageAdd<U, Output = S>,
+{
+    #[inline]
+    fn saturating_add(self, rhs: Quantity<S, U>) -> Self::Output {
+        Self::new(self.storage.saturating_add(rhs.storage))
+    }
+}
+
+/// Subtraction between 2 quantities of the same unit (`U`) and storage (`S`)
+/// that saturates at the numeric bounds.
+///
+/// ## Examples
+/// ```
+/// use typed_phy::{checked::SaturatingSub, IntExt};
+/// assert_eq!(4u8.s().saturating_sub(10.s()), 0.s());
+/// ```
+impl<S, U> SaturatingSub for Quantity<S, U>
+where
+    S: SaturatingSub<Output = S> + StorageSub<U, Output = S>,
+{
+    #[inline]
+    fn saturating_sub(self, rhs: Quantity<S, U>) -> Self::Output {
+        Self::new(self.storage.saturating_sub(rhs.storage))
+    }
+}
+
+/// Negation of a quantity that returns `None` on overflow.
+///
+/// ## Examples
+/// ```
+/// use typed_phy::{checked::CheckedNeg, IntExt};
+/// assert_eq!(4i8.s().checked_neg(), Some((-4).s()));
+/// assert_eq!((-128i8).s().checked_neg(), None);
+/// assert_eq!(4u8.s().checked_neg(), None);
+/// ```
+impl<S, U> CheckedNeg for Quantity<S, U>
+where
+    S: CheckedNeg,
+{
+    #[inline]
+    fn checked_neg(self) -> Option<Self> {
+        self.storage.checked_neg().map(Self::new)
     }
 }
 
-/// Multiplication between quantity and integer.
+/// Negation of a quantity that wraps around on overflow.
+///
+/// ## Examples
+/// ```
+/// use typed_phy::{checked::WrappingNeg, IntExt};
+/// assert_eq!((-128i8).s().wrapping_neg(), (-128).s());
+/// assert_eq!(1u8.s().wrapping_neg(), 255.s());
+/// ```
+impl<S, U> WrappingNeg for Quantity<S, U>
+where
+    S: WrappingNeg,
+{
+    #[inline]
+    fn wrapping_neg(self) -> Self {
+        Self::new(self.storage.wrapping_neg())
+    }
+}
+
+/// Multiplication between 2 quantities of the same storage (`S`).
 ///
 /// ## Examples
 /// ```
 /// use typed_phy::{checked::CheckedMul, IntExt};
-/// assert_eq!(1.m().checked_mul(10), Some(10.m()));
-/// assert_eq!(i32::max_value().m().checked_mul(10), None);
+/// assert_eq!(20.m().checked_mul(10.m()), Some(200.sqm())); // TODO example with different units
+/// assert_eq!(20.m().checked_mul(107374199.m()), None);
 /// ```
-impl<S, U> CheckedMul<S> for Quantity<S, U>
+impl<S, U0, U1> CheckedMul<Quantity<S, U1>> for Quantity<S, U0>
 where
-    S: CheckedMul<Output = S>,
+    S: CheckedMul<Output = S> + StorageMul<U0, U1, Output = S>,
+    U0: UnitTrait + Mul<U1>,
+    U1: UnitTrait,
 {
     #[inline]
-    fn checked_mul(self, rhs: S) -> Option<Self::Output> {
-        self.storage.checked_mul(rhs).map(Self::new)
+    fn checked_mul(self, rhs: Quantity<S, U1>) -> Option<Self::Output> {
+        self.storage.checked_mul(rhs.storage).map(Quantity::new)
     }
 }
 
-/// Division between quantity and integer.
+/// Division between 2 quantities of the same storage (`S`).
 ///
 /// ## Examples
 /// ```
 /// use typed_phy::{checked::CheckedDiv, IntExt};
-/// assert_eq!(20.m().checked_div(2), Some(10.m()));
-/// assert_eq!(20.m().checked_div(0), None);
+/// assert_eq!(20.m().checked_div(10.s()), Some(2.mps()));
+/// assert_eq!(20.m().checked_div(0.s()), None);
 /// ```
-impl<S, U> CheckedDiv<S> for Quantity<S, U>
+impl<S, U0, U1> CheckedDiv<Quantity<S, U1>> for Quantity<S, U0>
 where
-    S: CheckedDiv<Output = S>,
+    S: CheckedDiv<Output = S> + StorageDiv<U0, U1, Output = S>,
+    U0: UnitTrait + Div<U1>,
+    U1: UnitTrait,
 {
     #[inline]
-    fn checked_div(self, rhs: S) -> Option<Self::Output> {
-        self.storage.checked_div(rhs).map(Self::new)
+    fn checked_div(self, rhs: Quantity<S, U1>) -> Option<Self::Output> {
+        self.storage.checked_div(rhs.storage).map(Quantity::new)
     }
 }
 
+macro_rules! checked_scalar_impls {
+    ($( $S:ty ),+ $(,)?) => {
+        $(
+            /// Multiplication between quantity and integer.
+            ///
+            /// ## Examples
+            /// ```
+            /// use typed_phy::{checked::CheckedMul, IntExt};
+            /// assert_eq!(1.m().checked_mul(10), Some(10.m()));
+            /// assert_eq!(i32::max_value().m().checked_mul(10), None);
+            /// ```
+            impl<U> CheckedMul<$S> for Quantity<$S, U> {
+                #[inline]
+                fn checked_mul(self, rhs: $S) -> Option<Self::Output> {
+                    self.storage.checked_mul(rhs).map(Self::new)
+                }
+            }
+
+            /// Division between quantity and integer.
+            ///
+            /// ## Examples
+            /// ```
+            /// use typed_phy::{checked::CheckedDiv, IntExt};
+            /// assert_eq!(20.m().checked_div(2), Some(10.m()));
+            /// assert_eq!(20.m().checked_div(0), None);
+            /// ```
+            impl<U> CheckedDiv<$S> for Quantity<$S, U> {
+                #[inline]
+                fn checked_div(self, rhs: $S) -> Option<Self::Output> {
+                    self.storage.checked_div(rhs).map(Self::new)
+                }
+            }
+        )+
+    };
+}
+
+checked_scalar_impls!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
 impl<S, U> AddAssign for Quantity<S, U>
 where
     S: AddAssign,
@@ -629,21 +2157,79 @@ where
 
 impl<S, U> MulAssign<S> for Quantity<S, U>
 where
-    S: MulAssign,
+    S: MulAssign,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: S) {
+        self.storage.mul_assign(rhs);
+    }
+}
+
+impl<S, U> DivAssign<S> for Quantity<S, U>
+where
+    S: DivAssign,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: S) {
+        self.storage.div_assign(rhs);
+    }
+}
+
+/// Multiplication assignment by a dimensionless quantity (e.g. gain).
+///
+/// Ratio of the dimensionless unit is applied, so multiplying by `50` of a
+/// unit with ratio `1/100` (percent) halves the quantity.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{prefixes::Centi, units::Dimensionless, IntExt, Quantity};
+///
+/// let mut q = 10.m();
+/// q *= 3.dimensionless();
+/// assert_eq!(q, 30.m());
+///
+/// q *= Quantity::<_, Centi<Dimensionless>>::new(50);
+/// assert_eq!(q, 15.m());
+/// ```
+impl<S, U, R> MulAssign<Quantity<S, Unit<Dimensions<Z0, Z0, Z0, Z0, Z0, Z0, Z0>, R>>>
+    for Quantity<S, U>
+where
+    R: FractionTrait,
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S> + Copy,
 {
     #[inline]
-    fn mul_assign(&mut self, rhs: S) {
-        self.storage.mul_assign(rhs);
+    fn mul_assign(&mut self, rhs: Quantity<S, Unit<Dimensions<Z0, Z0, Z0, Z0, Z0, Z0, Z0>, R>>) {
+        self.storage = R::mul(self.storage * rhs.storage);
     }
 }
 
-impl<S, U> DivAssign<S> for Quantity<S, U>
+/// Division assignment by a dimensionless quantity.
+///
+/// Ratio of the dimensionless unit is applied, so dividing by `50` of a unit
+/// with ratio `1/100` (percent) doubles the quantity.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{prefixes::Centi, units::Dimensionless, IntExt, Quantity};
+///
+/// let mut q = 30.m();
+/// q /= 3.dimensionless();
+/// assert_eq!(q, 10.m());
+///
+/// q /= Quantity::<_, Centi<Dimensionless>>::new(50);
+/// assert_eq!(q, 20.m());
+/// ```
+impl<S, U, R> DivAssign<Quantity<S, Unit<Dimensions<Z0, Z0, Z0, Z0, Z0, Z0, Z0>, R>>>
+    for Quantity<S, U>
 where
-    S: DivAssign,
+    R: FractionTrait,
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S> + Copy,
 {
     #[inline]
-    fn div_assign(&mut self, rhs: S) {
-        self.storage.div_assign(rhs);
+    fn div_assign(&mut self, rhs: Quantity<S, Unit<Dimensions<Z0, Z0, Z0, Z0, Z0, Z0, Z0>, R>>) {
+        self.storage = R::div(self.storage) / rhs.storage;
     }
 }
 
@@ -828,6 +2414,20 @@ where
     }
 }
 
+/// Hashes only the storage, consistently with `==` which compares quantities
+/// of the same unit (so `1 km` and `1000 m` can't be compared and their
+/// hashes don't need to agree). See [`Quantity::hash_canonical`] for hashing
+/// across prefixes.
+impl<S, U> Hash for Quantity<S, U>
+where
+    S: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.storage.hash(state)
+    }
+}
+
 impl<S, U> Ord for Quantity<S, U>
 where
     S: Ord,
@@ -848,6 +2448,50 @@ where
     }
 }
 
+macro_rules! dimensionless_cmp_impls {
+    ($( $S:ty ),+ $(,)?) => {
+        $(
+            /// Comparison between dimensionless quantity and a bare number.
+            ///
+            /// ## Examples
+            /// ```
+            /// use typed_phy::IntExt;
+            /// let efficiency = 45.0.m() / 50.0.m();
+            /// assert!(efficiency == 0.9 && efficiency > 0.5);
+            /// ```
+            impl PartialEq<$S> for Quantity<$S, Dimensionless> {
+                #[inline]
+                fn eq(&self, other: &$S) -> bool {
+                    self.storage.eq(other)
+                }
+            }
+
+            impl PartialEq<Quantity<$S, Dimensionless>> for $S {
+                #[inline]
+                fn eq(&self, other: &Quantity<$S, Dimensionless>) -> bool {
+                    self.eq(&other.storage)
+                }
+            }
+
+            impl PartialOrd<$S> for Quantity<$S, Dimensionless> {
+                #[inline]
+                fn partial_cmp(&self, other: &$S) -> Option<Ordering> {
+                    self.storage.partial_cmp(other)
+                }
+            }
+
+            impl PartialOrd<Quantity<$S, Dimensionless>> for $S {
+                #[inline]
+                fn partial_cmp(&self, other: &Quantity<$S, Dimensionless>) -> Option<Ordering> {
+                    self.partial_cmp(&other.storage)
+                }
+            }
+        )+
+    };
+}
+
+dimensionless_cmp_impls!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
 // TODO: `From` impl to change ratio
 impl<S, U> From<S> for Quantity<S, U> {
     #[inline]
@@ -856,16 +2500,186 @@ impl<S, U> From<S> for Quantity<S, U> {
     }
 }
 
+impl<S, U> Quantity<S, U> {
+    /// Converts unsigned storage to a wider signed one (e.g. `u32` to `i64`)
+    /// keeping the unit, so that e.g. subtraction can't underflow.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::IntExt;
+    ///
+    /// let (a, b) = (3u32.m(), 5u32.m());
+    /// // `a - b` would panic in debug
+    /// assert_eq!(a.signed() - b.signed(), (-2i64).m());
+    /// ```
+    #[inline]
+    pub fn signed(self) -> Quantity<S::Signed, U>
+    where
+        S: ToSigned,
+    {
+        Quantity::new(self.storage.to_signed())
+    }
+
+    /// Converts the storage to `T` keeping the unit, returns `None` if the
+    /// value doesn't fit into `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{prefixes::Micro, units::Second, Quantity};
+    ///
+    /// type Us<S> = Quantity<S, Micro<Second>>;
+    ///
+    /// assert_eq!(Us::new(1_000i64).checked_cast::<u32>(), Some(Us::new(1_000u32)));
+    /// assert_eq!(Us::new(-1i64).checked_cast::<u32>(), None);
+    /// assert_eq!(Us::new(1i64 << 40).checked_cast::<u32>(), None);
+    /// ```
+    #[inline]
+    pub fn checked_cast<T>(self) -> Option<Quantity<T, U>>
+    where
+        T: TryFrom<S>,
+    {
+        T::try_from(self.storage).ok().map(Quantity::new)
+    }
+
+    /// Converts the storage to `T` keeping the unit, clamping the value to the
+    /// range of `T`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{prefixes::Micro, units::Second, Quantity};
+    ///
+    /// type Us<S> = Quantity<S, Micro<Second>>;
+    ///
+    /// assert_eq!(Us::new(1_000i64).saturating_cast::<u32>(), Us::new(1_000u32));
+    /// assert_eq!(Us::new(-1i64).saturating_cast::<u32>(), Us::new(0));
+    /// assert_eq!(Us::new(1i64 << 40).saturating_cast::<u32>(), Us::new(u32::MAX));
+    /// ```
+    #[inline]
+    pub fn saturating_cast<T>(self) -> Quantity<T, U>
+    where
+        T: SaturatingFrom<S>,
+    {
+        Quantity::new(T::saturating_from(self.storage))
+    }
+}
+
+// Note: it's impossible to write
+// `impl<S: Into<T>, T, U> From<Quantity<S, U>> for Quantity<T, U>` because it
+// overlaps with `impl<T> From<T> for T` (when `S = T`), so instead the impls
+// are written for primitives, mirroring std's impls.
+macro_rules! storage_conversions {
+    (
+        from { $( $From:ty => $( $Into:ty ),+ ; )+ }
+        try_from { $( $TryFrom:ty => $( $TryInto:ty ),+ ; )+ }
+    ) => {
+        $(
+            $(
+                /// Lossless conversion of the storage, the unit is preserved.
+                impl<U> From<Quantity<$From, U>> for Quantity<$Into, U> {
+                    #[inline]
+                    fn from(quantity: Quantity<$From, U>) -> Self {
+                        Self::new(<$Into>::from(quantity.storage))
+                    }
+                }
+            )+
+        )+
+
+        $(
+            $(
+                /// Fallible conversion of the storage, the unit is preserved.
+                impl<U> TryFrom<Quantity<$TryFrom, U>> for Quantity<$TryInto, U> {
+                    type Error = TryFromIntError;
+
+                    #[inline]
+                    fn try_from(quantity: Quantity<$TryFrom, U>) -> Result<Self, Self::Error> {
+                        <$TryInto>::try_from(quantity.storage).map(Self::new)
+                    }
+                }
+            )+
+        )+
+    };
+}
+
+storage_conversions! {
+    from {
+        u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize, f32, f64;
+        u16 => u32, u64, u128, usize, i32, i64, i128, f32, f64;
+        u32 => u64, u128, i64, i128, f64;
+        u64 => u128, i128;
+        i8 => i16, i32, i64, i128, isize, f32, f64;
+        i16 => i32, i64, i128, isize, f32, f64;
+        i32 => i64, i128, f64;
+        i64 => i128;
+        f32 => f64;
+    }
+    try_from {
+        u8 => i8;
+        u16 => u8, i8, i16, isize;
+        u32 => u8, u16, usize, i8, i16, i32, isize;
+        u64 => u8, u16, u32, usize, i8, i16, i32, i64, isize;
+        u128 => u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize;
+        usize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize;
+        i8 => u8, u16, u32, u64, u128, usize;
+        i16 => u8, u16, u32, u64, u128, usize, i8;
+        i32 => u8, u16, u32, u64, u128, usize, i8, i16, isize;
+        i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, isize;
+        i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize;
+        isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128;
+    }
+}
+
+/// Sum of quantities.
+///
+/// The sum of an empty iterator is zero. Note: custom storages need to
+/// implement [`Zero`] to be summed (previously [`Default`] was used).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{units::Metre, IntExt, Quantity};
+///
+/// assert_eq!((1..=4).map(<_>::m).sum::<Quantity<_, _>>(), 10.m());
+/// assert_eq!(core::iter::empty::<Quantity<u8, Metre>>().sum::<Quantity<_, _>>(), 0.m());
+/// ```
 impl<S, U> Sum for Quantity<S, U>
 where
-    Self: Add<Output = Self> + Default,
+    S: Add<Output = S> + Zero,
 {
     #[inline]
-    fn sum<I>(iter: I) -> Self
+    fn sum<I>(mut iter: I) -> Self
     where
         I: Iterator<Item = Self>,
     {
-        iter.fold(Self::default(), Self::add)
+        match iter.next() {
+            Some(first) => iter.fold(first, |acc, q| Self::new(acc.storage + q.storage)),
+            None => Self::new(S::zero()),
+        }
+    }
+}
+
+/// Sum of quantities behind references.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{IntExt, Quantity};
+///
+/// let distances = [1.km(), 2.km(), 3.km()];
+/// assert_eq!(distances.iter().sum::<Quantity<_, _>>(), 6.km());
+/// ```
+impl<'a, S, U> Sum<&'a Quantity<S, U>> for Quantity<S, U>
+where
+    S: Add<Output = S> + Zero + Copy,
+{
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.copied().sum()
     }
 }
 
@@ -907,7 +2721,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use typenum::{N1, N2, P1, U15, U71};
+    use typenum::{N1, N2, P1, U1, U15, U2, U3, U71};
 
     use crate::{prefixes::*, units::*, Dimensions, IntExt, Quantity, Unit};
 
@@ -947,14 +2761,563 @@ mod tests {
 
     #[test]
     fn iter_traits() {
-        #[cfg(nightly)]
-        let iter = 1.s()..=10.s();
-        #[cfg(not(nightly))]
+        // TODO: use `1.s()..=10.s()` when `impl Step for Quantity` is back
         let iter = (1..=10).map(<_>::s);
 
         // Sum of first n elements of arithmetic progression is equal to `n(a1 + an)/2`
         // `10 * (1 + 10) / 2 == 55`
         assert_eq!(iter.sum::<Quantity<_, _>>(), 55.s());
+
+        let empty: [Quantity<f32, Second>; 0] = [];
+        assert_eq!(empty.iter().sum::<Quantity<_, _>>(), 0.0.s());
+
+        let v = [1.5.m(), 2.5.m()];
+        assert_eq!(v.iter().sum::<Quantity<_, _>>(), 4.0.m());
+        assert_eq!(v.iter().copied().sum::<Quantity<_, _>>(), 4.0.m());
+    }
+
+    #[test]
+    fn battery_units() {
+        assert_eq!(2.kwh().into_unit::<WattHour>(), 2000.wh());
+        assert_eq!(
+            3i64.wh().into_unit::<Joule>(),
+            10_800i64.quantity::<Joule>()
+        );
+        assert_eq!(1.kwh().into_base(), 3_600_000.quantity::<Joule>());
+        assert_eq!(2500.mah().into_unit::<AmpereHour>(), 2.ah());
+        assert_eq!(1.ah().into_unit::<MilliAmpereHour>(), 1000.mah());
+        assert_eq!(5.mah().into_base(), 18.quantity::<Unit![Ampere * Second]>());
+        assert_eq!((10.wh() / 2.h()).into_unit::<Watt>(), 5.quantity::<Watt>());
+    }
+
+    #[test]
+    fn rpm() {
+        assert_eq!(3000.rpm().into_unit::<Hertz>(), 50.quantity::<Hertz>());
+        assert_eq!(
+            1.quantity::<Kilo<Hertz>>()
+                .into_unit::<RevolutionsPerMinute>(),
+            60_000.rpm()
+        );
+
+        // 3000 rpm = 50 rev/s = 100π rad/s
+        let omega = 3000.0f32.rpm().to_angular_velocity();
+        assert!((omega.into_inner() - 314.159_27).abs() < 1e-3);
+        let omega = 1.0f64.rpm().to_angular_velocity();
+        assert!((omega.into_inner() - 0.104_719_755_119_659_77).abs() < 1e-15);
+    }
+
+    #[test]
+    fn speed() {
+        assert_eq!(900.kn().into_unit::<MetrePerSecond>(), 463.mps());
+        assert_eq!(
+            9000i64.kn().into_unit::<KiloMetrePerHour>(),
+            16_668i64.kmph()
+        );
+        assert_eq!(3125.mph().into_unit::<MetrePerSecond>(), 1397.mps());
+        assert_eq!(
+            1_000_000i64.mph().into_unit::<KiloMetrePerHour>(),
+            1_609_344i64.kmph()
+        );
+
+        let kn = 10.0f64.mps().into_unit::<Knot>();
+        assert!((kn.into_inner() - 19.438_445).abs() < 1e-6);
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(5.quantity::<NauticalMile>().into_base(), 9260.m());
+        assert_eq!(10.kn() * 2.h(), 20.quantity::<NauticalMile>().into_unit());
+        assert_eq!(
+            1i64.km().into_unit::<Angstrom>(),
+            10_000_000_000_000i64.quantity::<Angstrom>()
+        );
+        assert_eq!(
+            1i64.quantity::<LightYear>().into_base(),
+            9_460_730_472_580_800i64.m()
+        );
+        assert_eq!(
+            1u64.quantity::<Parsec>().into_base(),
+            30_856_775_814_913_673u64.m()
+        );
+
+        let ly = 1.0f64.quantity::<Parsec>().into_unit::<LightYear>();
+        assert!((ly.into_inner() - 3.261_563_777).abs() < 1e-9);
+
+        // Ratio of `Mpc` doesn't fit into `u64`
+        let mpc = 1.0f64.quantity::<Mega<Parsec>>().into_base();
+        assert!((mpc.into_inner() / 3.085_677_581_491_367e22 - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn mass() {
+        assert_eq!(3.t().into_base(), 3000.kg());
+        assert_eq!(5000.ct().into_unit::<Gram>(), 1000.quantity::<Gram>());
+        assert_eq!(
+            10_000.ct().into_unit::<Milli<Gram>>(),
+            2_000_000.quantity::<Milli<Gram>>()
+        );
+
+        // Carbon-12 atom is exactly 12 Da
+        let c12 = 12.0f64.dalton().into_base();
+        assert!((c12.into_inner() / 1.992_646_879_92e-26 - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn concentration() {
+        let c: Quantity<_, MolePerLitre> = 6.quantity::<Mole>() / 2.quantity::<Litre>();
+        assert_eq!(
+            c.into_unit::<MolePerCubicMetre>(),
+            3000.quantity::<MolePerCubicMetre>()
+        );
+
+        let b: Quantity<_, MolePerKiloGram> = 6.quantity::<Mole>() / 2.kg();
+        assert_eq!(b, 3.quantity::<MolePerKiloGram>());
+    }
+
+    #[test]
+    fn photometry() {
+        // 2 lm for 3 s
+        let energy: Quantity<_, LumenSecond> = 2.quantity::<Candela>() * 3.s();
+        assert_eq!(energy, 6.quantity::<LumenSecond>());
+
+        let exposure: Quantity<_, LuxSecond> = energy / 2.sqm();
+        assert_eq!(exposure, 3.quantity::<LuxSecond>());
+
+        let luminance: Quantity<_, CandelaPerSquareMetre> = exposure / 3.s();
+        assert_eq!(luminance, 1.quantity::<CandelaPerSquareMetre>());
+    }
+
+    #[test]
+    fn radiometry() {
+        let intensity: Quantity<_, WattPerSteradian> =
+            8.quantity::<Watt>() / 2.quantity::<Steradian>();
+        assert_eq!(intensity, 4.quantity::<Watt>());
+
+        let flux: Quantity<_, WattPerSquareMetrePerHertz> =
+            6.quantity::<Watt>() / 1.sqm() / 3.quantity::<Hertz>();
+        assert_eq!(flux, 2.quantity::<WattPerSquareMetrePerHertz>());
+
+        let jy = 1.0f64.quantity::<Milli<Jansky>>().into_base();
+        assert!((jy.into_inner() / 1e-29 - 1.0).abs() < 1e-12);
+        let ujy = 1.0f64.quantity::<Jansky>().into_unit::<Micro<Jansky>>();
+        assert!((ujy.into_inner() - 1e6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn dose_rate() {
+        let dose = (2.0f64.usvph() * 3.0.h()).into_unit::<Micro<Sievert>>();
+        assert!((dose.into_inner() - 6.0).abs() < 1e-9);
+        let msvph = 1500.0f64.usvph().into_unit::<Milli<SievertPerHour>>();
+        assert!((msvph.into_inner() - 1.5).abs() < 1e-9);
+
+        let rate: Quantity<_, GrayPerSecond> = 10.quantity::<Gray>() / 5.s();
+        assert_eq!(rate, 2.quantity::<GrayPerSecond>());
+    }
+
+    #[test]
+    fn electric_field() {
+        let e: Quantity<_, VoltPerMetre> = 12.v() / 3.m();
+        assert_eq!(e, 4.quantity::<VoltPerMetre>());
+        // `V/m` is the same as `N/C`
+        assert_eq!(e * 2.quantity::<Coulomb>(), 8.quantity::<Newton>());
+
+        let sigma: Quantity<_, CoulombPerSquareMetre> = 6.quantity::<Coulomb>() / 2.sqm();
+        let rho: Quantity<_, CoulombPerCubicMetre> = sigma / 3.m();
+        assert_eq!(rho, 1.quantity::<CoulombPerCubicMetre>());
+
+        // `ε = σ / E`
+        let epsilon: Quantity<_, FaradPerMetre> = (sigma * 4) / e;
+        assert_eq!(epsilon, 3.quantity::<FaradPerMetre>());
+        assert_eq!(8.quantity::<Farad>() / 2.m(), 4.quantity::<FaradPerMetre>());
+    }
+
+    #[test]
+    fn flow() {
+        assert_eq!(
+            120_000.lpm().into_unit::<CubicMetrePerSecond>(),
+            2.quantity::<CubicMetrePerSecond>()
+        );
+        assert_eq!(
+            3.quantity::<CubicMetrePerSecond>()
+                .into_unit::<LitrePerMinute>(),
+            180_000.lpm()
+        );
+        assert_eq!(
+            (3000.lpm() * 2.min_()).into_unit::<Litre>(),
+            6000.quantity::<Litre>()
+        );
+
+        let viscosity: Quantity<_, PascalSecond> = 10.quantity::<Pascal>() * 2.s();
+        assert_eq!(viscosity, 20.quantity::<PascalSecond>());
+        let mpas = 0.89f64.quantity::<Milli<PascalSecond>>().into_base();
+        assert!((mpas.into_inner() - 0.000_89).abs() < 1e-12);
+    }
+
+    #[test]
+    fn thermal() {
+        // `Q = m c ΔT`
+        let c = 4.quantity::<JoulePerKiloGramKelvin>();
+        let heat = 2.kg() * c * 10.quantity::<Kelvin>();
+        assert_eq!(heat, 80.quantity::<Joule>());
+
+        // `R = L / (k A)`
+        let k = 2.quantity::<WattPerMetreKelvin>();
+        let r: Quantity<_, KelvinPerWatt> = 8.m() / (k * 2.sqm());
+        assert_eq!(r, 2.quantity::<KelvinPerWatt>());
+
+        // `ΔT = P R`
+        assert_eq!(5.quantity::<Watt>() * r, 10.quantity::<Kelvin>());
+    }
+
+    #[test]
+    fn pressure() {
+        assert_eq!(
+            2i64.quantity::<Atmosphere>().into_base(),
+            202_650i64.quantity::<Pascal>()
+        );
+        assert_eq!(
+            760i64.quantity::<Torr>().into_unit::<Atmosphere>(),
+            1i64.quantity::<Atmosphere>()
+        );
+        assert_eq!(
+            3.quantity::<Bar>().into_unit::<Kilo<Pascal>>(),
+            300.quantity::<Kilo<Pascal>>()
+        );
+
+        let mmhg = 1.0f64.quantity::<MilliMetreOfMercury>().into_base();
+        assert!((mmhg.into_inner() - 133.322_387_415).abs() < 1e-9);
+
+        let psi = 1.0f64.quantity::<PoundPerSquareInch>().into_base();
+        assert!((psi.into_inner() - 6_894.757_293_168).abs() < 1e-6);
+
+        let atm = 1.0f64.quantity::<Atmosphere>();
+        assert!((atm.into_unit::<PoundPerSquareInch>().into_inner() - 14.695_949).abs() < 1e-6);
+    }
+
+    #[test]
+    fn dimensionless_cmp() {
+        let ratio = 3.m() / 4.m();
+        assert!(ratio == 0);
+        assert!(0 == ratio);
+
+        let efficiency = 45.0.m() / 50.0.m();
+        assert!(efficiency == 0.9);
+        assert!(efficiency > 0.5 && efficiency < 1.0);
+        assert!(0.5 < efficiency);
+        assert!(2u8.dimensionless() >= 2);
+    }
+
+    #[test]
+    fn raw_slices() {
+        assert_eq!(
+            core::mem::size_of::<Quantity<u16, Metre>>(),
+            core::mem::size_of::<u16>()
+        );
+
+        let mut raw = [1.5, -2.0];
+        let typed = Quantity::<f64, Second>::from_raw_slice_mut(&mut raw);
+        typed[1] = 4.0.s();
+        assert_eq!(Quantity::as_raw_slice(typed), &[1.5, 4.0]);
+        Quantity::as_raw_slice_mut(typed)[0] = 0.5;
+        assert_eq!(raw, [0.5, 4.0]);
+
+        let empty: &[Quantity<i32, Metre>] = Quantity::from_raw_slice(&[]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn take_replace() {
+        let mut energy = 5.0.m() * 2.0.kg();
+        assert_eq!(energy.replace(1.0.m() * 1.0.kg()), 10.0.m() * 1.0.kg());
+        assert_eq!(energy.take(), 1.0.m() * 1.0.kg());
+        assert_eq!(energy, 0.0.m() * 0.0.kg());
+
+        // Non-`Copy` storage
+        let mut samples = Quantity::<Vec<u8>, Second>::new(vec![1, 2, 3]);
+        assert_eq!(samples.take().into_inner(), [1, 2, 3]);
+        assert!(samples.into_inner().is_empty());
+    }
+
+    #[test]
+    fn mixed_storages() {
+        use core::ops::{Add, Div, Mul, Sub};
+
+        #[derive(Debug, PartialEq)]
+        struct Wide(i64);
+
+        impl Add<i32> for Wide {
+            type Output = Wide;
+
+            fn add(self, rhs: i32) -> Wide {
+                Wide(self.0 + i64::from(rhs))
+            }
+        }
+
+        impl Sub<i32> for Wide {
+            type Output = Wide;
+
+            fn sub(self, rhs: i32) -> Wide {
+                Wide(self.0 - i64::from(rhs))
+            }
+        }
+
+        impl Mul<i32> for Wide {
+            type Output = Wide;
+
+            fn mul(self, rhs: i32) -> Wide {
+                Wide(self.0 * i64::from(rhs))
+            }
+        }
+
+        impl Div<i32> for Wide {
+            type Output = Wide;
+
+            fn div(self, rhs: i32) -> Wide {
+                Wide(self.0 / i64::from(rhs))
+            }
+        }
+
+        // `strict` routes operators through the checked traits
+        #[cfg(feature = "strict")]
+        impl crate::checked::CheckedAdd<i32> for Wide {
+            fn checked_add(self, rhs: i32) -> Option<Wide> {
+                self.0.checked_add(i64::from(rhs)).map(Wide)
+            }
+        }
+
+        #[cfg(feature = "strict")]
+        impl crate::checked::CheckedSub<i32> for Wide {
+            fn checked_sub(self, rhs: i32) -> Option<Wide> {
+                self.0.checked_sub(i64::from(rhs)).map(Wide)
+            }
+        }
+
+        #[cfg(feature = "strict")]
+        impl crate::checked::CheckedMul<i32> for Wide {
+            fn checked_mul(self, rhs: i32) -> Option<Wide> {
+                self.0.checked_mul(i64::from(rhs)).map(Wide)
+            }
+        }
+
+        #[cfg(feature = "strict")]
+        impl crate::checked::CheckedDiv<i32> for Wide {
+            fn checked_div(self, rhs: i32) -> Option<Wide> {
+                self.0.checked_div(i64::from(rhs)).map(Wide)
+            }
+        }
+
+        let acc = Quantity::<_, Metre>::new(Wide(i64::from(i32::MAX)));
+        let acc = acc + 1.m() + 1.m();
+        assert_eq!(acc, Quantity::new(Wide(i64::from(i32::MAX) + 2)));
+        assert_eq!(acc - 2.m(), Quantity::new(Wide(i64::from(i32::MAX))));
+
+        let area: Quantity<_, Unit![Metre ^ 2]> =
+            Quantity::<_, Metre>::new(Wide(i64::from(i32::MAX))) * 2.m();
+        assert_eq!(area.into_inner(), Wide(i64::from(i32::MAX) * 2));
+        let speed: Quantity<Wide, MetrePerSecond> = Quantity::<_, Metre>::new(Wide(10)) / 4.s();
+        assert_eq!(speed.into_inner(), Wide(2));
+
+        // References work too (the checked traits are implemented only for
+        // values)
+        #[cfg(not(feature = "strict"))]
+        assert_eq!(1.m() + Quantity::<_, Metre>::new(&2), 3.m());
+    }
+
+    #[test]
+    fn scale() {
+        assert_eq!(10.m().scale::<Frac![U3 / U2]>(), 15.m());
+        assert_eq!(1.km().scale::<Frac![U1 / U3]>(), 0.km());
+        assert_eq!(9.0.s().scale::<Frac![U2 / U3]>(), 6.0.s());
+        assert_eq!(5u8.kg().scale::<Frac![U2 / U1]>(), 10u8.kg());
+    }
+
+    #[test]
+    fn recip() {
+        assert_eq!(
+            2.0.quantity::<Ohm>().recip(),
+            0.5.quantity::<Unit![Dimensionless / Ohm]>()
+        );
+        assert_eq!(
+            4u32.quantity::<LitrePerHundredKiloMetre>()
+                .recip_into::<KiloMetrePerLitre>(),
+            25u32.quantity::<KiloMetrePerLitre>()
+        );
+        assert_eq!(
+            20u32
+                .quantity::<KiloMetrePerLitre>()
+                .recip_into::<LitrePerHundredKiloMetre>(),
+            5u32.quantity::<LitrePerHundredKiloMetre>()
+        );
+        // Resistivity to conductivity
+        assert_eq!(
+            1i64.quantity::<Unit![Milli<Ohm> * Metre]>()
+                .recip_into::<Unit![Dimensionless / Ohm / Metre]>(),
+            1000i64.quantity::<Unit![Dimensionless / Ohm / Metre]>()
+        );
+    }
+
+    #[test]
+    fn period_frequency() {
+        let period = 1.quantity::<Kilo<Hertz>>().period();
+        assert_eq!(period, 1.quantity::<Milli<Second>>());
+
+        let freq = 1i64.quantity::<Micro<Second>>().frequency();
+        assert_eq!(freq, 1i64.quantity::<Mega<Hertz>>());
+
+        assert_eq!(
+            1.h().checked_frequency(),
+            Some(1.quantity::<Unit![Dimensionless / Hour]>())
+        );
+        assert_eq!(0u8.s().checked_frequency(), None);
+    }
+
+    #[test]
+    fn scalar_lhs() {
+        assert_eq!(2 * 5.m(), 10.m());
+        assert_eq!(2.5 * 2.0.s(), 5.0.s());
+        assert_eq!(10u8 / 5u8.s(), 2u8.quantity::<Hertz>());
+        assert_eq!(1.0 / 4.0.mps(), 0.25.quantity::<Unit![Second / Metre]>());
+    }
+
+    #[test]
+    fn hash() {
+        use std::{
+            collections::hash_map::DefaultHasher,
+            hash::{Hash, Hasher},
+        };
+
+        fn hash(f: impl FnOnce(&mut DefaultHasher)) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            f(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash(|h| 5.m().hash(h)), hash(|h| 5.hash(h)));
+        assert_eq!(
+            hash(|h| 2.km().hash_canonical(h)),
+            hash(|h| 2000.m().hash_canonical(h))
+        );
+        assert_ne!(
+            hash(|h| 2.km().hash_canonical(h)),
+            hash(|h| 2.m().hash_canonical(h))
+        );
+    }
+
+    #[test]
+    fn duration_accessors() {
+        let t = Quantity::<i64, Micro<Second>>::new(-2_500_001);
+        assert_eq!(t.as_secs(), (-2).s());
+        assert_eq!(t.as_millis(), Quantity::new(-2_500));
+        assert_eq!(t.as_nanos(), Quantity::new(-2_500_001_000));
+        assert_eq!(t.subsec_micros(), Quantity::new(-500_001));
+        assert_eq!(t.as_secs_f64(), -2.500_001);
+
+        assert_eq!(90i64.min_().subsec_millis(), Quantity::new(0));
+        assert_eq!(Quantity::<u8, Milli<Second>>::new(250).as_secs_f32(), 0.25);
+
+        // The ratio doesn't fit into `u64`
+        let long = Quantity::<f64, crate::prefixes::Yotta<Second>>::new(3.0);
+        assert_eq!(long.as_secs_f64(), 3e24);
+    }
+
+    #[test]
+    fn assign_dimensionless() {
+        let mut q = 100.m();
+        q *= 2.dimensionless();
+        assert_eq!(q, 200.m());
+        q /= 4.dimensionless();
+        assert_eq!(q, 50.m());
+
+        let mut q = 2.0.s();
+        q *= Quantity::<_, Milli<Dimensionless>>::new(500.0);
+        assert_eq!(q, 1.0.s());
+        q /= Quantity::<_, Kilo<Dimensionless>>::new(0.5);
+        assert_eq!(q, 0.002.s());
+
+        // Plain storages still work
+        q *= 1000.0;
+        assert_eq!(q, 2.0.s());
+    }
+
+    #[test]
+    fn pow() {
+        use typenum::{Pow, N2, P2, P3};
+
+        assert_eq!(
+            <Quantity<_, Metre> as Pow<P3>>::powi(2.m(), P3::new()),
+            8.quantity::<CubicMetre>()
+        );
+        assert_eq!(
+            <Quantity<_, Centi<Metre>> as Pow<P2>>::powi(Quantity::new(3), P2::new()),
+            9.quantity::<Unit![Centi<Metre> ^ 2]>()
+        );
+        assert_eq!(
+            <Quantity<_, Second> as Pow<N2>>::powi(0.5.s(), N2::new()),
+            4.0.quantity::<Unit![Dimensionless / Second ^ 2]>()
+        );
+
+        // Method-call syntax uses the inherent method
+        assert_eq!(2.m().powi::<P3>(), 8.quantity::<CubicMetre>());
+        assert_eq!(
+            0.5.s().powi::<N2>(),
+            4.0.quantity::<Unit![Dimensionless / Second ^ 2]>()
+        );
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(Quantity::<i8, Second>::zero(), 0.s());
+        assert_eq!(Quantity::<u64, Kilo<Metre>>::ZERO, 0.km());
+        assert_eq!(Quantity::<f64, Metre>::ZERO, 0.0.m());
+
+        assert!(Quantity::<f32, Hour>::ZERO.is_zero());
+        assert!((5.m() - 5.m()).is_zero());
+        assert!(!(-1).s().is_zero());
+    }
+
+    #[test]
+    fn grid() {
+        assert_eq!((-12).m().round_to(5.m()), (-10).m());
+        assert_eq!((-13).m().round_to(5.m()), (-15).m());
+        assert_eq!((-13).m().ceil_to(5.m()), (-10).m());
+        assert_eq!(12.5.m().round_to(5.0.m()), 15.0.m());
+        assert_eq!(0.7.s().floor_to(0.25.s()), 0.5.s());
+        assert_eq!((-0.7).s().ceil_to(0.25.s()), (-0.5).s());
+
+        assert_eq!(250u8.m().checked_ceil_to(0.m()), None);
+        assert_eq!(255u8.m().checked_round_to(10.m()), None);
+        assert_eq!(254u8.m().checked_round_to(10.m()), Some(250.m()));
+        assert_eq!(244u8.m().checked_round_to(10.m()), Some(240.m()));
+    }
+
+    #[test]
+    fn grid_bounds() {
+        assert_eq!(i8::MIN.m().ceil_to(3.m()), (-126).m());
+        assert_eq!(i8::MIN.m().checked_ceil_to(3.m()), Some((-126).m()));
+        assert_eq!(i8::MIN.m().checked_floor_to(3.m()), None);
+        assert_eq!(i8::MIN.m().checked_round_to(3.m()), None);
+        assert_eq!(i8::MIN.m().checked_round_to(4.m()), Some(i8::MIN.m()));
+
+        assert_eq!(i8::MAX.m().checked_ceil_to(3.m()), None);
+        assert_eq!(i8::MAX.m().checked_floor_to(3.m()), Some(126.m()));
+        assert_eq!(i8::MAX.m().checked_round_to(3.m()), Some(126.m()));
+        assert_eq!(i8::MAX.m().checked_round_to(2.m()), None);
+        assert_eq!(u8::MAX.m().checked_ceil_to(u8::MAX.m()), Some(u8::MAX.m()));
+    }
+
+    #[test]
+    fn storage_conversions() {
+        use core::convert::TryFrom;
+
+        let wide: Quantity<i64, Metre> = 10i32.m().into();
+        assert_eq!(wide, 10i64.m());
+
+        let float: Quantity<f64, Second> = 1.5f32.s().into();
+        assert_eq!(float, 1.5f64.s());
+
+        assert_eq!(Quantity::<u8, Metre>::try_from(200i32.m()), Ok(200u8.m()));
+        assert!(Quantity::<u8, Metre>::try_from(300i32.m()).is_err());
+        assert!(Quantity::<u32, Metre>::try_from((-1i64).m()).is_err());
     }
 
     #[test]
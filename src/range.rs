@@ -0,0 +1,617 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use typenum::Prod;
+
+use crate::{
+    from_int::{AsPrimitive, Zero},
+    strict::{StorageAdd, StorageMul, StorageSub},
+    Quantity, UnitTrait,
+};
+
+/// Closed interval `lo..=hi` of quantities of the same unit.
+///
+/// Supports interval arithmetic, so it can be used for tolerance analysis.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{range::QuantityRange, IntExt};
+///
+/// // 10 ± 1 m
+/// let length = QuantityRange::around(10.m(), 1.m()).unwrap();
+/// // 5 ± 0.5 m
+/// let width = QuantityRange::around(5.0.m(), 0.5.m()).unwrap();
+///
+/// assert_eq!(length.lo(), 9.m());
+/// assert_eq!(length.hi(), 11.m());
+/// assert!(length.contains(10.m()));
+///
+/// let area = QuantityRange::new(4.5.m(), 5.5.m()).unwrap() * width;
+/// assert_eq!(area.lo(), 20.25.sqm());
+/// assert_eq!(area.hi(), 30.25.sqm());
+///
+/// let total = length + QuantityRange::new(1.m(), 2.m()).unwrap();
+/// assert_eq!(total, QuantityRange::new(10.m(), 13.m()).unwrap());
+/// ```
+pub struct QuantityRange<S, U> {
+    pub(crate) lo: Quantity<S, U>,
+    pub(crate) hi: Quantity<S, U>,
+}
+
+impl<S, U> QuantityRange<S, U>
+where
+    U: UnitTrait,
+{
+    /// Creates new range or returns `None` if `lo > hi` (or they aren't
+    /// comparable, e.g. `NaN`).
+    #[inline]
+    pub fn new(lo: Quantity<S, U>, hi: Quantity<S, U>) -> Option<Self>
+    where
+        S: PartialOrd,
+    {
+        if lo <= hi {
+            Some(Self { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    /// Creates range `center - tolerance..=center + tolerance` or returns
+    /// `None` if `tolerance` is negative (see [`new`](Self::new)).
+    #[inline]
+    pub fn around(center: Quantity<S, U>, tolerance: Quantity<S, U>) -> Option<Self>
+    where
+        S: StorageAdd<U, Output = S> + StorageSub<U, Output = S> + PartialOrd + Copy,
+    {
+        Self::new(center - tolerance, center + tolerance)
+    }
+
+    /// Returns lower bound of the range.
+    #[inline]
+    pub fn lo(&self) -> Quantity<S, U>
+    where
+        S: Copy,
+    {
+        self.lo
+    }
+
+    /// Returns upper bound of the range.
+    #[inline]
+    pub fn hi(&self) -> Quantity<S, U>
+    where
+        S: Copy,
+    {
+        self.hi
+    }
+
+    /// Returns `hi - lo`.
+    #[inline]
+    pub fn width(&self) -> Quantity<S, U>
+    where
+        S: StorageSub<U, Output = S> + Copy,
+    {
+        self.hi - self.lo
+    }
+
+    /// Returns `true` if `value` is in the range.
+    #[inline]
+    pub fn contains(&self, value: Quantity<S, U>) -> bool
+    where
+        S: PartialOrd,
+    {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Compares `value` with the range: `Less` if it's below the range,
+    /// `Greater` if it's above and `Equal` if it's in the range.
+    ///
+    /// Returns `None` if `value` isn't comparable with the bounds (e.g. `NaN`).
+    #[inline]
+    pub fn compare(&self, value: &Quantity<S, U>) -> Option<Ordering>
+    where
+        S: PartialOrd,
+    {
+        if value < &self.lo {
+            Some(Ordering::Less)
+        } else if value > &self.hi {
+            Some(Ordering::Greater)
+        } else if value >= &self.lo && value <= &self.hi {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `other` is fully contained in this range.
+    #[inline]
+    pub fn contains_range(&self, other: &Self) -> bool
+    where
+        S: PartialOrd,
+    {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+
+    /// Returns intersection of two ranges or `None` if they don't overlap.
+    #[inline]
+    pub fn intersection(self, other: Self) -> Option<Self>
+    where
+        S: PartialOrd,
+    {
+        Self::new(max(self.lo, other.lo), min(self.hi, other.hi))
+    }
+
+    /// Returns iterator over `lo, lo + step, lo + 2 * step, ...` up to `hi`
+    /// (inclusive).
+    ///
+    /// Returns `None` if the number of steps can't be computed (see
+    /// [`range_step`]).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `step` isn't positive.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{range::QuantityRange, IntExt};
+    ///
+    /// let range = QuantityRange::new(0.m(), 10.m()).unwrap();
+    /// let steps: Vec<_> = range.step_by(5.m()).unwrap().collect();
+    /// assert_eq!(steps, [0.m(), 5.m(), 10.m()]);
+    /// ```
+    #[inline]
+    pub fn step_by(self, step: Quantity<S, U>) -> Option<RangeStep<S, U>>
+    where
+        S: StepStorage,
+        usize: AsPrimitive<S>,
+    {
+        RangeStep::new(self.lo, self.hi, step, true)
+    }
+
+    /// Widens the range by `by` in both directions (negative `by` narrows it).
+    ///
+    /// Returns `None` if the range would become empty, i.e. `by` is negative
+    /// and its magnitude is more than half of the width (see
+    /// [`new`](Self::new)).
+    #[inline]
+    pub fn widen(self, by: Quantity<S, U>) -> Option<Self>
+    where
+        S: StorageAdd<U, Output = S> + StorageSub<U, Output = S> + PartialOrd + Copy,
+    {
+        Self::new(self.lo - by, self.hi + by)
+    }
+}
+
+#[inline]
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+#[inline]
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// `[a, b] + [c, d] = [a + c, b + d]`
+impl<S, U> Add for QuantityRange<S, U>
+where
+    S: StorageAdd<U, Output = S>,
+    U: UnitTrait,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            lo: self.lo + rhs.lo,
+            hi: self.hi + rhs.hi,
+        }
+    }
+}
+
+/// `[a, b] - [c, d] = [a - d, b - c]`
+impl<S, U> Sub for QuantityRange<S, U>
+where
+    S: StorageSub<U, Output = S>,
+    U: UnitTrait,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            lo: self.lo - rhs.hi,
+            hi: self.hi - rhs.lo,
+        }
+    }
+}
+
+/// `[a, b] * [c, d] = [min(ac, ad, bc, bd), max(ac, ad, bc, bd)]`, the unit
+/// changes like in `Quantity * Quantity`.
+impl<S, U0, U1> Mul<QuantityRange<S, U1>> for QuantityRange<S, U0>
+where
+    S: StorageMul<U0, U1, Output = S> + PartialOrd + Copy,
+    U0: UnitTrait + Mul<U1>,
+    U1: UnitTrait,
+    Prod<U0, U1>: UnitTrait,
+{
+    type Output = QuantityRange<S, Prod<U0, U1>>;
+
+    #[inline]
+    fn mul(self, rhs: QuantityRange<S, U1>) -> Self::Output {
+        let ac = self.lo * rhs.lo;
+        let ad = self.lo * rhs.hi;
+        let bc = self.hi * rhs.lo;
+        let bd = self.hi * rhs.hi;
+
+        QuantityRange {
+            lo: min(min(ac, ad), min(bc, bd)),
+            hi: max(max(ac, ad), max(bc, bd)),
+        }
+    }
+}
+
+impl<S: Clone, U> Clone for QuantityRange<S, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            lo: self.lo.clone(),
+            hi: self.hi.clone(),
+        }
+    }
+}
+
+impl<S: Copy, U> Copy for QuantityRange<S, U> {}
+
+impl<S: PartialEq, U> PartialEq for QuantityRange<S, U> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.lo == other.lo && self.hi == other.hi
+    }
+}
+
+impl<S, U> fmt::Debug for QuantityRange<S, U>
+where
+    S: fmt::Debug,
+    U: fmt::Debug + Default,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..={:?}", self.lo, self.hi)
+    }
+}
+
+impl<S, U> fmt::Display for QuantityRange<S, U>
+where
+    S: fmt::Display,
+    U: fmt::Display + Default,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.lo, self.hi)
+    }
+}
+
+/// Storage that can be used in [`RangeStep`].
+///
+/// This trait is implemented for primitive numbers.
+pub trait StepStorage:
+    Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + PartialOrd
+    + Zero
+    + AsPrimitive<usize>
+    + Copy
+{
+    /// Returns `self - rhs` or `None` if it overflows (or isn't finite for
+    /// floats).
+    fn checked_distance(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impls_step_storage {
+    (int: $( $I:ty ),+; float: $( $F:ty ),+ $(,)?) => {
+        $(
+            impl StepStorage for $I {
+                #[inline]
+                fn checked_distance(self, rhs: Self) -> Option<Self> {
+                    self.checked_sub(rhs)
+                }
+            }
+        )+
+        $(
+            impl StepStorage for $F {
+                #[inline]
+                fn checked_distance(self, rhs: Self) -> Option<Self> {
+                    Some(self - rhs).filter(|d| d.is_finite())
+                }
+            }
+        )+
+    };
+}
+
+impls_step_storage! {
+    int: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize;
+    float: f32, f64,
+}
+
+/// Returns iterator over `start, start + step, start + 2 * step, ...` up to
+/// `end` (exclusive), like `(start..end).step_by(step)` for integers.
+///
+/// Every value is computed as `start + step * n`, so floating point errors
+/// don't accumulate.
+///
+/// Returns `None` if the number of steps can't be computed, i.e. if `end -
+/// start` overflows (or isn't finite, e.g. `end` is infinity) or the number
+/// doesn't fit into `usize`.
+///
+/// ## Panics
+///
+/// Panics if `step` isn't positive.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{range::range_step, IntExt};
+///
+/// let ramp: Vec<_> = range_step(0.mv(), 3300.mv(), 50.mv()).unwrap().collect();
+/// assert_eq!(ramp.len(), 66);
+/// assert_eq!(ramp[1], 50.mv());
+/// assert_eq!(ramp.last(), Some(&3250.mv()));
+///
+/// let sweep: Vec<_> = range_step(0.0.s(), 1.0.s(), 0.1.s()).unwrap().collect();
+/// assert_eq!(sweep.len(), 10);
+///
+/// assert!(range_step(0.0.s(), f64::INFINITY.s(), 1.0.s()).is_none());
+/// ```
+#[inline]
+pub fn range_step<S, U>(
+    start: Quantity<S, U>,
+    end: Quantity<S, U>,
+    step: Quantity<S, U>,
+) -> Option<RangeStep<S, U>>
+where
+    S: StepStorage,
+    U: UnitTrait,
+    usize: AsPrimitive<S>,
+{
+    RangeStep::new(start, end, step, false)
+}
+
+/// Iterator returned by [`range_step`] and [`QuantityRange::step_by`].
+pub struct RangeStep<S, U> {
+    start: S,
+    step: S,
+    /// Index of the next value from the front
+    front: usize,
+    /// Index after the last value from the back
+    back: usize,
+    _unit: PhantomData<U>,
+}
+
+impl<S, U> RangeStep<S, U>
+where
+    S: StepStorage,
+    usize: AsPrimitive<S>,
+{
+    fn new(
+        start: Quantity<S, U>,
+        end: Quantity<S, U>,
+        step: Quantity<S, U>,
+        inclusive: bool,
+    ) -> Option<Self> {
+        let (start, end, step) = (start.into_inner(), end.into_inner(), step.into_inner());
+        assert!(step > S::zero(), "step must be positive");
+
+        let count = if start < end || (inclusive && start <= end) {
+            // `n` such that `start + step * n <= end < start + step * (n + 1)`
+            // (modulo rounding errors of floats)
+            let n: usize = (end.checked_distance(start)? / step).as_();
+            let last = start + step * n.as_();
+            if last < end || (inclusive && last <= end) {
+                n.checked_add(1)?
+            } else {
+                n
+            }
+        } else {
+            0
+        };
+
+        Some(Self {
+            start,
+            step,
+            front: 0,
+            back: count,
+            _unit: PhantomData,
+        })
+    }
+
+    fn get(&self, n: usize) -> Quantity<S, U> {
+        Quantity::new(self.start + self.step * n.as_())
+    }
+}
+
+impl<S, U> Iterator for RangeStep<S, U>
+where
+    S: StepStorage,
+    usize: AsPrimitive<S>,
+{
+    type Item = Quantity<S, U>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let value = self.get(self.front);
+        self.front += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<S, U> DoubleEndedIterator for RangeStep<S, U>
+where
+    S: StepStorage,
+    usize: AsPrimitive<S>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.get(self.back))
+    }
+}
+
+impl<S, U> ExactSizeIterator for RangeStep<S, U>
+where
+    S: StepStorage,
+    usize: AsPrimitive<S>,
+{
+}
+
+impl<S, U> FusedIterator for RangeStep<S, U>
+where
+    S: StepStorage,
+    usize: AsPrimitive<S>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use super::{range_step, QuantityRange};
+    use crate::{
+        units::{Metre, Second},
+        IntExt,
+    };
+
+    #[test]
+    fn new() {
+        assert!(QuantityRange::new(1.m(), 2.m()).is_some());
+        assert!(QuantityRange::new(1.m(), 1.m()).is_some());
+        assert!(QuantityRange::new(2.m(), 1.m()).is_none());
+        assert!(QuantityRange::new(f64::NAN.m(), 1.0.m()).is_none());
+    }
+
+    #[test]
+    fn set_ops() {
+        let a = QuantityRange::new(0.m(), 10.m()).unwrap();
+        let b = QuantityRange::new(5.m(), 20.m()).unwrap();
+        let c = QuantityRange::new(15.m(), 20.m()).unwrap();
+
+        assert_eq!(a.intersection(b), QuantityRange::new(5.m(), 10.m()));
+        assert_eq!(a.intersection(c), None);
+        assert!(b.contains_range(&c));
+        assert!(!a.contains_range(&b));
+        assert_eq!(a.width(), 10.m());
+        assert_eq!(a.widen(2.m()), QuantityRange::new((-2).m(), 12.m()));
+        assert_eq!(a.widen((-5).m()), QuantityRange::new(5.m(), 5.m()));
+        assert_eq!(a.widen((-6).m()), None);
+
+        assert_eq!(a.compare(&(-1).m()), Some(Ordering::Less));
+        assert_eq!(a.compare(&10.m()), Some(Ordering::Equal));
+        assert_eq!(a.compare(&11.m()), Some(Ordering::Greater));
+        assert_eq!(
+            QuantityRange::new(0.0.m(), 1.0.m())
+                .unwrap()
+                .compare(&f64::NAN.m()),
+            None
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = QuantityRange::new((-2).m(), 3.m()).unwrap();
+        let b = QuantityRange::new(4.s(), 5.s()).unwrap();
+
+        assert_eq!(a - a, QuantityRange::new((-5).m(), 5.m()).unwrap());
+
+        let p = a * b;
+        assert_eq!(p.lo(), (-10).quantity::<Unit![Metre * Second]>());
+        assert_eq!(p.hi(), 15.quantity::<Unit![Metre * Second]>());
+
+        let sq = a * a;
+        assert_eq!(sq.lo(), (-6).sqm());
+        assert_eq!(sq.hi(), 9.sqm());
+    }
+
+    #[test]
+    fn around() {
+        let r = QuantityRange::around(10.m(), 2.m()).unwrap();
+        assert_eq!((r.lo(), r.hi()), (8.m(), 12.m()));
+        assert_eq!(QuantityRange::around(10.m(), 0.m()).unwrap().width(), 0.m());
+        assert_eq!(QuantityRange::around(10.m(), (-2).m()), None);
+        assert_eq!(QuantityRange::around(1.0.m(), f64::NAN.m()), None);
+    }
+
+    #[test]
+    fn step() {
+        let it = range_step(0u8.m(), 255.m(), 100.m()).unwrap();
+        assert_eq!(it.len(), 3);
+        assert!(it.eq([0.m(), 100.m(), 200.m()].iter().copied()));
+
+        assert!(range_step(0.m(), 30.m(), 10.m())
+            .unwrap()
+            .rev()
+            .eq([20.m(), 10.m(), 0.m()].iter().copied()));
+        assert_eq!(range_step(5.m(), 5.m(), 1.m()).unwrap().count(), 0);
+        assert_eq!(range_step(6.m(), 5.m(), 1.m()).unwrap().count(), 0);
+
+        let closed = QuantityRange::new(0.m(), 30.m()).unwrap();
+        assert_eq!(closed.step_by(10.m()).unwrap().count(), 4);
+        assert_eq!(closed.step_by(7.m()).unwrap().next_back(), Some(28.m()));
+
+        let floats = QuantityRange::new(0.0.s(), 1.0.s()).unwrap();
+        assert_eq!(floats.step_by(0.1.s()).unwrap().count(), 11);
+        assert_eq!(floats.step_by(0.1.s()).unwrap().next_back(), Some(1.0.s()));
+    }
+
+    #[test]
+    fn step_overflow() {
+        // `127 - (-128)` doesn't fit into `i8`
+        let full = QuantityRange::new(i8::MIN.m(), i8::MAX.m()).unwrap();
+        assert!(full.step_by(1.m()).is_none());
+        assert_eq!(range_step((-100i8).m(), 27.m(), 1.m()).unwrap().len(), 127);
+
+        assert!(range_step(0.0.s(), f64::INFINITY.s(), 1.0.s()).is_none());
+        assert!(range_step(0.0.s(), 1.0.s(), f64::MIN_POSITIVE.s()).is_none());
+        assert_eq!(
+            range_step(0usize.m(), usize::MAX.m(), 1.m()).unwrap().len(),
+            usize::MAX
+        );
+        let all = QuantityRange::new(0usize.m(), usize::MAX.m()).unwrap();
+        assert!(all.step_by(1.m()).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn step_zero() {
+        let _ = range_step(0.m(), 30.m(), 0.m());
+    }
+}
@@ -0,0 +1,221 @@
+//! Exact dimensionless ratios (gearboxes, transformers, dividers).
+//!
+//! [`Ratio`] is a value-level handle to a type-level [`Fraction`], so it can be
+//! stored in structs and passed around like a normal value, while scaling
+//! stays exact: `value * N / D` is computed with integers, no floats involved.
+//!
+//! ## Examples
+//!
+//! ```
+//! use typed_phy::{ratio::Ratio, IntExt};
+//! use typenum::{U1, U3, U5, U12};
+//!
+//! // Two-stage gearbox: 12:1 and then 5:3
+//! let first = Ratio::<U1, U12>::new();
+//! let second = Ratio::<U3, U5>::new();
+//! let gearbox = first * second;
+//!
+//! assert_eq!(gearbox * 6000u32.rpm(), 300.rpm());
+//! assert_eq!(gearbox.inverse() * 300u32.rpm(), 6000.rpm());
+//!
+//! // Overflow is reported instead of wrapping
+//! assert_eq!(gearbox.inverse().checked_apply(200u8.rpm()), None);
+//! ```
+//!
+//! [`Fraction`]: crate::fraction::Fraction
+use core::{
+    fmt,
+    ops::{Div, Mul},
+};
+
+use typenum::{Prod, Unsigned};
+
+use crate::{
+    checked::{CheckedDiv, CheckedMul},
+    fraction::{Fraction, FractionTrait},
+    from_int::FromUnsigned,
+    simplify::{Simplified, Simplify},
+    Quantity,
+};
+
+/// Exact ratio `N / D` (type-level unsigned integers).
+///
+/// `Ratio * Quantity` scales the quantity without changing its unit,
+/// `Ratio * Ratio` composes ratios (e.g. stages of a drivetrain).
+pub struct Ratio<N, D>(phantasm::Invariant<(N, D)>);
+
+impl<N, D> Ratio<N, D> {
+    /// Creates the ratio.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(phantasm::Invariant)
+    }
+
+    /// Returns the inverse ratio `D / N` (e.g. the same gearbox driven from
+    /// the other side).
+    #[inline]
+    pub const fn inverse(self) -> Ratio<D, N> {
+        Ratio::new()
+    }
+}
+
+impl<N, D> Ratio<N, D>
+where
+    Fraction<N, D>: FractionTrait,
+{
+    /// Scales the quantity: `q * N / D`.
+    ///
+    /// The multiplication is done first, so integer storages only lose the
+    /// fractional part of the result (but may overflow, see
+    /// [`checked_apply`](Ratio::checked_apply)).
+    #[inline]
+    pub fn apply<S, U>(self, q: Quantity<S, U>) -> Quantity<S, U>
+    where
+        S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+    {
+        Quantity::new(<Fraction<N, D>>::mul(q.into_inner()))
+    }
+
+    /// Scales the quantity: `q * N / D`, returning `None` on overflow.
+    ///
+    /// Note: overflow of the intermediate `q * N` is an overflow too, even if
+    /// the final result would fit.
+    #[inline]
+    pub fn checked_apply<S, U>(self, q: Quantity<S, U>) -> Option<Quantity<S, U>>
+    where
+        S: FromUnsigned + CheckedMul<Output = S> + CheckedDiv<Output = S>,
+    {
+        let n = S::from_unsigned::<<Fraction<N, D> as FractionTrait>::Numerator>();
+        let d = S::from_unsigned::<<Fraction<N, D> as FractionTrait>::Divisor>();
+        q.into_inner()
+            .checked_mul(n)?
+            .checked_div(d)
+            .map(Quantity::new)
+    }
+
+    /// Returns the ratio as `f64`.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        <Fraction<N, D>>::to_f64()
+    }
+}
+
+/// `ratio * q`
+impl<N, D, S, U> Mul<Quantity<S, U>> for Ratio<N, D>
+where
+    Fraction<N, D>: FractionTrait,
+    S: FromUnsigned + Mul<Output = S> + Div<Output = S>,
+{
+    type Output = Quantity<S, U>;
+
+    #[inline]
+    fn mul(self, rhs: Quantity<S, U>) -> Self::Output {
+        self.apply(rhs)
+    }
+}
+
+/// Composition of ratios `n/d` and `a/b` (`(n * a)/(d * b)`, simplified)
+type Composed<N, D, A, B> = Simplified<Fraction<Prod<N, A>, Prod<D, B>>>;
+
+/// `(n/d) * (a/b) = (n * a)/(d * b)`
+///
+/// The result is simplified, so composed ratios don't overflow more than
+/// needed in [`checked_apply`](Ratio::checked_apply).
+impl<N, D, A, B> Mul<Ratio<A, B>> for Ratio<N, D>
+where
+    N: Mul<A>,
+    D: Mul<B>,
+    Fraction<Prod<N, A>, Prod<D, B>>: Simplify,
+    Composed<N, D, A, B>: FractionTrait,
+{
+    type Output = Ratio<
+        <Composed<N, D, A, B> as FractionTrait>::Numerator,
+        <Composed<N, D, A, B> as FractionTrait>::Divisor,
+    >;
+
+    #[inline]
+    fn mul(self, _rhs: Ratio<A, B>) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+// We need to use handwritten impls to prevent unnecessary bounds on generics
+impl<N, D> Clone for Ratio<N, D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N, D> Copy for Ratio<N, D> {}
+
+impl<N, D> Default for Ratio<N, D> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, D> PartialEq for Ratio<N, D> {
+    #[inline]
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<N, D> Eq for Ratio<N, D> {}
+
+impl<N, D> fmt::Debug for Ratio<N, D>
+where
+    N: Unsigned,
+    D: Unsigned,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ratio<{}>", <Fraction<N, D>>::new())
+    }
+}
+
+impl<N, D> fmt::Display for Ratio<N, D>
+where
+    N: Unsigned,
+    D: Unsigned,
+{
+    /// Formats the ratio like [`Fraction`] (so the alternate flag is
+    /// respected too).
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&<Fraction<N, D>>::new(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typenum::{U1, U10, U2, U230, U3, U4};
+
+    use super::Ratio;
+    use crate::IntExt;
+
+    #[test]
+    fn transformer() {
+        // 230 V to 10 V
+        let t = Ratio::<U1, U230>::new() * Ratio::<U10, U1>::new();
+        assert_eq!(t * 230.v(), 10.v());
+        assert_eq!(t.inverse() * 10.v(), 230.v());
+        assert_eq!(format!("{}", t), "1/23");
+    }
+
+    #[test]
+    fn checked() {
+        let r = Ratio::<U3, U2>::new();
+        assert_eq!(r.checked_apply(80u8.m()), Some(120u8.m()));
+        // `100 * 3` overflows even though `150` fits
+        assert_eq!(r.checked_apply(100u8.m()), None);
+        assert_eq!(r.apply(4.0.m()), 6.0.m());
+        assert_eq!(Ratio::<U3, U4>::new().to_f64(), 0.75);
+
+        // Composed ratio is simplified to `1/1`, so `100 * 6` doesn't overflow
+        let identity = Ratio::<U2, U3>::new() * Ratio::<U3, U2>::new();
+        assert_eq!(identity.checked_apply(100u8.m()), Some(100u8.m()));
+    }
+}
@@ -1,35 +1,782 @@
-//! Runtime representation of types (internal API, used for display impl(s))
+//! Runtime representation of types.
+//!
+//! Type-level units are great for catching errors at compile time, but
+//! sometimes units are only known at runtime (e.g. parsed from strings or used
+//! for error messages). This module provides plain values mirroring
+//! [`Fraction`], [`Dimensions`] and [`Unit`].
+//!
+//! [`Fraction`]: crate::fraction::Fraction
+//! [`Dimensions`]: crate::Dimensions
+//! [`Unit`]: struct@crate::Unit
+use core::fmt::{self, Display};
+
 use typenum::marker_traits::{Integer, Unsigned};
 
-use crate::{fraction::FractionTrait, DimensionsTrait, UnitTrait};
+use crate::{
+    fraction::FractionTrait,
+    unit::{try_get_prefixed_power, ALIAS_UNITS, COHERENT_UNITS, SIMPLE_UNITS},
+    DimensionsTrait, UnitTrait,
+};
+
+/// Runtime representation of [`Fraction`](crate::fraction::Fraction).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RtFraction {
+    /// Numerator of the fraction
+    pub numerator: u128,
+    /// Divisor of the fraction
+    pub divisor: u128,
+}
+
+impl RtFraction {
+    /// Returns `n` if the fraction is equal to `10^n`
+    pub(crate) fn pow10(&self) -> Option<i32> {
+        // `is_multiple_of` isn't available on older compilers
+        #[allow(clippy::manual_is_multiple_of)]
+        fn log10(mut x: u128) -> Option<i32> {
+            let mut n = 0;
+            while x % 10 == 0 {
+                x /= 10;
+                n += 1;
+            }
+
+            if x == 1 {
+                Some(n)
+            } else {
+                None
+            }
+        }
+
+        let gcd = gcd(self.numerator, self.divisor);
+        if gcd == 0 {
+            return None;
+        }
+
+        Some(log10(self.numerator / gcd)? - log10(self.divisor / gcd)?)
+    }
+
+    /// Returns the same fraction with numerator and divisor divided by their
+    /// greatest common divisor (see [`gcd`]).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::rt::RtFraction;
+    ///
+    /// let f = RtFraction {
+    ///     numerator: 2000,
+    ///     divisor: 2,
+    /// };
+    /// assert_eq!(f.reduced().numerator, 1000);
+    /// assert_eq!(f.reduced().divisor, 1);
+    /// ```
+    #[inline]
+    pub const fn reduced(self) -> Self {
+        let gcd = gcd(self.numerator, self.divisor);
+        if gcd == 0 {
+            return self;
+        }
+
+        Self {
+            numerator: self.numerator / gcd,
+            divisor: self.divisor / gcd,
+        }
+    }
+
+    pub(crate) fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cross-reduce first to make overflow less likely
+        let a = Self {
+            numerator: self.numerator,
+            divisor: rhs.divisor,
+        }
+        .reduced();
+        let b = Self {
+            numerator: rhs.numerator,
+            divisor: self.divisor,
+        }
+        .reduced();
+
+        Some(Self {
+            numerator: a.numerator.checked_mul(b.numerator)?,
+            divisor: a.divisor.checked_mul(b.divisor)?,
+        })
+    }
+
+    fn checked_pow(self, exp: i8) -> Option<Self> {
+        let Self { numerator, divisor } = self.reduced();
+        let (numerator, divisor) = if exp < 0 {
+            (divisor, numerator)
+        } else {
+            (numerator, divisor)
+        };
+
+        let exp = u32::from(exp.unsigned_abs());
+        Some(Self {
+            numerator: numerator.checked_pow(exp)?,
+            divisor: divisor.checked_pow(exp)?,
+        })
+    }
+}
+
+/// Greatest common divisor of `a` and `b` (`gcd(0, 0)` is `0`).
+///
+/// This is the runtime counterpart of typenum's [`Gcf`](typenum::Gcf) (which
+/// works with both unsigned and signed type-level integers, the result is
+/// always non-negative).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::rt::gcd;
+/// use typenum::{assert_type_eq, Gcf, N12, P12, P18, P6, U2, U6, U8, Z0};
+///
+/// assert_eq!(gcd(12, 18), 6);
+/// assert_eq!(gcd(7, 0), 7);
+/// assert_eq!(gcd(0, 0), 0);
+///
+/// assert_type_eq!(Gcf<U8, U6>, U2);
+/// assert_type_eq!(Gcf<N12, P18>, P6);
+/// assert_type_eq!(Gcf<Z0, N12>, P12);
+/// ```
+#[inline]
+pub const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+/// Runtime representation of [`Dimensions`](crate::Dimensions).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RtDimensions {
+    /// Exponent of length (metre)
+    pub length: i8,
+    /// Exponent of mass (kilogram)
+    pub mass: i8,
+    /// Exponent of time (second)
+    pub time: i8,
+    /// Exponent of electric current (ampere)
+    pub electric_current: i8,
+    /// Exponent of thermodynamic temperature (kelvin)
+    pub thermodynamic_temperature: i8,
+    /// Exponent of amount of substance (mole)
+    pub amount_of_substance: i8,
+    /// Exponent of luminous intensity (candela)
+    pub luminous_intensity: i8,
+}
+
+impl RtDimensions {
+    fn checked_zip(self, rhs: Self, f: impl Fn(i8, i8) -> Option<i8>) -> Option<Self> {
+        Some(Self {
+            length: f(self.length, rhs.length)?,
+            mass: f(self.mass, rhs.mass)?,
+            time: f(self.time, rhs.time)?,
+            electric_current: f(self.electric_current, rhs.electric_current)?,
+            thermodynamic_temperature: f(
+                self.thermodynamic_temperature,
+                rhs.thermodynamic_temperature,
+            )?,
+            amount_of_substance: f(self.amount_of_substance, rhs.amount_of_substance)?,
+            luminous_intensity: f(self.luminous_intensity, rhs.luminous_intensity)?,
+        })
+    }
+
+    /// Returns exponents paired with symbols of the corresponding base units
+    pub(crate) fn exponents(&self) -> [(&'static str, i8); 7] {
+        [
+            ("m", self.length),
+            ("kg", self.mass),
+            ("s", self.time),
+            ("A", self.electric_current),
+            ("K", self.thermodynamic_temperature),
+            ("mol", self.amount_of_substance),
+            ("cd", self.luminous_intensity),
+        ]
+    }
+
+    /// Writes non-zero exponents as a product of base units (e.g.
+    /// `m * kg^2 * s^-1`), returns `false` if all exponents are zero.
+    ///
+    /// Shared by [`Display`] impls of [`RtUnit`] and [`Unit`](struct@crate::Unit).
+    pub(crate) fn fmt_exponents(&self, f: &mut fmt::Formatter<'_>) -> Result<bool, fmt::Error> {
+        let mut first = true;
+        for &(symbol, exp) in self.exponents().iter() {
+            if exp == 0 {
+                continue;
+            }
+
+            if !first {
+                f.write_str(" * ")?;
+            }
+            first = false;
+
+            match exp {
+                1 => f.write_str(symbol)?,
+                exp => f.write_fmt(format_args!("{}^{}", symbol, exp))?,
+            }
+        }
+
+        Ok(!first)
+    }
+}
+
+/// Runtime representation of [`Unit`](struct@crate::Unit).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{prefixes::Kilo, rt::RtUnit, units::Metre};
+///
+/// let km = RtUnit::of::<Kilo<Metre>>();
+/// assert_eq!(km.dimensions.length, 1);
+/// assert_eq!((km.ratio.numerator, km.ratio.divisor), (1000, 1));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RtUnit {
+    /// Exponents of the unit
+    pub dimensions: RtDimensions,
+    /// Ratio of the unit
+    pub ratio: RtFraction,
+}
+
+impl RtUnit {
+    const DIMENSIONLESS: Self = Self {
+        dimensions: RtDimensions {
+            length: 0,
+            mass: 0,
+            time: 0,
+            electric_current: 0,
+            thermodynamic_temperature: 0,
+            amount_of_substance: 0,
+            luminous_intensity: 0,
+        },
+        ratio: RtFraction {
+            numerator: 1,
+            divisor: 1,
+        },
+    };
+
+    /// Returns runtime representation of the unit `U`.
+    ///
+    /// Note: the ratio is reduced (so e.g. `Kilo<Gram>` and `KiloGram` have
+    /// the same representation).
+    #[inline]
+    pub const fn of<U: UnitTrait>() -> Self {
+        Self::of_rt(U::RT)
+    }
+
+    /// Parses unit from string like `kg*m/s^2`.
+    ///
+    /// Supported are symbols of named units (the same as used by `Display`
+    /// impl of [`Unit`], plus `Bq`, `Gy` and `Sv`), SI prefixes (`u` can be
+    /// used instead of `μ`), `*` (or `·`), `/`, parentheses and integer
+    /// exponents (`^`). `1` can be used for dimensionless unit (e.g. `1/s`).
+    /// Everything before `/` is the numerator and the single term (or
+    /// parenthesized group) after it is the denominator, forms like `J/kg*K`
+    /// or `m/s/s` are ambiguous and rejected (write `J/(kg·K)` instead). The
+    /// exponent binds to the symbol right before it. Whitespace around
+    /// operators is ignored. Names of derived units (e.g. `L/100km`) are also
+    /// accepted as a whole.
+    ///
+    /// The ratio of the result is reduced, so it's equal to
+    /// [`RtUnit::of`] of the corresponding type.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{
+    ///     prefixes::{Kilo, Milli},
+    ///     rt::{ParseUnitError, RtUnit},
+    ///     units::{
+    ///         Dimensionless, JoulePerKiloGramKelvin, KiloMetrePerHour, Newton, Second,
+    ///     },
+    ///     Unit,
+    /// };
+    ///
+    /// assert_eq!(RtUnit::parse("kg*m/s^2"), Ok(RtUnit::of::<Newton>()));
+    /// assert_eq!(RtUnit::parse("km / h"), Ok(RtUnit::of::<KiloMetrePerHour>()));
+    /// assert_eq!(RtUnit::parse("ms"), Ok(RtUnit::of::<Milli<Second>>()));
+    /// assert_eq!(
+    ///     RtUnit::parse("1/ks^2"),
+    ///     Ok(RtUnit::of::<Unit![Dimensionless / Kilo<Second> ^ 2]>())
+    /// );
+    ///
+    /// assert_eq!(RtUnit::parse("kmeter"), Err(ParseUnitError::UnknownSymbol));
+    /// assert_eq!(RtUnit::parse("m^x"), Err(ParseUnitError::InvalidExponent));
+    /// assert_eq!(RtUnit::parse("J/kg*K"), Err(ParseUnitError::Ambiguous));
+    /// assert_eq!(
+    ///     RtUnit::parse("J/(kg·K)"),
+    ///     Ok(RtUnit::of::<JoulePerKiloGramKelvin>())
+    /// );
+    /// ```
+    ///
+    /// [`Unit`]: struct@crate::Unit
+    #[inline]
+    pub fn parse(s: &str) -> Result<Self, ParseUnitError> {
+        // Some names (like `L/100km`) can't be parsed as an expression
+        if let Some(unit) = find(COHERENT_UNITS, s.trim()) {
+            return Ok(unit);
+        }
+
+        match parse_expr(s)? {
+            (unit, "") => Ok(unit),
+            // The only thing `parse_expr` can stop at is a closing parenthesis
+            _ => Err(ParseUnitError::UnbalancedParens),
+        }
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            dimensions: self
+                .dimensions
+                .checked_zip(rhs.dimensions, i8::checked_add)?,
+            ratio: self.ratio.checked_mul(rhs.ratio)?,
+        })
+    }
+
+    fn checked_pow(self, exp: i8) -> Option<Self> {
+        Some(Self {
+            dimensions: self
+                .dimensions
+                .checked_zip(self.dimensions, |a, _| a.checked_mul(exp))?,
+            ratio: self.ratio.checked_pow(exp)?,
+        })
+    }
+
+    fn recip(self) -> Option<Self> {
+        self.checked_pow(-1)
+    }
+}
+
+impl RtUnit {
+    /// Returns name of the unit (e.g. `km/h` or `mJ`), if the unit has one
+    fn name(&self) -> Option<Name> {
+        let this = Self::of_rt(*self);
+
+        if let Some(&(name, _)) = COHERENT_UNITS
+            .iter()
+            .find(|&&(_, unit)| Self::of_rt(unit) == this)
+        {
+            return Some(Name("", name, 1));
+        }
+
+        for &(name, unit) in SIMPLE_UNITS {
+            if unit.dimensions != this.dimensions {
+                continue;
+            }
+
+            let ratio = this.ratio.checked_mul(RtFraction {
+                numerator: unit.ratio.divisor,
+                divisor: unit.ratio.numerator,
+            });
+            if let Some(prefix) = ratio
+                .and_then(|ratio| ratio.pow10())
+                .and_then(crate::prefixes::symbol)
+            {
+                return Some(Name(prefix, name, 1));
+            }
+        }
+
+        try_get_prefixed_power(&this).map(|(prefix, symbol, exp)| Name(prefix, symbol, exp))
+    }
+
+    /// Returns `true` if [`RtUnit::parse`] parses it's [`Display`] output back
+    /// to the same unit
+    #[cfg(feature = "deser")]
+    pub(crate) fn is_parsable(&self) -> bool {
+        use core::fmt::Write;
+
+        /// Fixed size buffer, so this works without `alloc`
+        struct Buf {
+            buf: [u8; 256],
+            len: usize,
+        }
+
+        impl Write for Buf {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.len + s.len();
+                self.buf
+                    .get_mut(self.len..end)
+                    .ok_or(fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let mut buf = Buf {
+            buf: [0; 256],
+            len: 0,
+        };
+        if write!(buf, "{}", self).is_err() {
+            return false;
+        }
+
+        // Only `&str`s were written
+        core::str::from_utf8(&buf.buf[..buf.len])
+            .ok()
+            .and_then(|s| Self::parse(s).ok())
+            == Some(Self::of_rt(*self))
+    }
+
+    const fn of_rt(rt: Self) -> Self {
+        RtUnit {
+            dimensions: rt.dimensions,
+            ratio: rt.ratio.reduced(),
+        }
+    }
+}
+
+/// Prefix, symbol and exponent
+struct Name(&'static str, &'static str, i8);
+
+/// Shows the unit the same way as [`Unit`]'s [`Display`] does, e.g. `km/h`,
+/// `m * s^-1` or `m (ratio: 1/3)`.
+///
+/// Output (if it doesn't have ratio note) can be parsed back with
+/// [`RtUnit::parse`].
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     prefixes::Milli,
+///     rt::RtUnit,
+///     units::{Joule, KiloMetrePerHour, Metre, Second},
+///     Unit,
+/// };
+///
+/// assert_eq!(RtUnit::of::<KiloMetrePerHour>().to_string(), "km/h");
+/// assert_eq!(RtUnit::of::<Milli<Joule>>().to_string(), "mJ");
+/// assert_eq!(RtUnit::of::<Unit![Metre * Second]>().to_string(), "m * s");
+/// assert_eq!(RtUnit::parse("kg*m/s").unwrap().to_string(), "m * kg * s^-1");
+/// ```
+///
+/// [`Unit`]: struct@crate::Unit
+impl Display for RtUnit {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(Name(prefix, symbol, 1)) => f.write_fmt(format_args!("{}{}", prefix, symbol)),
+            Some(Name(prefix, symbol, exp)) => {
+                f.write_fmt(format_args!("{}{}^{}", prefix, symbol, exp))
+            },
+            None => {
+                let sep = if self.dimensions.fmt_exponents(f)? {
+                    " "
+                } else {
+                    ""
+                };
+
+                let RtFraction { numerator, divisor } = self.ratio.reduced();
+                match divisor {
+                    _ if numerator == divisor => Ok(()),
+                    1 => f.write_fmt(format_args!("{}(ratio: {})", sep, numerator)),
+                    _ => f.write_fmt(format_args!("{}(ratio: {} / {})", sep, numerator, divisor)),
+                }
+            },
+        }
+    }
+}
+
+/// Serializes unit as a string (see [`Display`] impl), fails if the unit
+/// can't be parsed back.
+#[cfg(feature = "deser")]
+impl serde::Serialize for RtUnit {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if !self.is_parsable() {
+            return Err(serde::ser::Error::custom(format_args!(
+                "unit `{}` can't be represented as a string",
+                self
+            )));
+        }
+
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes unit from a string (see [`RtUnit::parse`]).
+#[cfg(feature = "deser")]
+impl<'de> serde::Deserialize<'de> for RtUnit {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = RtUnit;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a unit symbol (like `km/h`)")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                RtUnit::parse(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// Parses `factor * factor * ... [/ factor]`, returns the unit and the rest of
+/// the input
+fn parse_expr(s: &str) -> Result<(RtUnit, &str), ParseUnitError> {
+    let (mut acc, mut rest) = parse_factor(s)?;
+    while let Some(r) = rest.strip_prefix(['*', '·']) {
+        let (factor, r) = parse_factor(r)?;
+        acc = acc.checked_mul(factor).ok_or(ParseUnitError::Overflow)?;
+        rest = r;
+    }
+
+    if let Some(r) = rest.strip_prefix('/') {
+        let (factor, r) = parse_factor(r)?;
+        acc = factor
+            .recip()
+            .and_then(|factor| acc.checked_mul(factor))
+            .ok_or(ParseUnitError::Overflow)?;
+        rest = r;
+
+        // `a/b*c` could mean both `(a/b)*c` and `a/(b*c)`
+        if rest.starts_with(['*', '·', '/']) {
+            return Err(ParseUnitError::Ambiguous);
+        }
+    }
 
-#[derive(Eq, PartialEq)]
-pub(crate) struct RtFraction {
-    pub(crate) numerator: u64,
-    pub(crate) divisor: u64,
+    Ok((acc, rest))
 }
 
-#[derive(Eq, PartialEq)]
-pub(crate) struct RtDimensions {
-    pub(crate) length: i8,
-    pub(crate) mass: i8,
-    pub(crate) time: i8,
-    pub(crate) electric_current: i8,
-    pub(crate) thermodynamic_temperature: i8,
-    pub(crate) amount_of_substance: i8,
-    pub(crate) luminous_intensity: i8,
+/// Parses a single term or a parenthesized expression, returns it and the rest
+/// of the input (starting with an operator or a closing parenthesis)
+fn parse_factor(s: &str) -> Result<(RtUnit, &str), ParseUnitError> {
+    let s = s.trim_start();
+    if let Some(inner) = s.strip_prefix('(') {
+        let (unit, rest) = parse_expr(inner)?;
+        let rest = rest
+            .strip_prefix(')')
+            .ok_or(ParseUnitError::UnbalancedParens)?;
+        return Ok((unit, rest.trim_start()));
+    }
+
+    let end = s.find(['*', '·', '/', '(', ')']).unwrap_or(s.len());
+    match s[end..].chars().next() {
+        Some('(') => Err(ParseUnitError::UnbalancedParens),
+        _ => Ok((parse_term(s[..end].trim())?, &s[end..])),
+    }
+}
+
+/// Parses `symbol` or `symbol^exp`
+fn parse_term(term: &str) -> Result<RtUnit, ParseUnitError> {
+    let (symbol, exp) = match term.find('^') {
+        Some(i) => {
+            let exp = term[i + 1..]
+                .trim()
+                .parse::<i8>()
+                .map_err(|_| ParseUnitError::InvalidExponent)?;
+            (term[..i].trim(), exp)
+        },
+        None => (term, 1),
+    };
+
+    lookup(symbol)?
+        .checked_pow(exp)
+        .ok_or(ParseUnitError::Overflow)
+}
+
+/// Finds unit by its exact name in the table, the ratio of the result is
+/// reduced
+fn find(table: &[(&str, RtUnit)], symbol: &str) -> Option<RtUnit> {
+    table
+        .iter()
+        .find(|&&(name, _)| name == symbol)
+        .map(|&(_, unit)| RtUnit::of_rt(unit))
+}
+
+/// Finds unit by (possibly prefixed) symbol
+fn lookup(symbol: &str) -> Result<RtUnit, ParseUnitError> {
+    match symbol {
+        "" => return Err(ParseUnitError::Empty),
+        "1" => return Ok(RtUnit::DIMENSIONLESS),
+        _ => {},
+    }
+
+    if let Some(unit) = find(SIMPLE_UNITS, symbol)
+        .or_else(|| find(COHERENT_UNITS, symbol))
+        .or_else(|| find(ALIAS_UNITS, symbol))
+    {
+        return Ok(unit);
+    }
+
+    for exp in -30..=30 {
+        let prefix = match crate::prefixes::symbol(exp) {
+            Some("") | None => continue,
+            Some("μ") if symbol.starts_with('u') => "u",
+            Some(prefix) => prefix,
+        };
+
+        if let Some(unit) = symbol
+            .strip_prefix(prefix)
+            .and_then(|rest| find(SIMPLE_UNITS, rest).or_else(|| find(ALIAS_UNITS, rest)))
+        {
+            let factor = RtFraction {
+                numerator: 10,
+                divisor: 1,
+            };
+            return factor
+                .checked_pow(exp as i8)
+                .and_then(|ratio| unit.ratio.checked_mul(ratio))
+                .map(|ratio| RtUnit { ratio, ..unit })
+                .ok_or(ParseUnitError::Overflow);
+        }
+    }
+
+    Err(ParseUnitError::UnknownSymbol)
 }
 
-#[derive(Eq, PartialEq)]
-pub(crate) struct RtUnit {
-    pub(crate) dimensions: RtDimensions,
-    pub(crate) ratio: RtFraction,
+/// Error returned by [`RtUnit::parse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseUnitError {
+    /// Symbol is missing (e.g. the input is empty or ends with an operator)
+    Empty,
+    /// Symbol isn't a (prefixed) symbol of a known unit
+    UnknownSymbol,
+    /// Exponent after `^` isn't a valid integer
+    InvalidExponent,
+    /// Exponent or ratio of the unit is too big
+    Overflow,
+    /// `/` is followed by another `*` or `/` (e.g. `J/kg*K`)
+    Ambiguous,
+    /// Parentheses aren't balanced or are misplaced (e.g. `(m/s` or `m(s)`)
+    UnbalancedParens,
+}
+
+impl Display for ParseUnitError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParseUnitError::Empty => "missing unit symbol",
+            ParseUnitError::UnknownSymbol => "unknown unit symbol",
+            ParseUnitError::InvalidExponent => "invalid exponent",
+            ParseUnitError::Overflow => "exponent or ratio is too big",
+            ParseUnitError::Ambiguous => "ambiguous `/`, use at most one `/` as the last operator",
+            ParseUnitError::UnbalancedParens => "unbalanced parentheses",
+        })
+    }
+}
+
+/// Returns human-readable explanation of the difference between 2 units (e.g.
+/// `left has s^-1, right has s^-2; ratios differ by 1000`).
+///
+/// This is useful for panic and error messages when units are checked at
+/// runtime.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     prefixes::Kilo,
+///     rt::{explain_mismatch, RtUnit},
+///     units::{Hertz, Metre, MetrePerSecondSquared, Second},
+///     Unit,
+/// };
+///
+/// let a = RtUnit::of::<Unit![Kilo<Metre> / Second]>();
+/// let b = RtUnit::of::<MetrePerSecondSquared>();
+/// assert_eq!(
+///     explain_mismatch(a, b).to_string(),
+///     "left has s^-1, right has s^-2; ratios differ by 1000"
+/// );
+///
+/// let a = RtUnit::of::<Hertz>();
+/// assert_eq!(explain_mismatch(a, a).to_string(), "units are the same");
+/// ```
+#[inline]
+pub fn explain_mismatch(left: RtUnit, right: RtUnit) -> impl Display {
+    Mismatch { left, right }
+}
+
+struct Mismatch {
+    left: RtUnit,
+    right: RtUnit,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Ratios are compared reduced, so e.g. `2/2` and `1/1` are the same
+        let (left, right) = (RtUnit::of_rt(self.left), RtUnit::of_rt(self.right));
+        if left == right {
+            return f.write_str("units are the same");
+        }
+
+        let mut first = true;
+        if left.dimensions != right.dimensions {
+            let (l, r) = (left.dimensions.exponents(), right.dimensions.exponents());
+            for (side, exponents) in &[("left", l), ("right", r)] {
+                if !first {
+                    f.write_str(", ")?;
+                }
+                f.write_fmt(format_args!("{} has", side))?;
+                // Only the dimensions those differ
+                for (&(symbol, e), _) in exponents
+                    .iter()
+                    .zip(l.iter().zip(r.iter()))
+                    .filter(|(_, (l, r))| l != r)
+                {
+                    f.write_fmt(format_args!(" {}^{}", symbol, e))?;
+                }
+                first = false;
+            }
+        }
+
+        // `l_n/l_d : r_n/r_d` = `l_n*r_d : r_n*l_d`
+        let (l, r) = (left.ratio, right.ratio);
+        let quot = l.checked_mul(RtFraction {
+            numerator: r.divisor,
+            divisor: r.numerator,
+        });
+        match quot.map(RtFraction::reduced) {
+            Some(RtFraction { numerator, divisor }) if numerator == divisor => {},
+            quot => {
+                if !first {
+                    f.write_str("; ")?;
+                }
+
+                match quot {
+                    Some(RtFraction { numerator, divisor }) => {
+                        let (big, small) = if numerator > divisor {
+                            (numerator, divisor)
+                        } else {
+                            (divisor, numerator)
+                        };
+                        match (big, small) {
+                            (n, 1) => f.write_fmt(format_args!("ratios differ by {}", n))?,
+                            (n, d) => f.write_fmt(format_args!("ratios differ by {}/{}", n, d))?,
+                        }
+                    },
+                    // The ratios are too far apart to be represented
+                    None => f.write_str("ratios differ")?,
+                }
+            },
+        }
+
+        Ok(())
+    }
 }
 
 pub(crate) trait FractionRtExt: FractionTrait {
     const RT: RtFraction = RtFraction {
-        numerator: Self::Numerator::U64,
-        divisor: Self::Divisor::U64,
+        numerator: Self::Numerator::U128,
+        divisor: Self::Divisor::U128,
     };
 }
 
@@ -57,3 +804,166 @@ pub(crate) trait UnitRtExt: UnitTrait {
 }
 
 impl<T> UnitRtExt for T where T: UnitTrait {}
+
+#[cfg(test)]
+mod tests {
+    use super::{explain_mismatch, ParseUnitError, RtFraction, RtUnit};
+    use crate::{
+        prefixes::{Centi, Deca, Kilo, Micro, Milli, Quecto, Quetta, Ronna, Yocto, Yotta},
+        units::*,
+    };
+
+    fn explain<A: crate::UnitTrait, B: crate::UnitTrait>() -> String {
+        explain_mismatch(RtUnit::of::<A>(), RtUnit::of::<B>()).to_string()
+    }
+
+    #[test]
+    fn mismatch() {
+        assert_eq!(explain::<Metre, Metre>(), "units are the same");
+        assert_eq!(explain::<Hour, Minute>(), "ratios differ by 60");
+        assert_eq!(
+            explain::<Centi<Metre>, Kilo<Metre>>(),
+            "ratios differ by 100000"
+        );
+        assert_eq!(explain::<Milli<Second>, Minute>(), "ratios differ by 60000");
+        assert_eq!(
+            explain::<Yotta<Metre>, Metre>(),
+            "ratios differ by 1000000000000000000000000"
+        );
+        assert_eq!(explain::<Yotta<Metre>, Yocto<Metre>>(), "ratios differ");
+        assert_eq!(
+            explain::<Metre, Second>(),
+            "left has m^1 s^0, right has m^0 s^1"
+        );
+        assert_eq!(
+            explain::<Newton, Watt>(),
+            "left has m^1 s^-2, right has m^2 s^-3"
+        );
+        assert_eq!(
+            explain::<KiloGram, Kilo<Second>>(),
+            "left has kg^1 s^0, right has kg^0 s^1; ratios differ by 1000"
+        );
+
+        // Equal, but not reduced ratios
+        let unreduced = RtUnit {
+            ratio: RtFraction {
+                numerator: 2,
+                divisor: 2,
+            },
+            ..RtUnit::of::<Metre>()
+        };
+        assert_eq!(
+            explain_mismatch(unreduced, RtUnit::of::<Metre>()).to_string(),
+            "units are the same"
+        );
+        let unreduced = RtUnit {
+            ratio: RtFraction {
+                numerator: 2000,
+                divisor: 2,
+            },
+            ..RtUnit::of::<Second>()
+        };
+        assert_eq!(
+            explain_mismatch(unreduced, RtUnit::of::<Metre>()).to_string(),
+            "left has m^0 s^1, right has m^1 s^0; ratios differ by 1000"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        macro_rules! assert_parse {
+            ($s:literal, $T:ty) => {
+                assert_eq!(RtUnit::parse($s), Ok(RtUnit::of::<$T>()), "{}", $s);
+            };
+        }
+
+        assert_parse!("m", Metre);
+        assert_parse!("kg", KiloGram);
+        assert_parse!("g", Gram);
+        assert_parse!("mg", Milli<Gram>);
+        assert_parse!("dam", Deca<Metre>);
+        assert_parse!("dimless", Dimensionless);
+        assert_parse!("1", Dimensionless);
+        assert_parse!("min", Minute);
+        assert_parse!("mol", Mole);
+        assert_parse!("cd", Candela);
+        assert_parse!("kWh", KiloWattHour);
+        assert_parse!("mAh", MilliAmpereHour);
+        assert_parse!("kΩ", Kilo<Ohm>);
+        assert_parse!("%", Percent);
+        assert_parse!("μs", Micro<Second>);
+        assert_parse!("us", Micro<Second>);
+        assert_parse!("YJ", Yotta<Joule>);
+        assert_parse!("RW", Ronna<Watt>);
+        assert_parse!("Qg", Quetta<Gram>);
+        assert_parse!("qm", Quecto<Metre>);
+        assert_parse!("m^2", SquareMetre);
+        assert_parse!("m/s^2", MetrePerSecondSquared);
+        assert_parse!("W/m^2", WattPerSquareMetre);
+        assert_parse!("m^-1", Unit![Dimensionless / Metre]);
+        assert_parse!("N*m", NewtonMetre);
+        assert_parse!("J/K", JoulePerKelvin);
+        assert_parse!("kg * m^2 / s^3", Watt);
+        assert_parse!("cm^3", Unit![Centi<Metre> ^ 3]);
+        assert_parse!("1/min", RevolutionsPerMinute);
+        assert_parse!("km/h", KiloMetrePerHour);
+        assert_parse!("kg*m/s^2", Newton);
+        assert_parse!("N·m", NewtonMetre);
+        assert_parse!("W/(m·K)", WattPerMetreKelvin);
+        assert_parse!("W / (m^2 · Hz)", WattPerSquareMetrePerHertz);
+        assert_parse!("(kg*m)/(s^2)", Newton);
+        assert_parse!("((m))", Metre);
+        assert_parse!("L/100km", LitrePerHundredKiloMetre);
+        assert_parse!(" L/100km ", LitrePerHundredKiloMetre);
+        assert_parse!("Sv", Sievert);
+        assert_parse!("mGy", Milli<Gray>);
+        assert_parse!("kBq", Kilo<Becquerel>);
+        assert_parse!("Gy/s", GrayPerSecond);
+        assert_parse!("Sv/h", SievertPerHour);
+        assert_parse!("mSv/h", Milli<SievertPerHour>);
+        assert_parse!("uSv/h", Micro<SievertPerHour>);
+
+        assert_eq!(RtUnit::parse(""), Err(ParseUnitError::Empty));
+        assert_eq!(RtUnit::parse("m*"), Err(ParseUnitError::Empty));
+        assert_eq!(RtUnit::parse("m/"), Err(ParseUnitError::Empty));
+        assert_eq!(RtUnit::parse("m//s"), Err(ParseUnitError::Empty));
+        assert_eq!(RtUnit::parse("m/s*s"), Err(ParseUnitError::Ambiguous));
+        assert_eq!(RtUnit::parse("m/s/s"), Err(ParseUnitError::Ambiguous));
+        assert_eq!(RtUnit::parse("J/kg * K"), Err(ParseUnitError::Ambiguous));
+        assert_eq!(RtUnit::parse("J/kg·K"), Err(ParseUnitError::Ambiguous));
+        assert_eq!(RtUnit::parse("(m/s"), Err(ParseUnitError::UnbalancedParens));
+        assert_eq!(RtUnit::parse("m/s)"), Err(ParseUnitError::UnbalancedParens));
+        assert_eq!(RtUnit::parse("m(s)"), Err(ParseUnitError::UnbalancedParens));
+        assert_eq!(RtUnit::parse("()"), Err(ParseUnitError::Empty));
+        assert_eq!(RtUnit::parse("foo"), Err(ParseUnitError::UnknownSymbol));
+        assert_eq!(RtUnit::parse("kmin"), Err(ParseUnitError::UnknownSymbol));
+        assert_eq!(RtUnit::parse("m^"), Err(ParseUnitError::InvalidExponent));
+        assert_eq!(
+            RtUnit::parse("m^1000"),
+            Err(ParseUnitError::InvalidExponent)
+        );
+        assert_eq!(RtUnit::parse("Ym^4"), Err(ParseUnitError::Overflow));
+        assert_eq!(RtUnit::parse("m^100*m^100"), Err(ParseUnitError::Overflow));
+    }
+
+    #[test]
+    fn round_trip() {
+        use crate::unit::{ALIAS_UNITS, COHERENT_UNITS, SIMPLE_UNITS};
+
+        for &(name, unit) in SIMPLE_UNITS.iter().chain(COHERENT_UNITS).chain(ALIAS_UNITS) {
+            let unit = RtUnit::of_rt(unit);
+            assert_eq!(RtUnit::parse(name), Ok(unit), "{}", name);
+
+            let displayed = unit.to_string();
+            assert_eq!(RtUnit::parse(&displayed), Ok(unit), "{}", displayed);
+            #[cfg(feature = "deser")]
+            assert!(unit.is_parsable(), "{}", displayed);
+        }
+
+        #[cfg(feature = "deser")]
+        {
+            assert!(RtUnit::of::<Unit![Metre * Second]>().is_parsable());
+            assert!(!RtUnit::of::<Unit![Metre / Hour]>().is_parsable());
+        }
+    }
+}
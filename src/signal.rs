@@ -0,0 +1,714 @@
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, Mul, Sub},
+};
+
+use typenum::{Prod, Quot, U2};
+
+use crate::{
+    eq::UnitEq,
+    from_int::{FromUnsigned, Zero},
+    strict::{StorageAdd, StorageMul, StorageSub},
+    units::Second,
+    Quantity, UnitTrait,
+};
+
+/// Accumulates `value * dt`, keeping track of units (e.g. integrating
+/// velocity over time gives distance).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{signal::Integrator, IntExt};
+///
+/// let mut distance = Integrator::new();
+/// distance.step(2.mps(), 3.s());
+/// distance.step(4.mps(), 1.s());
+///
+/// assert_eq!(distance.total(), 10.m());
+/// ```
+pub struct Integrator<Q, Dt>
+where
+    Q: Mul<Dt>,
+{
+    total: Prod<Q, Dt>,
+    _dt: PhantomData<Dt>,
+}
+
+impl<Q, Dt> Integrator<Q, Dt>
+where
+    Q: Mul<Dt>,
+{
+    /// Creates new integrator with zero total.
+    #[inline]
+    pub fn new() -> Self
+    where
+        Prod<Q, Dt>: Default,
+    {
+        Self::with_initial(Default::default())
+    }
+
+    /// Creates new integrator with the given initial total.
+    #[inline]
+    pub fn with_initial(total: Prod<Q, Dt>) -> Self {
+        Self {
+            total,
+            _dt: PhantomData,
+        }
+    }
+
+    /// Adds `value * dt` to the total.
+    #[inline]
+    pub fn step(&mut self, value: Q, dt: Dt)
+    where
+        Prod<Q, Dt>: AddAssign,
+    {
+        self.total += value * dt;
+    }
+
+    /// Returns the accumulated total.
+    #[inline]
+    pub fn total(&self) -> Prod<Q, Dt>
+    where
+        Prod<Q, Dt>: Copy,
+    {
+        self.total
+    }
+
+    /// Resets the total to zero.
+    #[inline]
+    pub fn reset(&mut self)
+    where
+        Prod<Q, Dt>: Default,
+    {
+        self.total = Default::default();
+    }
+}
+
+impl<Q, Dt> Default for Integrator<Q, Dt>
+where
+    Q: Mul<Dt>,
+    Prod<Q, Dt>: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes `Δvalue / dt` between consecutive values, keeping track of units
+/// (e.g. differentiating distance over time gives velocity).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{signal::Differentiator, IntExt};
+///
+/// let mut velocity = Differentiator::new();
+///
+/// // There is no previous value yet
+/// assert_eq!(velocity.step(10.m(), 1.s()), None);
+/// assert_eq!(velocity.step(16.m(), 2.s()), Some(3.mps()));
+/// ```
+pub struct Differentiator<Q, Dt> {
+    previous: Option<Q>,
+    _dt: PhantomData<Dt>,
+}
+
+impl<Q, Dt> Differentiator<Q, Dt> {
+    /// Creates new differentiator without a previous value.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            previous: None,
+            _dt: PhantomData,
+        }
+    }
+
+    /// Remembers `value` and returns `(value - previous) / dt` or `None` if
+    /// this is the first value.
+    #[inline]
+    pub fn step(&mut self, value: Q, dt: Dt) -> Option<Quot<Q, Dt>>
+    where
+        Q: Sub<Output = Q> + Div<Dt> + Copy,
+    {
+        self.previous
+            .replace(value)
+            .map(|previous| (value - previous) / dt)
+    }
+
+    /// Returns the previous value.
+    #[inline]
+    pub fn previous(&self) -> Option<Q>
+    where
+        Q: Copy,
+    {
+        self.previous
+    }
+
+    /// Forgets the previous value.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+impl<Q, Dt> Default for Differentiator<Q, Dt> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Value sampled at some point in time (e.g. a telemetry reading).
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{signal::Sample, IntExt};
+///
+/// let a = Sample::new(10.s(), 100.m());
+/// let b = Sample::from((14.s(), 120.m()));
+///
+/// assert_eq!(a.rate(b), 5.mps());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Sample<T, V> {
+    /// Time at which the value was sampled
+    pub time: T,
+    /// Sampled value
+    pub value: V,
+}
+
+impl<T, V> Sample<T, V> {
+    /// Creates new sample.
+    #[inline]
+    pub const fn new(time: T, value: V) -> Self {
+        Self { time, value }
+    }
+
+    /// Returns `(next.value - self.value) / (next.time - self.time)`, i.e. the
+    /// average rate of change between two samples.
+    ///
+    /// Note that if both samples have the same time, this divides by zero
+    /// (which panics for integers).
+    #[inline]
+    pub fn rate(self, next: Self) -> Quot<V, T>
+    where
+        T: Sub<Output = T>,
+        V: Sub<Output = V> + Div<T>,
+    {
+        (next.value - self.value) / (next.time - self.time)
+    }
+
+    /// Same as [`rate`](Self::rate), but returns `None` if both samples have
+    /// the same time.
+    #[inline]
+    pub fn checked_rate(self, next: Self) -> Option<Quot<V, T>>
+    where
+        T: Sub<Output = T> + PartialEq,
+        V: Sub<Output = V> + Div<T>,
+    {
+        if self.time == next.time {
+            None
+        } else {
+            Some(self.rate(next))
+        }
+    }
+
+    /// Converts the sample into `(time, value)` pair.
+    #[inline]
+    pub fn into_pair(self) -> (T, V) {
+        (self.time, self.value)
+    }
+}
+
+impl<T, V> From<(T, V)> for Sample<T, V> {
+    #[inline]
+    fn from((time, value): (T, V)) -> Self {
+        Self::new(time, value)
+    }
+}
+
+/// Returns rates of change between consecutive samples (see
+/// [`Sample::rate`]), `n` samples give `n - 1` rates.
+///
+/// To use with a slice, call `.iter().copied()` on it.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     signal::{finite_difference, Sample},
+///     IntExt,
+/// };
+///
+/// let positions = [
+///     Sample::new(0.s(), 0.m()),
+///     Sample::new(2.s(), 10.m()),
+///     Sample::new(3.s(), 13.m()),
+/// ];
+/// let velocities: Vec<_> = finite_difference(positions.iter().copied()).collect();
+///
+/// assert_eq!(velocities, [5.mps(), 3.mps()]);
+/// ```
+#[inline]
+pub fn finite_difference<I, T, V>(samples: I) -> FiniteDifference<I::IntoIter>
+where
+    I: IntoIterator<Item = Sample<T, V>>,
+    T: Sub<Output = T> + Copy,
+    V: Sub<Output = V> + Div<T> + Copy,
+{
+    FiniteDifference {
+        iter: samples.into_iter(),
+        previous: None,
+    }
+}
+
+/// Iterator returned by [`finite_difference`].
+#[derive(Debug, Clone)]
+pub struct FiniteDifference<I: Iterator> {
+    iter: I,
+    previous: Option<I::Item>,
+}
+
+impl<I, T, V> Iterator for FiniteDifference<I>
+where
+    I: Iterator<Item = Sample<T, V>>,
+    T: Sub<Output = T> + Copy,
+    V: Sub<Output = V> + Div<T> + Copy,
+{
+    type Item = Quot<V, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = match self.previous {
+            Some(previous) => previous,
+            None => self.iter.next()?,
+        };
+
+        let next = self.iter.next()?;
+        self.previous = Some(next);
+        Some(previous.rate(next))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        match self.previous {
+            Some(_) => (lo, hi),
+            None => (lo.saturating_sub(1), hi.map(|hi| hi.saturating_sub(1))),
+        }
+    }
+}
+
+impl<I, T, V> FusedIterator for FiniteDifference<I>
+where
+    I: FusedIterator<Item = Sample<T, V>>,
+    T: Sub<Output = T> + Copy,
+    V: Sub<Output = V> + Div<T> + Copy,
+{
+}
+
+/// Integrates samples using the trapezoidal rule, i.e. sums
+/// `(v0 + v1) * (t1 - t0) / 2` over consecutive samples (zero if there are
+/// less than 2 samples).
+///
+/// The division by 2 is done once, after summing, so integers are truncated
+/// only once.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     signal::{trapezoidal_integral, Sample},
+///     IntExt,
+/// };
+///
+/// let velocities = [
+///     Sample::new(0.s(), 0.mps()),
+///     Sample::new(2.s(), 4.mps()),
+///     Sample::new(3.s(), 4.mps()),
+/// ];
+///
+/// assert_eq!(trapezoidal_integral(velocities.iter().copied()), 8.m());
+/// ```
+#[inline]
+pub fn trapezoidal_integral<I, S, T, V>(samples: I) -> Quantity<S, Prod<V, T>>
+where
+    I: IntoIterator<Item = Sample<Quantity<S, T>, Quantity<S, V>>>,
+    S: StorageAdd<V, Output = S>
+        + StorageSub<T, Output = S>
+        + StorageMul<V, T, Output = S>
+        + StorageAdd<Prod<V, T>, Output = S>
+        + FromUnsigned
+        + Div<Output = S>
+        + Zero
+        + Copy,
+    T: UnitTrait,
+    V: UnitTrait + Mul<T>,
+{
+    let mut samples = samples.into_iter();
+    let mut total = Quantity::zero();
+
+    if let Some(mut previous) = samples.next() {
+        for sample in samples {
+            total = total + (previous.value + sample.value) * (sample.time - previous.time);
+            previous = sample;
+        }
+    }
+
+    total.map(|t| t / S::from_unsigned::<U2>())
+}
+
+/// Exponential moving average (first-order low-pass) filter.
+///
+/// Each update computes `y = (y * tau + x * dt) / (tau + dt)`, where `tau` is
+/// the time constant and `dt` is the update period (this form works with
+/// unsigned integers too). The first input is returned as is, unless the
+/// filter was created with an initial value.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{signal::EmaFilter, IntExt};
+///
+/// let mut filter = EmaFilter::new(3.0.s(), 1.0.s());
+///
+/// assert_eq!(filter.update(1.0.v()), 1.0.v());
+/// assert_eq!(filter.update(5.0.v()), 2.0.v());
+/// assert_eq!(filter.value(), Some(2.0.v()));
+/// ```
+pub struct EmaFilter<S, U> {
+    /// Time constant
+    tau: S,
+    /// Update period
+    dt: S,
+    value: Option<S>,
+    _unit: PhantomData<U>,
+}
+
+impl<S, U> EmaFilter<S, U> {
+    /// Creates new filter with the given time constant and update period,
+    /// both must be times of the same unit.
+    #[inline]
+    pub fn new<T>(time_constant: Quantity<S, T>, period: Quantity<S, T>) -> Self
+    where
+        T: UnitTrait<Dimensions = <Second as UnitTrait>::Dimensions>,
+    {
+        Self {
+            tau: time_constant.into_inner(),
+            dt: period.into_inner(),
+            value: None,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Same as [`new`](Self::new), but starts from `initial` instead of the
+    /// first input.
+    #[inline]
+    pub fn with_initial<T>(
+        time_constant: Quantity<S, T>,
+        period: Quantity<S, T>,
+        initial: Quantity<S, U>,
+    ) -> Self
+    where
+        T: UnitTrait<Dimensions = <Second as UnitTrait>::Dimensions>,
+    {
+        Self {
+            value: Some(initial.into_inner()),
+            ..Self::new(time_constant, period)
+        }
+    }
+
+    /// Feeds `input` to the filter and returns the new output.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if both the time constant and the update period are zero
+    /// integers.
+    #[inline]
+    pub fn update(&mut self, input: Quantity<S, U>) -> Quantity<S, U>
+    where
+        S: Add<Output = S> + Mul<Output = S> + Div<Output = S> + Copy,
+    {
+        let x = input.into_inner();
+        let y = match self.value {
+            Some(y) => (y * self.tau + x * self.dt) / (self.tau + self.dt),
+            None => x,
+        };
+
+        self.value = Some(y);
+        Quantity::new(y)
+    }
+
+    /// Returns the current output or `None` if there were no inputs yet.
+    #[inline]
+    pub fn value(&self) -> Option<Quantity<S, U>>
+    where
+        S: Copy,
+    {
+        self.value.map(Quantity::new)
+    }
+
+    /// Forgets the current output, the next input will be returned as is.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+/// Slew-rate limiter, limits the change between successive values to
+/// `max_rate * dt`.
+///
+/// The first input is returned as is, unless the limiter was created with an
+/// initial value.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{
+///     prefixes::Milli,
+///     signal::SlewLimiter,
+///     units::{Second, Volt},
+///     IntExt, Quantity, Unit,
+/// };
+///
+/// // 2 V/s, updated every 100 ms
+/// let max_rate = 2.quantity::<Unit![Volt / Second]>();
+/// let dt = Quantity::<_, Milli<Second>>::new(100);
+/// let mut limiter = SlewLimiter::<_, Milli<Volt>>::new(max_rate, dt);
+///
+/// assert_eq!(limiter.update(1000.mv()), 1000.mv());
+/// assert_eq!(limiter.update(2000.mv()), 1200.mv());
+/// assert_eq!(limiter.update(1100.mv()), 1100.mv());
+/// assert_eq!(limiter.update(0.mv()), 900.mv());
+/// ```
+pub struct SlewLimiter<S, U> {
+    /// Maximum change per update (`max_rate * dt`)
+    max_step: S,
+    value: Option<S>,
+    _unit: PhantomData<U>,
+}
+
+impl<S, U> SlewLimiter<S, U> {
+    /// Creates new limiter with the given maximum rate of change and update
+    /// period.
+    ///
+    /// `max_rate * dt` must have exactly the unit of the limited values
+    /// (e.g. `V/s * ms = mV`), so the step isn't rounded.
+    #[inline]
+    pub fn new<R, T>(max_rate: Quantity<S, R>, dt: Quantity<S, T>) -> Self
+    where
+        S: StorageMul<R, T, Output = S>,
+        R: UnitTrait + Mul<T>,
+        T: UnitTrait<Dimensions = <Second as UnitTrait>::Dimensions>,
+        Prod<R, T>: UnitTrait + UnitEq<U>,
+    {
+        Self {
+            max_step: (max_rate * dt).into_same_unit::<U>().into_inner(),
+            value: None,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Same as [`new`](Self::new), but starts from `initial` instead of the
+    /// first input.
+    #[inline]
+    pub fn with_initial<R, T>(
+        max_rate: Quantity<S, R>,
+        dt: Quantity<S, T>,
+        initial: Quantity<S, U>,
+    ) -> Self
+    where
+        S: StorageMul<R, T, Output = S>,
+        R: UnitTrait + Mul<T>,
+        T: UnitTrait<Dimensions = <Second as UnitTrait>::Dimensions>,
+        Prod<R, T>: UnitTrait + UnitEq<U>,
+    {
+        Self {
+            value: Some(initial.into_inner()),
+            ..Self::new(max_rate, dt)
+        }
+    }
+
+    /// Feeds `input` to the limiter and returns the new output, i.e. `input`
+    /// clamped to `previous ± max_rate * dt`.
+    #[inline]
+    pub fn update(&mut self, input: Quantity<S, U>) -> Quantity<S, U>
+    where
+        S: Add<Output = S> + Sub<Output = S> + PartialOrd + Copy,
+    {
+        let x = input.into_inner();
+        let y = match self.value {
+            // Comparing differences instead of `previous ± step` to not underflow unsigned
+            // integers
+            Some(y) if x > y && x - y > self.max_step => y + self.max_step,
+            Some(y) if x < y && y - x > self.max_step => y - self.max_step,
+            _ => x,
+        };
+
+        self.value = Some(y);
+        Quantity::new(y)
+    }
+
+    /// Returns the current output or `None` if there were no inputs yet.
+    #[inline]
+    pub fn value(&self) -> Option<Quantity<S, U>>
+    where
+        S: Copy,
+    {
+        self.value.map(Quantity::new)
+    }
+
+    /// Forgets the current output, the next input will be returned as is.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        finite_difference, trapezoidal_integral, Differentiator, EmaFilter, Integrator, Sample,
+        SlewLimiter,
+    };
+    use crate::{
+        prefixes::Milli,
+        units::{Metre, MetrePerSecond, Second, Volt},
+        IntExt, Quantity,
+    };
+
+    #[test]
+    fn integrator() {
+        let mut it = Integrator::<Quantity<f64, MetrePerSecond>, _>::new();
+        for _ in 0..4 {
+            it.step(2.5.mps(), 0.5.s());
+        }
+        assert_eq!(it.total(), 5.0.m());
+
+        it.reset();
+        assert_eq!(it.total(), 0.0.m());
+
+        let mut it = Integrator::with_initial(1.quantity::<Unit![Metre * Milli<Second>]>());
+        it.step(2.m(), Quantity::<_, Milli<Second>>::new(10));
+        assert_eq!(it.total(), 21.quantity::<Unit![Metre * Milli<Second>]>());
+    }
+
+    #[test]
+    fn differentiator() {
+        let mut it = Differentiator::default();
+        assert_eq!(it.step(1.0.mps(), 0.5.s()), None);
+        assert_eq!(
+            it.step(2.0.mps(), 0.5.s()),
+            Some(2.0.quantity::<Unit![Metre / Second ^ 2]>())
+        );
+        assert_eq!(it.previous(), Some(2.0.mps()));
+
+        it.reset();
+        assert_eq!(it.previous(), None);
+        assert_eq!(it.step(0.0.mps(), 1.0.s()), None);
+    }
+
+    #[test]
+    fn sample() {
+        let samples = [(0, 5), (2, 9), (3, 6)]
+            .iter()
+            .map(|&(t, v)| Sample::from((t.s(), v.v())))
+            .collect::<Vec<_>>();
+
+        let rates = samples
+            .windows(2)
+            .map(|w| w[0].rate(w[1]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rates,
+            [
+                2.quantity::<Unit![Volt / Second]>(),
+                (-3).quantity::<Unit![Volt / Second]>()
+            ]
+        );
+
+        assert_eq!(samples[0].checked_rate(samples[0]), None);
+        assert_eq!(samples[2].into_pair(), (3.s(), 6.v()));
+
+        // The unit of the rate is derived from both units
+        let a = Sample::new(Quantity::<_, Milli<Second>>::new(1500.0), 1.0.m());
+        let b = Sample::new(Quantity::<_, Milli<Second>>::new(2000.0), 2.0.m());
+        assert_eq!(a.rate(b).into_unit::<MetrePerSecond>(), 2.0.mps());
+    }
+
+    #[test]
+    fn finite_difference_and_integral() {
+        let empty: [Sample<Quantity<f64, Second>, Quantity<f64, Metre>>; 0] = [];
+        assert_eq!(finite_difference(empty.iter().copied()).count(), 0);
+        assert_eq!(trapezoidal_integral(empty.iter().copied()), 0.0.quantity());
+
+        let single = [Sample::new(1.s(), 5.v())];
+        assert_eq!(finite_difference(single.iter().copied()).next(), None);
+        assert_eq!(
+            trapezoidal_integral(single.iter().copied()),
+            0.quantity::<Unit![Volt * Second]>()
+        );
+
+        let samples = [
+            Sample::new(0.s(), 1.v()),
+            Sample::new(1.s(), 2.v()),
+            Sample::new(3.s(), 2.v()),
+        ];
+        let rates = finite_difference(samples.iter().copied());
+        assert_eq!(rates.size_hint(), (2, Some(2)));
+        assert_eq!(
+            rates.collect::<Vec<_>>(),
+            [
+                1.quantity::<Unit![Volt / Second]>(),
+                0.quantity::<Unit![Volt / Second]>()
+            ]
+        );
+
+        // 1.5 + 4, truncated only once
+        assert_eq!(
+            trapezoidal_integral(samples.iter().copied()),
+            5.quantity::<Unit![Volt * Second]>()
+        );
+    }
+
+    #[test]
+    fn ema_filter() {
+        // Different units of the time constant and the filtered value
+        let mut filter =
+            EmaFilter::new(Quantity::<_, Milli<Second>>::new(90u32), Quantity::new(10));
+        assert_eq!(filter.value(), None);
+        assert_eq!(filter.update(100.mv()), 100.mv());
+        assert_eq!(filter.update(200.mv()), 110.mv());
+        assert_eq!(filter.update(200.mv()), 119.mv());
+
+        filter.reset();
+        assert_eq!(filter.update(0.mv()), 0.mv());
+
+        let mut filter = EmaFilter::with_initial(1.0.s(), 1.0.s(), 4.0.m());
+        assert_eq!(filter.update(2.0.m()), 3.0.m());
+        assert_eq!(filter.update(3.0.m()), 3.0.m());
+    }
+
+    #[test]
+    fn slew_limiter() {
+        // Unsigned values don't underflow
+        let mut limiter =
+            SlewLimiter::with_initial(2u32.quantity::<Unit![Metre / Second]>(), 3.s(), 5.m());
+        assert_eq!(limiter.update(0.m()), 0.m());
+        assert_eq!(limiter.update(100.m()), 6.m());
+        assert_eq!(limiter.update(0.m()), 0.m());
+        assert_eq!(limiter.value(), Some(0.m()));
+
+        limiter.reset();
+        assert_eq!(limiter.value(), None);
+        assert_eq!(limiter.update(100.m()), 100.m());
+
+        let mut limiter = SlewLimiter::new(0.5.mps(), 1.0.s());
+        assert_eq!(limiter.update(1.0.m()), 1.0.m());
+        assert_eq!(limiter.update(-1.0.m()), 0.5.m());
+    }
+}
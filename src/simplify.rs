@@ -1,11 +1,19 @@
-use core::ops::Div;
+use core::ops::{BitAnd, Div, Sub};
 
-use typenum::{Gcd, Gcf, Quot};
+use typenum::{And, Bit, Gcd, Gcf, Quot, Sub1, UInt, UTerm, B0, B1, U0, U48};
 
-use crate::{fraction::Fraction, Quantity, Unit};
+use crate::{
+    fraction::{Fraction, Pow10Fraction},
+    Quantity, Unit,
+};
 
 /// Simplify fraction.
 ///
+/// Fractions with a numerator or a divisor longer than 48 bits (e.g. ratios of
+/// units with extreme prefixes, like `10^27 / 1` of yotta-kilo) are kept as
+/// is: `typenum`'s division of such numbers exceeds the default recursion
+/// limit.
+///
 /// ## Examples
 /// ```
 /// use typed_phy::{fraction::Fraction, simplify::Simplify};
@@ -23,14 +31,77 @@ pub trait Simplify {
     fn simplify(self) -> Self::Output;
 }
 
+/// Result of simplification of `T`
+pub type Simplified<T> = <T as Simplify>::Output;
+
 impl<N, D> Simplify for Fraction<N, D>
 where
-    N: Gcd<D>,
-    N: Div<Gcf<N, D>>,
-    D: Div<Gcf<N, D>>,
+    N: small::AtMostBits<U48>,
+    D: small::AtMostBits<U48>,
+    small::Fits<N, U48>: BitAnd<small::Fits<D, U48>>,
+    Self: small::SimplifyIf<And<small::Fits<N, U48>, small::Fits<D, U48>>>,
+{
+    type Output =
+        <Self as small::SimplifyIf<And<small::Fits<N, U48>, small::Fits<D, U48>>>>::Output;
+
+    #[inline]
+    fn simplify(self) -> Self::Output {
+        Self::Output::default()
+    }
+}
+
+mod small {
+    use super::*;
+
+    /// `B1` if `Self` has at most `Bits` bits, `B0` otherwise
+    pub trait AtMostBits<Bits> {
+        type Output: Bit;
+    }
+
+    pub type Fits<N, Bits> = <N as AtMostBits<Bits>>::Output;
+
+    impl<Bits> AtMostBits<Bits> for UTerm {
+        type Output = B1;
+    }
+
+    impl<N, B> AtMostBits<U0> for UInt<N, B> {
+        type Output = B0;
+    }
+
+    impl<N, B, C, Bc> AtMostBits<UInt<C, Bc>> for UInt<N, B>
+    where
+        UInt<C, Bc>: Sub<B1>,
+        N: AtMostBits<Sub1<UInt<C, Bc>>>,
+    {
+        type Output = Fits<N, Sub1<UInt<C, Bc>>>;
+    }
+
+    /// Simplifies the fraction if `Cond` is `B1`
+    pub trait SimplifyIf<Cond> {
+        type Output: Default;
+    }
+
+    impl<N, D> SimplifyIf<B0> for Fraction<N, D> {
+        type Output = Self;
+    }
+
+    impl<N, D> SimplifyIf<B1> for Fraction<N, D>
+    where
+        N: Gcd<D>,
+        N: Div<Gcf<N, D>>,
+        D: Div<Gcf<N, D>>,
+    {
+        #[allow(clippy::type_complexity)]
+        type Output = Fraction<Quot<N, Gcf<N, D>>, Quot<D, Gcf<N, D>>>;
+    }
+}
+
+/// Simplifies only the residual, the power of ten is kept separate.
+impl<E, F> Simplify for Pow10Fraction<E, F>
+where
+    F: Simplify,
 {
-    #[allow(clippy::type_complexity)]
-    type Output = Fraction<Quot<N, Gcf<N, D>>, Quot<D, Gcf<N, D>>>;
+    type Output = Pow10Fraction<E, F::Output>;
 
     #[inline]
     fn simplify(self) -> Self::Output {
@@ -58,6 +129,6 @@ where
 
     #[inline]
     fn simplify(self) -> Self::Output {
-        self.set_unit_unchecked()
+        self.reinterpret_unit()
     }
 }
@@ -0,0 +1,490 @@
+use core::{
+    fmt,
+    iter::{Enumerate, FusedIterator},
+    marker::PhantomData,
+    ops::Mul,
+    slice,
+};
+
+use typenum::Prod;
+
+#[cfg(feature = "float-math")]
+use crate::from_int::Sqrt;
+use crate::{
+    from_int::AsPrimitive,
+    range::{QuantityRange, StepStorage},
+    Quantity, UnitTrait,
+};
+
+/// Streaming mean/variance accumulator (Welford's algorithm), doesn't store
+/// the samples.
+///
+/// Implemented only for float storages (`f32`, `f64`): with integer storages
+/// the running mean would be truncated on every sample.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{stats::RunningStats, IntExt};
+///
+/// let mut stats = RunningStats::<f64, _>::new();
+/// for &x in &[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+///     stats.push(x.m());
+/// }
+///
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), Some(5.0.m()));
+/// assert_eq!(stats.variance(), Some(4.0.sqm()));
+/// # #[cfg(feature = "float-math")]
+/// assert_eq!(stats.std_dev(), Some(2.0.m()));
+/// ```
+///
+/// Integer storages aren't supported:
+///
+/// ```compile_fail
+/// use typed_phy::{stats::RunningStats, IntExt};
+///
+/// let mut stats = RunningStats::new();
+/// stats.push(1.m());
+/// ```
+pub struct RunningStats<S, U> {
+    count: u64,
+    mean: S,
+    /// Sum of squared differences from the current mean
+    m2: S,
+    _unit: PhantomData<U>,
+}
+
+macro_rules! running_stats_impls {
+    ($( $F:ty ),+ $(,)?) => {
+        $(
+            impl<U> RunningStats<$F, U>
+            where
+                U: UnitTrait,
+            {
+                /// Creates new accumulator without samples.
+                #[inline]
+                pub fn new() -> Self {
+                    Self {
+                        count: 0,
+                        mean: 0.0,
+                        m2: 0.0,
+                        _unit: PhantomData,
+                    }
+                }
+
+                /// Adds a sample.
+                #[inline]
+                pub fn push(&mut self, value: Quantity<$F, U>) {
+                    let x = value.into_inner();
+                    self.count += 1;
+
+                    let delta = x - self.mean;
+                    self.mean += delta / self.count as $F;
+                    self.m2 += delta * (x - self.mean);
+                }
+
+                /// Returns the number of pushed samples.
+                #[inline]
+                pub fn count(&self) -> u64 {
+                    self.count
+                }
+
+                /// Returns the mean of the samples or `None` if there are no
+                /// samples.
+                #[inline]
+                pub fn mean(&self) -> Option<Quantity<$F, U>> {
+                    if self.count == 0 {
+                        return None;
+                    }
+
+                    Some(Quantity::new(self.mean))
+                }
+
+                /// Returns the (population) variance of the samples or `None`
+                /// if there are no samples.
+                #[inline]
+                pub fn variance(&self) -> Option<Quantity<$F, Prod<U, U>>>
+                where
+                    U: Mul<U>,
+                    Prod<U, U>: UnitTrait,
+                {
+                    if self.count == 0 {
+                        return None;
+                    }
+
+                    Some(Quantity::new(self.m2 / self.count as $F))
+                }
+
+                /// Returns the (population) standard deviation of the samples
+                /// or `None` if there are no samples.
+                ///
+                /// Requires `float-math` feature.
+                #[cfg(feature = "float-math")]
+                #[inline]
+                pub fn std_dev(&self) -> Option<Quantity<$F, U>> {
+                    if self.count == 0 {
+                        return None;
+                    }
+
+                    Some(Quantity::new(Sqrt::sqrt(self.m2 / self.count as $F)))
+                }
+
+                /// Forgets all samples.
+                #[inline]
+                pub fn reset(&mut self) {
+                    *self = Self::new();
+                }
+            }
+
+            impl<U> Default for RunningStats<$F, U>
+            where
+                U: UnitTrait,
+            {
+                #[inline]
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        )+
+    };
+}
+
+running_stats_impls!(f32, f64);
+
+impl<S: Clone, U> Clone for RunningStats<S, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            count: self.count,
+            mean: self.mean.clone(),
+            m2: self.m2.clone(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<S: Copy, U> Copy for RunningStats<S, U> {}
+
+impl<S: fmt::Debug, U> fmt::Debug for RunningStats<S, U> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunningStats")
+            .field("count", &self.count)
+            .field("mean", &self.mean)
+            .field("m2", &self.m2)
+            .finish()
+    }
+}
+
+/// Fixed-bin histogram of quantities, doesn't allocate.
+///
+/// The range is split into `N` bins of the same width starting at the lower
+/// bound: bin `i` is `lo + width * i..lo + width * (i + 1)`. The last bin also
+/// includes its upper bound, so `hi` of a range that is exactly `N` bins wide
+/// is counted in it. Values outside of the range are counted separately (see
+/// [`underflow`] and [`overflow`]), values in the range that fall past the
+/// last bin are counted as overflow too.
+///
+/// ## Examples
+///
+/// ```
+/// use typed_phy::{range::QuantityRange, stats::Histogram, IntExt};
+///
+/// let range = QuantityRange::new(0.mv(), 3000.mv()).unwrap();
+/// let mut hist = Histogram::<_, _, 3>::new(range, 1000.mv());
+///
+/// for &x in &[10, 500, 1500, 2999, 3000, 4000] {
+///     hist.record(x.mv());
+/// }
+///
+/// assert_eq!(hist.counts(), &[2, 1, 2]);
+/// assert_eq!(hist.overflow(), 1);
+///
+/// let (bin, count) = hist.bins().nth(1).unwrap();
+/// assert_eq!((bin.lo(), bin.hi(), count), (1000.mv(), 2000.mv(), 1));
+/// ```
+///
+/// [`underflow`]: Histogram::underflow
+/// [`overflow`]: Histogram::overflow
+pub struct Histogram<S, U, const N: usize> {
+    lo: S,
+    hi: S,
+    width: S,
+    counts: [u32; N],
+    underflow: u32,
+    overflow: u32,
+    _unit: PhantomData<U>,
+}
+
+impl<S, U, const N: usize> Histogram<S, U, N>
+where
+    S: StepStorage,
+    U: UnitTrait,
+{
+    /// Creates new empty histogram that splits `range` into bins of
+    /// `bin_width`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `bin_width` isn't positive or `N` is `0`.
+    #[inline]
+    pub fn new(range: QuantityRange<S, U>, bin_width: Quantity<S, U>) -> Self {
+        let width = bin_width.into_inner();
+        assert!(width > S::zero(), "bin width must be positive");
+        assert!(N > 0, "histogram must have at least one bin");
+
+        Self {
+            lo: range.lo.into_inner(),
+            hi: range.hi.into_inner(),
+            width,
+            counts: [0; N],
+            underflow: 0,
+            overflow: 0,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Adds a sample.
+    ///
+    /// Values that aren't comparable with the bounds of the range (e.g. `NaN`)
+    /// are ignored.
+    #[inline]
+    pub fn record(&mut self, value: Quantity<S, U>)
+    where
+        usize: AsPrimitive<S>,
+    {
+        let x = value.into_inner();
+        if x < self.lo {
+            self.underflow = self.underflow.saturating_add(1);
+        } else if x > self.hi {
+            self.overflow = self.overflow.saturating_add(1);
+        } else if x >= self.lo {
+            let bin = x.checked_distance(self.lo).and_then(|d| {
+                let i: usize = (d / self.width).as_();
+                match i {
+                    i if i < N => Some(i),
+                    // upper bound of the last bin
+                    i if i == N && d == self.width * N.as_() => Some(N - 1),
+                    _ => None,
+                }
+            });
+
+            let count = match bin {
+                Some(i) => &mut self.counts[i],
+                // `x - lo` doesn't fit into `S`, so it's past the last bin too
+                None => &mut self.overflow,
+            };
+            *count = count.saturating_add(1);
+        }
+    }
+
+    /// Returns the number of samples in every bin.
+    #[inline]
+    pub fn counts(&self) -> &[u32; N] {
+        &self.counts
+    }
+
+    /// Returns the number of samples below the range.
+    #[inline]
+    pub fn underflow(&self) -> u32 {
+        self.underflow
+    }
+
+    /// Returns the number of samples above the range.
+    #[inline]
+    pub fn overflow(&self) -> u32 {
+        self.overflow
+    }
+
+    /// Returns the number of recorded samples (including the ones outside of
+    /// the range).
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.counts.iter().map(|&c| u64::from(c)).sum::<u64>()
+            + u64::from(self.underflow)
+            + u64::from(self.overflow)
+    }
+
+    /// Returns iterator over bins (their bounds) and the number of samples in
+    /// them.
+    #[inline]
+    pub fn bins(&self) -> Bins<'_, S, U>
+    where
+        usize: AsPrimitive<S>,
+    {
+        Bins {
+            lo: self.lo,
+            width: self.width,
+            counts: self.counts.iter().enumerate(),
+            _unit: PhantomData,
+        }
+    }
+
+    /// Forgets all samples.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.counts = [0; N];
+        self.underflow = 0;
+        self.overflow = 0;
+    }
+}
+
+/// Iterator returned by [`Histogram::bins`].
+pub struct Bins<'a, S, U> {
+    lo: S,
+    width: S,
+    counts: Enumerate<slice::Iter<'a, u32>>,
+    _unit: PhantomData<U>,
+}
+
+impl<S, U> Iterator for Bins<'_, S, U>
+where
+    S: StepStorage,
+    usize: AsPrimitive<S>,
+{
+    type Item = (QuantityRange<S, U>, u32);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, &count) = self.counts.next()?;
+        let lo = self.lo + self.width * i.as_();
+        let bin = QuantityRange {
+            lo: Quantity::new(lo),
+            hi: Quantity::new(lo + self.width),
+        };
+
+        Some((bin, count))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.counts.size_hint()
+    }
+}
+
+impl<S, U> ExactSizeIterator for Bins<'_, S, U>
+where
+    S: StepStorage,
+    usize: AsPrimitive<S>,
+{
+}
+
+impl<S, U> FusedIterator for Bins<'_, S, U>
+where
+    S: StepStorage,
+    usize: AsPrimitive<S>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Histogram, RunningStats};
+    use crate::{
+        range::QuantityRange,
+        units::{Metre, Second},
+        IntExt,
+    };
+
+    #[test]
+    fn empty() {
+        let stats = RunningStats::<f64, Metre>::default();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.variance(), None);
+        #[cfg(feature = "float-math")]
+        assert_eq!(stats.std_dev(), None);
+
+        let copy = stats;
+        assert_eq!(copy.clone().count(), 0);
+        assert_eq!(
+            format!("{:?}", stats),
+            "RunningStats { count: 0, mean: 0.0, m2: 0.0 }"
+        );
+    }
+
+    #[test]
+    fn welford() {
+        let mut stats = RunningStats::<f32, _>::new();
+        stats.push(3.0.s());
+        assert_eq!(stats.mean(), Some(3.0.s()));
+        assert_eq!(stats.variance(), Some(0.0.quantity::<Unit![Second ^ 2]>()));
+
+        stats.push(5.0.s());
+        stats.push(7.0.s());
+        assert_eq!(stats.count(), 3);
+        assert_eq!(stats.mean(), Some(5.0.s()));
+        #[cfg(feature = "float-math")]
+        assert_eq!(stats.std_dev(), Some(1.632_993_2.s()));
+
+        stats.reset();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), None);
+    }
+
+    #[test]
+    fn large_offset() {
+        // Naive `sum(x^2) - sum(x)^2` would lose all precision here
+        let mut stats = RunningStats::<f64, _>::new();
+        for &x in &[1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0] {
+            stats.push(x.m());
+        }
+        assert_eq!(stats.mean(), Some((1e9 + 10.0).m()));
+        assert_eq!(stats.variance(), Some(22.5.sqm()));
+    }
+
+    #[test]
+    fn histogram() {
+        let range = QuantityRange::new((-1.0).m(), 1.0.m()).unwrap();
+        let mut hist = Histogram::<f64, Metre, 4>::new(range, 0.5.m());
+        for &x in &[-2.0, -1.0, -0.1, 0.0, 0.2, 0.75, 1.0, f64::NAN, 5.0] {
+            hist.record(x.m());
+        }
+
+        assert_eq!(hist.counts(), &[1, 1, 2, 2]);
+        assert_eq!(hist.underflow(), 1);
+        assert_eq!(hist.overflow(), 1);
+        assert_eq!(hist.total(), 8);
+
+        let bounds: Vec<_> = hist.bins().map(|(bin, _)| (bin.lo(), bin.hi())).collect();
+        assert_eq!(
+            bounds,
+            [
+                ((-1.0).m(), (-0.5).m()),
+                ((-0.5).m(), 0.0.m()),
+                (0.0.m(), 0.5.m()),
+                (0.5.m(), 1.0.m()),
+            ]
+        );
+
+        hist.reset();
+        assert_eq!(hist.total(), 0);
+    }
+
+    #[test]
+    fn histogram_past_last_bin() {
+        let range = QuantityRange::new(0.s(), 3001.s()).unwrap();
+        let mut hist = Histogram::<i32, Second, 3>::new(range, 1000.s());
+        for &x in &[0, 2999, 3000, 3001] {
+            hist.record(x.s());
+        }
+        assert_eq!(hist.counts(), &[1, 0, 2]);
+        assert_eq!(hist.overflow(), 1);
+
+        let range = QuantityRange::new((-100).m(), 100.m()).unwrap();
+        let mut hist = Histogram::<i8, Metre, 2>::new(range, 50.m());
+        // `100 - (-100)` doesn't fit into `i8`
+        for &x in &[-100, -51, -50, 0, 99, 100] {
+            hist.record(x.m());
+        }
+        assert_eq!(hist.counts(), &[2, 2]);
+        assert_eq!(hist.overflow(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "bin width must be positive")]
+    fn histogram_zero_width() {
+        let range = QuantityRange::new(0.s(), 10.s()).unwrap();
+        let _ = Histogram::<i32, Second, 2>::new(range, 0.s());
+    }
+}
@@ -0,0 +1,325 @@
+//! Storage arithmetic behind the operators (`+`, `-`, `*`, `/`) of
+//! [`Quantity`](crate::Quantity).
+//!
+//! The traits of this module are sealed, they are implemented for every
+//! storage that supports the operators. Without the `strict` feature they are
+//! just the plain operators of the storage (the corresponding [`core::ops`]
+//! traits).
+//!
+//! With the `strict` feature operators of quantities are routed through the
+//! [`checked`](crate::checked) traits, so integer operations panic on
+//! overflow (in both debug and release builds) with a message that includes
+//! the units of the operands:
+//!
+//! ```should_panic
+//! # #[cfg(feature = "strict")] {
+//! use typed_phy::{prefixes::Kilo, units::Metre, Quantity};
+//!
+//! let max = Quantity::<u8, Kilo<Metre>>::new(u8::MAX);
+//! // Panics with "attempt to add `km` quantities with overflow"
+//! let _ = max + Quantity::<_, Kilo<Metre>>::new(1);
+//! # }
+//! # #[cfg(not(feature = "strict"))] panic!();
+//! ```
+//!
+//! Floats (and complex numbers) can't overflow, so they still use the plain
+//! operators. Custom storages need to implement the [`checked`] traits to
+//! support the operators with the `strict` feature.
+//!
+//! Note: only operators between 2 quantities are affected. Operators with
+//! scalars (e.g. `q * 2`) and compound assignment (`+=`, `-=`, ...) always use
+//! the plain operators of the storage.
+//!
+//! Generic code needs the traits of this module in bounds (e.g.
+//! `S: StorageAdd<U, Output = S>` instead of `S: Add<Output = S>`) to compile
+//! with the `strict` feature.
+//!
+//! [`checked`]: crate::checked
+
+/// Addition of storages of quantities of unit `U`.
+pub trait StorageAdd<U, Rhs = Self>: sealed::StorageAdd<U, Rhs> {}
+
+impl<S: sealed::StorageAdd<U, Rhs>, U, Rhs> StorageAdd<U, Rhs> for S {}
+
+/// Subtraction of storages of quantities of unit `U`.
+pub trait StorageSub<U, Rhs = Self>: sealed::StorageSub<U, Rhs> {}
+
+impl<S: sealed::StorageSub<U, Rhs>, U, Rhs> StorageSub<U, Rhs> for S {}
+
+/// Multiplication of storages of quantities of units `U0` and `U1`.
+pub trait StorageMul<U0, U1, Rhs = Self>: sealed::StorageMul<U0, U1, Rhs> {}
+
+impl<S: sealed::StorageMul<U0, U1, Rhs>, U0, U1, Rhs> StorageMul<U0, U1, Rhs> for S {}
+
+/// Division of storages of quantities of units `U0` and `U1`.
+pub trait StorageDiv<U0, U1, Rhs = Self>: sealed::StorageDiv<U0, U1, Rhs> {}
+
+impl<S: sealed::StorageDiv<U0, U1, Rhs>, U0, U1, Rhs> StorageDiv<U0, U1, Rhs> for S {}
+
+mod sealed {
+    pub trait StorageAdd<U, Rhs>: Sized {
+        /// The resulting type after addition
+        type Output;
+
+        /// Adds two storages (see the [module docs](super))
+        fn storage_add(self, rhs: Rhs) -> Self::Output;
+    }
+
+    pub trait StorageSub<U, Rhs>: Sized {
+        /// The resulting type after subtraction
+        type Output;
+
+        /// Subtracts two storages (see the [module docs](super))
+        fn storage_sub(self, rhs: Rhs) -> Self::Output;
+    }
+
+    pub trait StorageMul<U0, U1, Rhs>: Sized {
+        /// The resulting type after multiplication
+        type Output;
+
+        /// Multiplies two storages (see the [module docs](super))
+        fn storage_mul(self, rhs: Rhs) -> Self::Output;
+    }
+
+    pub trait StorageDiv<U0, U1, Rhs>: Sized {
+        /// The resulting type after division
+        type Output;
+
+        /// Divides two storages (see the [module docs](super))
+        fn storage_div(self, rhs: Rhs) -> Self::Output;
+    }
+
+    #[cfg(not(feature = "strict"))]
+    mod plain {
+        use core::ops::{Add, Div, Mul, Sub};
+
+        impl<S: Add<Rhs>, Rhs, U> super::StorageAdd<U, Rhs> for S {
+            type Output = S::Output;
+
+            #[inline]
+            fn storage_add(self, rhs: Rhs) -> S::Output {
+                self + rhs
+            }
+        }
+
+        impl<S: Sub<Rhs>, Rhs, U> super::StorageSub<U, Rhs> for S {
+            type Output = S::Output;
+
+            #[inline]
+            fn storage_sub(self, rhs: Rhs) -> S::Output {
+                self - rhs
+            }
+        }
+
+        impl<S: Mul<Rhs>, Rhs, U0, U1> super::StorageMul<U0, U1, Rhs> for S {
+            type Output = S::Output;
+
+            #[inline]
+            fn storage_mul(self, rhs: Rhs) -> S::Output {
+                self * rhs
+            }
+        }
+
+        impl<S: Div<Rhs>, Rhs, U0, U1> super::StorageDiv<U0, U1, Rhs> for S {
+            type Output = S::Output;
+
+            #[inline]
+            fn storage_div(self, rhs: Rhs) -> S::Output {
+                self / rhs
+            }
+        }
+    }
+
+    #[cfg(feature = "strict")]
+    mod checked {
+        use core::fmt;
+
+        use crate::{
+            checked::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub},
+            rt::RtUnit,
+            UnitTrait,
+        };
+
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn overflow(args: fmt::Arguments<'_>) -> ! {
+            panic!("{}", args)
+        }
+
+        impl<S: CheckedAdd<Rhs>, Rhs, U: UnitTrait> super::StorageAdd<U, Rhs> for S {
+            type Output = S::Output;
+
+            #[inline]
+            #[track_caller]
+            fn storage_add(self, rhs: Rhs) -> S::Output {
+                match self.checked_add(rhs) {
+                    Some(res) => res,
+                    None => overflow(format_args!(
+                        "attempt to add `{}` quantities with overflow",
+                        RtUnit::of::<U>()
+                    )),
+                }
+            }
+        }
+
+        impl<S: CheckedSub<Rhs>, Rhs, U: UnitTrait> super::StorageSub<U, Rhs> for S {
+            type Output = S::Output;
+
+            #[inline]
+            #[track_caller]
+            fn storage_sub(self, rhs: Rhs) -> S::Output {
+                match self.checked_sub(rhs) {
+                    Some(res) => res,
+                    None => overflow(format_args!(
+                        "attempt to subtract `{}` quantities with overflow",
+                        RtUnit::of::<U>()
+                    )),
+                }
+            }
+        }
+
+        impl<S: CheckedMul<Rhs>, Rhs, U0: UnitTrait, U1: UnitTrait> super::StorageMul<U0, U1, Rhs> for S {
+            type Output = S::Output;
+
+            #[inline]
+            #[track_caller]
+            fn storage_mul(self, rhs: Rhs) -> S::Output {
+                match self.checked_mul(rhs) {
+                    Some(res) => res,
+                    None => overflow(format_args!(
+                        "attempt to multiply `{}` by `{}` with overflow",
+                        RtUnit::of::<U0>(),
+                        RtUnit::of::<U1>(),
+                    )),
+                }
+            }
+        }
+
+        impl<S: CheckedDiv<Rhs>, Rhs, U0: UnitTrait, U1: UnitTrait> super::StorageDiv<U0, U1, Rhs> for S {
+            type Output = S::Output;
+
+            #[inline]
+            #[track_caller]
+            fn storage_div(self, rhs: Rhs) -> S::Output {
+                match self.checked_div(rhs) {
+                    Some(res) => res,
+                    None => overflow(format_args!(
+                        "attempt to divide `{}` by `{}` with overflow or by zero",
+                        RtUnit::of::<U0>(),
+                        RtUnit::of::<U1>(),
+                    )),
+                }
+            }
+        }
+
+        // Storages those can't overflow use the plain operators (floats implement
+        // `CheckedAdd`/`CheckedSub` that always succeed)
+        macro_rules! plain_impls {
+            (@add_sub [$( $gen:ident ),*] $S:ty) => {
+                impl<$( $gen, )* U> super::StorageAdd<U, $S> for $S
+                where
+                    $S: core::ops::Add<Output = $S>,
+                {
+                    type Output = $S;
+
+                    #[inline]
+                    fn storage_add(self, rhs: $S) -> $S {
+                        self + rhs
+                    }
+                }
+
+                impl<$( $gen, )* U> super::StorageSub<U, $S> for $S
+                where
+                    $S: core::ops::Sub<Output = $S>,
+                {
+                    type Output = $S;
+
+                    #[inline]
+                    fn storage_sub(self, rhs: $S) -> $S {
+                        self - rhs
+                    }
+                }
+            };
+            ([$( $gen:ident ),*] $S:ty) => {
+                impl<$( $gen, )* U0, U1> super::StorageMul<U0, U1, $S> for $S
+                where
+                    $S: core::ops::Mul<Output = $S>,
+                {
+                    type Output = $S;
+
+                    #[inline]
+                    fn storage_mul(self, rhs: $S) -> $S {
+                        self * rhs
+                    }
+                }
+
+                impl<$( $gen, )* U0, U1> super::StorageDiv<U0, U1, $S> for $S
+                where
+                    $S: core::ops::Div<Output = $S>,
+                {
+                    type Output = $S;
+
+                    #[inline]
+                    fn storage_div(self, rhs: $S) -> $S {
+                        self / rhs
+                    }
+                }
+            };
+        }
+
+        plain_impls!([] f32);
+        plain_impls!([] f64);
+        #[cfg(feature = "complex")]
+        plain_impls!(@add_sub [T] num_complex::Complex<T>);
+        #[cfg(feature = "complex")]
+        plain_impls!([T] num_complex::Complex<T>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntExt;
+    #[cfg(feature = "strict")]
+    use crate::{
+        prefixes::Kilo,
+        units::{Metre, Second},
+        Quantity,
+    };
+
+    #[test]
+    #[cfg(feature = "strict")]
+    #[should_panic(expected = "attempt to add `km` quantities with overflow")]
+    fn add_overflow() {
+        let _ = Quantity::<u8, Kilo<Metre>>::new(u8::MAX) + Quantity::<u8, Kilo<Metre>>::new(1);
+    }
+
+    #[test]
+    #[cfg(feature = "strict")]
+    #[should_panic(expected = "attempt to subtract `m` quantities with overflow")]
+    fn sub_overflow() {
+        let _ = 1u32.m() - 2u32.m();
+    }
+
+    #[test]
+    #[cfg(feature = "strict")]
+    #[should_panic(expected = "attempt to multiply `m` by `s` with overflow")]
+    fn mul_overflow() {
+        let _ = Quantity::<i32, Metre>::new(i32::MAX) * Quantity::<i32, Second>::new(2);
+    }
+
+    #[test]
+    #[cfg(feature = "strict")]
+    #[should_panic(expected = "attempt to divide `m` by `s` with overflow or by zero")]
+    fn div_by_zero() {
+        let _ = 1.m() / 0.s();
+    }
+
+    #[test]
+    fn no_overflow() {
+        assert_eq!(1.m() + 2.m() - 1.m(), 2.m());
+        assert_eq!(6.m() / 2.s() * 1.s(), 3.m());
+        assert_eq!(200u8.m() + 55u8.m(), 255u8.m());
+        assert_eq!(1.5.m() + f64::MAX.m(), f64::MAX.m());
+    }
+}
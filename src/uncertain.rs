@@ -0,0 +1,257 @@
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use typenum::{Prod, Quot};
+
+use crate::{
+    from_int::{Sqrt, Zero},
+    strict::{StorageAdd, StorageSub},
+    Quantity, UnitTrait,
+};
+
+/// Measured value with 1-sigma uncertainty (`value ± error`) in the same
+/// unit.
+///
+/// Uncertainty is propagated through `+ - * /` using first-order
+/// (linear) approximation and assuming that errors are independent:
+///
+/// - `a ± b`: `σ = sqrt(σa² + σb²)`
+/// - `a * b`: `σ = sqrt((b σa)² + (a σb)²)`
+/// - `a / b`: `σ = sqrt((σa / b)² + (a σb / b²)²)`
+///
+/// The operators need [`Sqrt`], which is implemented for float storages with
+/// `float-math` feature.
+///
+/// ## Examples
+///
+/// ```
+/// # #[cfg(feature = "float-math")] {
+/// use typed_phy::{uncertain::Uncertain, IntExt};
+///
+/// let distance = Uncertain::new(100.0.m(), 3.0.m());
+/// let time = Uncertain::new(10.0.s(), 0.4.s());
+///
+/// let speed = distance / time;
+/// assert_eq!(speed.value(), 10.0.mps());
+/// assert_eq!(speed.error(), 0.5.mps());
+/// # }
+/// ```
+pub struct Uncertain<S, U> {
+    value: Quantity<S, U>,
+    error: Quantity<S, U>,
+}
+
+impl<S, U> Uncertain<S, U>
+where
+    U: UnitTrait,
+{
+    /// Creates new uncertain quantity from central value and 1-sigma
+    /// uncertainty.
+    #[inline]
+    pub fn new(value: Quantity<S, U>, error: Quantity<S, U>) -> Self {
+        Self { value, error }
+    }
+
+    /// Creates new quantity without uncertainty.
+    #[inline]
+    pub fn exact(value: Quantity<S, U>) -> Self
+    where
+        S: Zero,
+    {
+        Self::new(value, Quantity::new(S::zero()))
+    }
+
+    /// Returns the central value.
+    #[inline]
+    pub fn value(&self) -> Quantity<S, U>
+    where
+        S: Copy,
+    {
+        self.value
+    }
+
+    /// Returns the 1-sigma uncertainty.
+    #[inline]
+    pub fn error(&self) -> Quantity<S, U>
+    where
+        S: Copy,
+    {
+        self.error
+    }
+
+    /// Returns `error / value` (relative uncertainty).
+    #[inline]
+    pub fn relative_error(&self) -> S
+    where
+        S: Div<Output = S> + Copy,
+    {
+        self.error.into_inner() / self.value.into_inner()
+    }
+}
+
+/// `sqrt(a² + b²)`
+#[inline]
+fn hypot<S>(a: S, b: S) -> S
+where
+    S: Add<Output = S> + Mul<Output = S> + Sqrt + Copy,
+{
+    (a * a + b * b).sqrt()
+}
+
+impl<S, U> Add for Uncertain<S, U>
+where
+    S: Add<Output = S> + StorageAdd<U, Output = S> + Mul<Output = S> + Sqrt + Copy,
+    U: UnitTrait,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value + rhs.value,
+            Quantity::new(hypot(self.error.into_inner(), rhs.error.into_inner())),
+        )
+    }
+}
+
+impl<S, U> Sub for Uncertain<S, U>
+where
+    S: Add<Output = S> + StorageSub<U, Output = S> + Mul<Output = S> + Sqrt + Copy,
+    U: UnitTrait,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value - rhs.value,
+            Quantity::new(hypot(self.error.into_inner(), rhs.error.into_inner())),
+        )
+    }
+}
+
+impl<S, U0, U1> Mul<Uncertain<S, U1>> for Uncertain<S, U0>
+where
+    S: Add<Output = S> + Mul<Output = S> + Sqrt + Copy,
+    U0: UnitTrait + Mul<U1>,
+    U1: UnitTrait,
+    Prod<U0, U1>: UnitTrait,
+{
+    type Output = Uncertain<S, Prod<U0, U1>>;
+
+    #[inline]
+    fn mul(self, rhs: Uncertain<S, U1>) -> Self::Output {
+        let (a, sa) = (self.value.into_inner(), self.error.into_inner());
+        let (b, sb) = (rhs.value.into_inner(), rhs.error.into_inner());
+
+        Uncertain::new(Quantity::new(a * b), Quantity::new(hypot(b * sa, a * sb)))
+    }
+}
+
+impl<S, U0, U1> Div<Uncertain<S, U1>> for Uncertain<S, U0>
+where
+    S: Add<Output = S> + Mul<Output = S> + Div<Output = S> + Sqrt + Copy,
+    U0: UnitTrait + Div<U1>,
+    U1: UnitTrait,
+    Quot<U0, U1>: UnitTrait,
+{
+    type Output = Uncertain<S, Quot<U0, U1>>;
+
+    #[inline]
+    fn div(self, rhs: Uncertain<S, U1>) -> Self::Output {
+        let (a, sa) = (self.value.into_inner(), self.error.into_inner());
+        let (b, sb) = (rhs.value.into_inner(), rhs.error.into_inner());
+
+        Uncertain::new(
+            Quantity::new(a / b),
+            Quantity::new(hypot(sa / b, a * sb / (b * b))),
+        )
+    }
+}
+
+impl<S: Clone, U> Clone for Uncertain<S, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            error: self.error.clone(),
+        }
+    }
+}
+
+impl<S: Copy, U> Copy for Uncertain<S, U> {}
+
+impl<S: PartialEq, U> PartialEq for Uncertain<S, U> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.error == other.error
+    }
+}
+
+impl<S, U> fmt::Debug for Uncertain<S, U>
+where
+    S: fmt::Debug,
+    U: fmt::Debug + Default,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} ± {:?}", self.value, self.error)
+    }
+}
+
+impl<S, U> fmt::Display for Uncertain<S, U>
+where
+    S: fmt::Display,
+    U: fmt::Display + Default,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ± {}", self.value, self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Uncertain;
+    use crate::IntExt;
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn add_sub() {
+        let a = Uncertain::new(10.0.m(), 3.0.m());
+        let b = Uncertain::new(5.0.m(), 4.0.m());
+
+        assert_eq!(a + b, Uncertain::new(15.0.m(), 5.0.m()));
+        assert_eq!(a - b, Uncertain::new(5.0.m(), 5.0.m()));
+        assert_eq!(
+            a + Uncertain::exact(1.0.m()),
+            Uncertain::new(11.0.m(), 3.0.m())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "float-math")]
+    fn mul_div() {
+        let a = Uncertain::new(3.0f64.m(), 0.3.m());
+        let b = Uncertain::new(4.0.m(), 0.0.m());
+
+        let area = a * b;
+        assert_eq!(area.value(), 12.0.sqm());
+        assert!((area.error().into_inner() - 1.2).abs() < 1e-12);
+        assert!((area.relative_error() - 0.1).abs() < 1e-12);
+
+        let v = Uncertain::exact(10.0f64.m()) / Uncertain::new(2.0.s(), 0.1.s());
+        assert_eq!(v.value(), 5.0.mps());
+        assert!((v.error().into_inner() - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Uncertain::new(1.5.m(), 0.25.m()).to_string(),
+            "1.5 m ± 0.25 m"
+        );
+    }
+}
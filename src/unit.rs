@@ -5,11 +5,12 @@ use core::{
 
 use crate::{
     fraction::{FractionTrait, One},
-    rt::{RtDimensions, RtUnit, UnitRtExt},
+    rt::{FractionRtExt, RtDimensions, RtUnit, UnitRtExt},
+    simplify::{Simplified, Simplify},
     units::*,
-    DimensionsTrait,
+    DimensionsTrait, Quantity,
 };
-use typenum::{Prod, Quot};
+use typenum::{Exp, Integer, Pow, Prod, Quot};
 
 /// Trait implemented for [`Unit`].
 /// Mostly needed to simplify bound and write
@@ -33,11 +34,41 @@ use typenum::{Prod, Quot};
 ///
 /// [`Unit`]: struct@Unit
 pub trait UnitTrait {
-    ///
+    /// Dimensions
     type Dimensions: DimensionsTrait;
 
     /// Ratio
     type Ratio: FractionTrait;
+
+    /// The numerator of the (reduced) ratio as a runtime value (e.g. `1000`
+    /// for `Kilo<Metre>`)
+    const RATIO_NUM: u128 = <Self::Ratio as FractionRtExt>::RT.reduced().numerator;
+
+    /// The divisor of the (reduced) ratio as a runtime value (e.g. `3600` for
+    /// `Hour`)
+    const RATIO_DEN: u128 = <Self::Ratio as FractionRtExt>::RT.reduced().divisor;
+
+    /// Exponent of length (metre)
+    const LENGTH: i8 = <Self::Dimensions as DimensionsTrait>::Length::I8;
+
+    /// Exponent of mass (kilogram)
+    const MASS: i8 = <Self::Dimensions as DimensionsTrait>::Mass::I8;
+
+    /// Exponent of time (second)
+    const TIME: i8 = <Self::Dimensions as DimensionsTrait>::Time::I8;
+
+    /// Exponent of electric current (ampere)
+    const ELECTRIC_CURRENT: i8 = <Self::Dimensions as DimensionsTrait>::ElectricCurrent::I8;
+
+    /// Exponent of thermodynamic temperature (kelvin)
+    const THERMODYNAMIC_TEMPERATURE: i8 =
+        <Self::Dimensions as DimensionsTrait>::ThermodynamicTemperature::I8;
+
+    /// Exponent of amount of substance (mole)
+    const AMOUNT_OF_SUBSTANCE: i8 = <Self::Dimensions as DimensionsTrait>::AmountOfSubstance::I8;
+
+    /// Exponent of luminous intensity (candela)
+    const LUMINOUS_INTENSITY: i8 = <Self::Dimensions as DimensionsTrait>::LuminousIntensity::I8;
 }
 
 impl<D: DimensionsTrait, R: FractionTrait> UnitTrait for Unit<D, R> {
@@ -113,6 +144,30 @@ impl<D, R> Unit<D, R> {
     pub const fn new() -> Self {
         Self(phantasm::Invariant)
     }
+
+    /// Creates quantity of `value` in this unit, same as [`Quantity::new`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{
+    ///     prefixes::Kilo,
+    ///     units::{Metre, Watt},
+    ///     IntExt, Quantity,
+    /// };
+    ///
+    /// assert_eq!(Metre::of(5), 5.m());
+    /// assert_eq!(Kilo::<Watt>::of(2.5), Quantity::<_, Kilo<Watt>>::new(2.5));
+    ///
+    /// const LIMIT: Quantity<u32, Metre> = Metre::of(100);
+    /// assert_eq!(LIMIT, 100.m());
+    /// ```
+    ///
+    /// [`Quantity::new`]: crate::Quantity::new
+    #[inline]
+    pub const fn of<S>(value: S) -> Quantity<S, Self> {
+        Quantity::new(value)
+    }
 }
 
 impl<D, R> Default for Unit<D, R> {
@@ -144,68 +199,206 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match try_get_simple_name::<D, R>() {
-            Some(str) => f.write_str(str),
-            None => {
-                let RtUnit {
-                    dimensions:
-                        RtDimensions {
-                            length,
-                            mass,
-                            time,
-                            electric_current,
-                            thermodynamic_temperature,
-                            amount_of_substance,
-                            luminous_intensity,
-                        },
-                    ratio: _,
-                } = Self::RT;
-                let mut first = true;
-
-                macro_rules! push {
-                    ($first:ident, $i:ident, $d:expr) => {
-                        if $first {
-                            match $i {
-                                0 => {},
-                                1 => {
-                                    f.write_str($d)?;
-                                    $first = false;
-                                },
-                                exp => {
-                                    f.write_fmt(format_args!("{}^{}", $d, exp))?;
-                                    $first = false;
-                                },
-                            }
-                        } else {
-                            match $i {
-                                0 => {},
-                                1 => f.write_fmt(format_args!(" * {}", $d))?,
-                                exp => f.write_fmt(format_args!(" * {}^{}", $d, exp))?,
-                            }
-                        }
-                    };
-                }
-
-                push!(first, length, "m");
-                push!(first, mass, "kg");
-                push!(first, time, "s");
-                push!(first, electric_current, "A");
-                push!(first, thermodynamic_temperature, "K");
-                push!(first, amount_of_substance, "mol");
-                push!(first, luminous_intensity, "cd");
-
-                if first {
-                    f.write_fmt(format_args!("(ratio: {:#})", R::default()))?;
+        match (
+            try_get_simple_name::<D, R>(),
+            try_get_prefixed_power(&Self::RT),
+        ) {
+            (Some(str), _) => f.write_str(str),
+            (None, Some((prefix, symbol, 1))) => f.write_fmt(format_args!("{}{}", prefix, symbol)),
+            (None, Some((prefix, symbol, exp))) => {
+                f.write_fmt(format_args!("{}{}^{}", prefix, symbol, exp))
+            },
+            (None, None) => {
+                let sep = if Self::RT.dimensions.fmt_exponents(f)? {
+                    " "
                 } else {
-                    f.write_fmt(format_args!(" (ratio: {:#})", R::default()))?;
-                }
-
-                Ok(())
+                    ""
+                };
+                f.write_fmt(format_args!("{}(ratio: {:#})", sep, R::default()))
             },
         }
     }
 }
 
+/// Tries to represent unit with exactly one non-zero exponent as a prefixed
+/// base unit raised to that exponent (e.g. `km^2` or `cm^3`). Returns prefix,
+/// symbol of the base unit and the exponent.
+pub(crate) fn try_get_prefixed_power(rt: &RtUnit) -> Option<(&'static str, &'static str, i8)> {
+    let RtDimensions {
+        length,
+        mass,
+        time,
+        electric_current,
+        thermodynamic_temperature,
+        amount_of_substance,
+        luminous_intensity,
+    } = rt.dimensions;
+
+    let exponents = [
+        (length, "m"),
+        (mass, "g"),
+        (time, "s"),
+        (electric_current, "A"),
+        (thermodynamic_temperature, "K"),
+        (amount_of_substance, "mol"),
+        (luminous_intensity, "cd"),
+    ];
+    let mut non_zero = exponents.iter().copied().filter(|&(exp, _)| exp != 0);
+
+    let (exp, symbol) = non_zero.next()?;
+    if non_zero.next().is_some() {
+        return None;
+    }
+
+    let mut pow10 = rt.ratio.pow10()?;
+    if symbol == "g" {
+        // The base unit is kg (kilogram), but we count ratio from gram
+        pow10 += 3 * i32::from(exp);
+    }
+
+    if pow10 % i32::from(exp) != 0 {
+        return None;
+    }
+
+    let prefix = crate::prefixes::symbol(pow10 / i32::from(exp))?;
+    Some((prefix, symbol, exp))
+}
+
+/// Calls `$callback` with lists of units that have names.
+///
+/// This is the single source of truth for unit names, it's used both for
+/// displaying and for parsing units.
+macro_rules! unit_names {
+    ($callback:ident) => {
+        $callback! {
+            // by "simple" I mean "units those have name and can be concatenated
+            // with prefixes (milli/micro/kilo/etc)"
+            simple {
+                // Base units
+                Metre => "m",
+                // No kg (see below)
+                Second => "s",
+                Ampere => "A",
+                Kelvin => "K",
+                Mole => "mol",
+                Candela => "cd",
+
+                // The base unit is kg (kilogram), but when we are
+                // writing we want to count ratio from gram
+                Gram => "g",
+
+                // Derived units
+                // (No Radian/Steradian as they are dimensionless)
+                Hertz => "Hz",
+                Newton => "N",
+                Pascal => "Pa",
+                Joule => "J",
+                Watt => "W",
+                Coulomb => "C",
+                Volt => "V",
+                Ohm => "Ω",
+                Farad => "F",
+
+                // Non-SI
+                WattHour => "Wh",
+                AmpereHour => "Ah",
+            }
+            coherent {
+                // milli dimensionless (mdimless) and co. is something very strange :D
+                Dimensionless => "dimless",
+                Percent => "%",
+
+                // Coherent derived units
+                SquareMetre => "m^2",
+                CubicMetre => "m^3",
+                MetrePerSecond => "m/s",
+                MetrePerSecondSquared => "m/s^2",
+                JoulePerKelvin => "J/K",
+                WattPerSquareMetre => "W/m^2",
+                WattPerMetreKelvin => "W/(m·K)",
+                JoulePerKiloGramKelvin => "J/(kg·K)",
+                KelvinPerWatt => "K/W",
+                MolePerCubicMetre => "mol/m^3",
+                MolePerKiloGram => "mol/kg",
+                LumenSecond => "lm·s",
+                LuxSecond => "lx·s",
+                CandelaPerSquareMetre => "cd/m^2",
+                PascalSecond => "Pa·s",
+                CubicMetrePerSecond => "m^3/s",
+                VoltPerMetre => "V/m",
+                CoulombPerSquareMetre => "C/m^2",
+                CoulombPerCubicMetre => "C/m^3",
+                FaradPerMetre => "F/m",
+                WattPerSquareMetrePerHertz => "W/(m^2·Hz)",
+
+                // Non-SI
+                Minute => "min",
+                Hour => "h",
+                Day => "d",
+                Week => "wk",
+                JulianYear => "a",
+                KiloMetrePerHour => "km/h",
+                Knot => "kn",
+                MilePerHour => "mph",
+                NauticalMile => "nmi",
+                Angstrom => "Å",
+                LightYear => "ly",
+                Parsec => "pc",
+                Tonne => "t",
+                Carat => "ct",
+                // Not in `simple`: ratios of prefixed daltons overflow
+                Dalton => "Da",
+                Degree => "°",
+                RevolutionsPerMinute => "rpm",
+                Litre => "L",
+                MolePerLitre => "mol/L",
+                WattHourPerKiloGram => "Wh/kg",
+                LitrePerMinute => "L/min",
+                LitrePerHundredKiloMetre => "L/100km",
+                SievertPerHour => "Sv/h",
+                crate::prefixes::Milli<SievertPerHour> => "mSv/h",
+                crate::prefixes::Micro<SievertPerHour> => "μSv/h",
+                crate::prefixes::Nano<SievertPerHour> => "nSv/h",
+                // Not in `simple`: ratios of some prefixed janskys overflow
+                Jansky => "Jy",
+                crate::prefixes::Milli<Jansky> => "mJy",
+                crate::prefixes::Micro<Jansky> => "μJy",
+                Bar => "bar",
+                Atmosphere => "atm",
+                Torr => "Torr",
+                MilliMetreOfMercury => "mmHg",
+                PoundPerSquareInch => "psi",
+            }
+            // Symbols of units those are the same type as some other unit, so
+            // they are never displayed, but can be parsed (with prefixes)
+            aliases {
+                Becquerel => "Bq",
+                Gray => "Gy",
+                Sievert => "Sv",
+            }
+        }
+    };
+}
+
+macro_rules! tables {
+    (
+        simple { $( $unit:ty => $s:literal, )+ }
+        coherent { $( $unit_:ty => $s_:literal, )+ }
+        aliases { $( $alias:ty => $s_alias:literal, )+ }
+    ) => {
+        /// Units those can be concatenated with prefixes
+        pub(crate) const SIMPLE_UNITS: &[(&str, RtUnit)] = &[$( ($s, <$unit>::RT), )+];
+
+        /// Units those can't be concatenated with prefixes
+        pub(crate) const COHERENT_UNITS: &[(&str, RtUnit)] = &[$( ($s_, <$unit_>::RT), )+];
+
+        /// Units those can be parsed (with prefixes), but are never displayed
+        pub(crate) const ALIAS_UNITS: &[(&str, RtUnit)] = &[$( ($s_alias, <$alias>::RT), )+];
+    };
+}
+
+unit_names!(tables);
+
 fn try_get_simple_name<D, R>() -> Option<&'static str>
 where
     D: DimensionsTrait,
@@ -213,12 +406,14 @@ where
 {
     macro_rules! r#match {
             (
-                $t:ty;
                 simple { $( $unit:ty => $s:literal, )+ }
                 coherent { $( $unit_:ty => $s_:literal, )+ }
+                aliases { $( $alias:ty => $s_alias:literal, )+ }
             ) => {
-                match <$t>::RT {
+                match <Unit<D, R>>::RT {
                     $(
+                        <$crate::prefixes::Quetta::<$unit>>::RT => Some(concat!("Q", $s)),
+                        <$crate::prefixes::Ronna::<$unit>>::RT => Some(concat!("R", $s)),
                         <$crate::prefixes::Yotta::<$unit>>::RT => Some(concat!("Y", $s)),
                         <$crate::prefixes::Zetta::<$unit>>::RT => Some(concat!("Z", $s)),
                         <$crate::prefixes::Exa::<$unit>>::RT => Some(concat!("E", $s)),
@@ -240,6 +435,8 @@ where
                         <$crate::prefixes::Atto::<$unit>>::RT => Some(concat!("a", $s)),
                         <$crate::prefixes::Zepto::<$unit>>::RT => Some(concat!("z", $s)),
                         <$crate::prefixes::Yocto::<$unit>>::RT => Some(concat!("y", $s)),
+                        <$crate::prefixes::Ronto::<$unit>>::RT => Some(concat!("r", $s)),
+                        <$crate::prefixes::Quecto::<$unit>>::RT => Some(concat!("q", $s)),
                     )+
                     $(
                         <$unit_>::RT => Some($s_),
@@ -249,56 +446,15 @@ where
             };
         }
 
-    // this is actually match on ~260 variants, yes
-    r#match! {
-        Unit<D, R>;
-        // by "simple" I mean "units those have name and can be concatenated
-        // with prefixes (milli/micro/kilo/etc)"
-        simple {
-            // Base units
-            Metre => "m",
-            // No kg (see below)
-            Second => "s",
-            Ampere => "A",
-            Kelvin => "K",
-            Mole => "mol",
-            Candela => "cd",
-
-            // The base unit is kg (kilogram), but when we are
-            // writing we want to count ratio from gram
-            Gram => "g",
-
-            // Derived units
-            // (No Radian/Steradian as they are dimensionless)
-            Hertz => "Hz",
-            Newton => "N",
-            Pascal => "Pa",
-            Joule => "J",
-            Watt => "W",
-        }
-        coherent {
-            // milli dimensionless (mdimless) and co. is something very strange :D
-            Dimensionless => "dimless",
-
-            // Coherent derived units
-            SquareMetre => "m^2",
-            CubicMetre => "m^3",
-            MetrePerSecond => "m/s",
-
-            // Non-SI
-            Minute => "min",
-            Hour => "h",
-            Day => "d",
-            KiloMetrePerHour => "km/h",
-        }
-    }
+    // this is actually match on ~300 variants, yes
+    unit_names!(r#match)
 }
 
 // We need to use handwritten impls to prevent unnecessary bounds on generics
 impl<D, R> Clone for Unit<D, R> {
     #[inline]
     fn clone(&self) -> Self {
-        Self::new()
+        *self
     }
 }
 
@@ -308,14 +464,20 @@ impl<D, R> Copy for Unit<D, R> {}
 /// `Unit<1, 0, -1, ..., 1/10> * Unit<0, 0, 1, ..., 10/1> =
 /// Unit<1, 0, 0, ..., 1/1>`
 ///
+/// The resulting ratio is simplified, so products of units with equal scales
+/// are the same type regardless of the order of factors. Prefixes don't
+/// simplify ratios though (see [`prefixes`](crate::prefixes)), neither do
+/// ratios that are too big for `typenum` (see [`Simplify`]).
+///
 /// It's used for multiplying quantities.
 impl<U, D, R> Mul<U> for Unit<D, R>
 where
     U: UnitTrait,
     D: Mul<U::Dimensions>,
     R: Mul<U::Ratio>,
+    Prod<R, U::Ratio>: Simplify,
 {
-    type Output = Unit<Prod<D, U::Dimensions>, Prod<R, U::Ratio>>;
+    type Output = Unit<Prod<D, U::Dimensions>, Simplified<Prod<R, U::Ratio>>>;
 
     #[inline]
     fn mul(self, _rhs: U) -> Self::Output {
@@ -327,14 +489,17 @@ where
 /// `Unit<1, 0, -1, ..., 1/10> / Unit<0, 0, 1, ..., 10/1> =
 /// Unit<1, 0, -2, ..., 1/100>`
 ///
+/// The resulting ratio is simplified (see [`Mul`] impl).
+///
 /// It's used for dividing quantities.
 impl<U, D, R> Div<U> for Unit<D, R>
 where
     U: UnitTrait,
     D: Div<U::Dimensions>,
     R: Div<U::Ratio>,
+    Quot<R, U::Ratio>: Simplify,
 {
-    type Output = Unit<Quot<D, U::Dimensions>, Quot<R, U::Ratio>>;
+    type Output = Unit<Quot<D, U::Dimensions>, Simplified<Quot<R, U::Ratio>>>;
 
     #[inline]
     fn div(self, _rhs: U) -> Self::Output {
@@ -342,14 +507,57 @@ where
     }
 }
 
+/// This multiplies exponents by `P` and raises ratio to the power `P` at
+/// type-level. E.g. `Unit<1, 0, -1, ..., 1/10> ^ 2 = Unit<2, 0, -2, ..., 1/100>`
+///
+/// The resulting ratio is simplified (see [`Mul`] impl).
+///
+/// It's used for raising quantities to integer powers.
+impl<P, D, R> Pow<P> for Unit<D, R>
+where
+    P: Integer,
+    D: Pow<P>,
+    R: Pow<P>,
+    Exp<R, P>: Simplify,
+{
+    type Output = Unit<Exp<D, P>, Simplified<Exp<R, P>>>;
+
+    #[inline]
+    fn powi(self, _exp: P) -> Self::Output {
+        Unit::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        prefixes::{Giga, Kilo, Milli, Nano, Yotta},
+        prefixes::{
+            Centi, Giga, Kilo, Mega, Micro, Milli, Nano, Pico, Quecto, Quetta, Ronna, Yocto, Yotta,
+            Zetta,
+        },
         units::*,
-        Dimensions, Unit,
+        Dimensions, Quantity, Unit, UnitTrait,
     };
-    use typenum::{N1, N2, P1, Z0};
+    use typenum::{assert_type_eq, Exp, N1, N2, P1, P2, P3, Z0};
+
+    #[test]
+    fn consts() {
+        const KMPH: (u128, u128, i8, i8) = (
+            KiloMetrePerHour::RATIO_NUM,
+            KiloMetrePerHour::RATIO_DEN,
+            KiloMetrePerHour::LENGTH,
+            KiloMetrePerHour::TIME,
+        );
+        assert_eq!(KMPH, (5, 18, 1, -1));
+
+        // `Kilo<Gram>` has ratio `1000/1000` at type level
+        assert_eq!((<Kilo<Gram>>::RATIO_NUM, <Kilo<Gram>>::RATIO_DEN), (1, 1));
+        assert_eq!(Volt::MASS, 1);
+        assert_eq!(Volt::ELECTRIC_CURRENT, -1);
+        assert_eq!(Kelvin::THERMODYNAMIC_TEMPERATURE, 1);
+        assert_eq!(Mole::AMOUNT_OF_SUBSTANCE, 1);
+        assert_eq!(Candela::LUMINOUS_INTENSITY, 1);
+    }
 
     macro_rules! assert_display_eq {
         ($T:ty, $s:expr $(,)?) => {
@@ -357,6 +565,53 @@ mod tests {
         };
     }
 
+    #[test]
+    fn pow() {
+        assert_type_eq!(Exp<Metre, P2>, SquareMetre);
+        assert_type_eq!(Exp<Metre, P3>, CubicMetre);
+        assert_type_eq!(Exp<Second, N1>, Hertz);
+        assert_type_eq!(Exp<MetrePerSecond, Z0>, Dimensionless);
+        assert_type_eq!(Exp<Kilo<Metre>, P2>, Mega<Unit![Metre ^ 2]>);
+        assert_type_eq!(Unit![Kilo<Metre> * Milli<Metre>], SquareMetre);
+        assert_type_eq!(Unit![Kilo<Metre> / Milli<Second>], Mega<MetrePerSecond>);
+        assert_type_eq!(
+            Exp<Centi<Metre>, N2>,
+            Unit![Dimensionless / Centi<Metre> / Centi<Metre>]
+        );
+    }
+
+    #[test]
+    fn extreme_prefixes() {
+        fn ratio<U: UnitTrait>() -> (u128, u128) {
+            (U::RATIO_NUM, U::RATIO_DEN)
+        }
+
+        // These ratios are too big to be simplified at type level, but the
+        // operations still compile
+        assert_eq!(
+            ratio::<Unit![Yotta<Metre> * Kilo<Metre>]>(),
+            (10u128.pow(27), 1)
+        );
+        assert_eq!(ratio::<Unit![Yotta<Metre> * Yocto<Metre>]>(), (1, 1));
+        assert_eq!(ratio::<Unit![Quetta<Metre> / Quetta<Second>]>(), (1, 1));
+        assert_eq!(
+            ratio::<Unit![Ronna<Gram> * Quecto<Metre>]>(),
+            (1, 10u128.pow(6))
+        );
+        assert_eq!(
+            ratio::<Unit![Dalton * Yotta<Metre>]>(),
+            (250_000, 150_553_519)
+        );
+
+        let area =
+            Quantity::<f64, Yotta<Metre>>::new(2.0) * Quantity::<f64, Yocto<Metre>>::new(3.0);
+        assert_eq!(area.into_unit::<SquareMetre>(), Quantity::new(6.0));
+
+        let speed =
+            Quantity::<f64, Quetta<Metre>>::new(6.0) / Quantity::<f64, Quetta<Second>>::new(3.0);
+        assert_eq!(speed.into_unit::<MetrePerSecond>(), Quantity::new(2.0));
+    }
+
     #[test]
     fn display_base() {
         assert_display_eq!(Metre, "m");
@@ -375,19 +630,97 @@ mod tests {
     fn display_coherent() {
         assert_display_eq!(CubicMetre, "m^3");
         assert_display_eq!(MetrePerSecond, "m/s");
+        assert_display_eq!(MetrePerSecondSquared, "m/s^2");
+        assert_display_eq!(NewtonMetre, "J");
+        assert_display_eq!(JoulePerKelvin, "J/K");
+        assert_display_eq!(WattPerSquareMetre, "W/m^2");
+        assert_display_eq!(WattPerMetreKelvin, "W/(m·K)");
+        assert_display_eq!(JoulePerKiloGramKelvin, "J/(kg·K)");
+        assert_display_eq!(KelvinPerWatt, "K/W");
+        assert_display_eq!(MolePerCubicMetre, "mol/m^3");
+        assert_display_eq!(MolePerKiloGram, "mol/kg");
+        assert_display_eq!(LumenSecond, "lm·s");
+        assert_display_eq!(LuxSecond, "lx·s");
+        assert_display_eq!(CandelaPerSquareMetre, "cd/m^2");
+        assert_display_eq!(Becquerel, "Hz");
+        assert_display_eq!(PascalSecond, "Pa·s");
+        assert_display_eq!(CubicMetrePerSecond, "m^3/s");
+        assert_display_eq!(LitrePerMinute, "L/min");
+        assert_display_eq!(WattHourPerKiloGram, "Wh/kg");
+        assert_display_eq!(LitrePerHundredKiloMetre, "L/100km");
+        assert_display_eq!(VoltPerMetre, "V/m");
+        assert_display_eq!(CoulombPerSquareMetre, "C/m^2");
+        assert_display_eq!(CoulombPerCubicMetre, "C/m^3");
+        assert_display_eq!(FaradPerMetre, "F/m");
+        assert_display_eq!(SievertPerHour, "Sv/h");
+        assert_display_eq!(Micro::<SievertPerHour>, "μSv/h");
+        assert_display_eq!(WattPerSteradian, "W");
+        assert_display_eq!(WattPerSquareMetrePerHertz, "W/(m^2·Hz)");
+        assert_display_eq!(Jansky, "Jy");
+        assert_display_eq!(Milli::<Jansky>, "mJy");
+        assert_display_eq!(Micro::<Jansky>, "μJy");
+        assert_display_eq!(Litre, "L");
+        assert_display_eq!(MolePerLitre, "mol/L");
         assert_display_eq!(Hour, "h");
         assert_display_eq!(Minute, "min");
+        assert_display_eq!(Week, "wk");
+        assert_display_eq!(JulianYear, "a");
         assert_display_eq!(KiloMetrePerHour, "km/h");
+        assert_display_eq!(Knot, "kn");
+        assert_display_eq!(MilePerHour, "mph");
+        assert_display_eq!(NauticalMile, "nmi");
+        assert_display_eq!(Angstrom, "Å");
+        assert_display_eq!(LightYear, "ly");
+        assert_display_eq!(Parsec, "pc");
+        assert_display_eq!(Tonne, "t");
+        assert_display_eq!(Carat, "ct");
+        assert_display_eq!(Dalton, "Da");
+        assert_display_eq!(Degree, "°");
+        assert_display_eq!(RevolutionsPerMinute, "rpm");
+        assert_display_eq!(Percent, "%");
+        assert_display_eq!(Bar, "bar");
+        assert_display_eq!(Atmosphere, "atm");
+        assert_display_eq!(Torr, "Torr");
+        assert_display_eq!(MilliMetreOfMercury, "mmHg");
+        assert_display_eq!(PoundPerSquareInch, "psi");
     }
 
     #[test]
     fn display_builtin_prefix() {
         assert_display_eq!(Kilo::<Hertz>, "kHz");
         assert_display_eq!(Yotta::<Joule>, "YJ");
+        assert_display_eq!(Zetta::<Joule>, "ZJ");
+        assert_display_eq!(Ronna::<Joule>, "RJ");
+        assert_display_eq!(Quetta::<Gram>, "Qg");
+        assert_display_eq!(Quecto::<Metre>, "qm");
         assert_display_eq!(Giga::<Watt>, "GW");
         assert_display_eq!(Kilo::<Gram>, "kg");
         assert_display_eq!(Milli::<Gram>, "mg");
         assert_display_eq!(Nano::<Metre>, "nm");
+        assert_display_eq!(WattHour, "Wh");
+        assert_display_eq!(KiloWattHour, "kWh");
+        assert_display_eq!(AmpereHour, "Ah");
+        assert_display_eq!(MilliAmpereHour, "mAh");
+        assert_display_eq!(Kilo::<Ohm>, "kΩ");
+        assert_display_eq!(Milli::<Volt>, "mV");
+        assert_display_eq!(Micro::<Coulomb>, "μC");
+        assert_display_eq!(Pico::<Farad>, "pF");
+    }
+
+    #[test]
+    fn display_prefixed_power() {
+        assert_display_eq!(Unit![Kilo<Metre> * Kilo<Metre>], "km^2");
+        assert_display_eq!(Unit![Centi<Metre> ^ 3], "cm^3");
+        assert_display_eq!(Unit![Dimensionless / Kilo<Metre> ^ 2], "km^-2");
+        assert_display_eq!(Unit![Milli<Second> ^ 2], "ms^2");
+        assert_display_eq!(Unit![KiloGram ^ 2], "kg^2");
+        assert_display_eq!(Unit![Gram ^ 3], "g^3");
+        assert_display_eq!(Unit![Milli<Gram> ^ 2], "mg^2");
+
+        // Ratio isn't a power of a prefix
+        assert_display_eq!(Unit![Minute ^ 2], "s^2 (ratio: 3600)");
+        assert_display_eq!(Unit![Kilo<Metre> * Metre], "m^2 (ratio: 1000)");
+        assert_display_eq!(Unit![Kilo<Metre> ^ 2 * Metre], "hm^3");
     }
 
     #[test]
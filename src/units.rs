@@ -1,7 +1,13 @@
-use typenum::{P1, U24, U60, Z0};
+use typenum::{
+    Exp, Prod, Sum, UInt, UTerm, B0, B1, P1, U1, U10, U1000, U1000000000000000000, U101, U133, U15,
+    U18, U200, U214, U237, U24, U26, U30, U322, U325, U359, U387, U397, U415, U45, U460, U463,
+    U472, U487, U5, U516, U580, U60, U602, U64, U665, U673, U7, U730, U76, U760, U775, U800, U814,
+    U852, U856, U9, U900, U913, U980, Z0,
+};
 
 use crate::{
-    prefixes::{Kilo, Milli, MulBy},
+    fraction::Fraction,
+    prefixes::{Centi, DivBy, Hecto, Kilo, Milli, MulBy},
     unit::Unit,
     Dimensions,
 };
@@ -33,6 +39,13 @@ pub type Candela = Unit<Dimensions<Z0, Z0, Z0, Z0, Z0, Z0, P1>>;
 // Derived units
 /// Radian. `rad`
 pub type Radian = Unit![Metre / Metre];
+/// Degree. `°`. `π/180` radians.
+///
+/// π is irrational, so like [`Parsec`] this alias uses a fixed value:
+/// 0.017 453 292 519 943 296 radians (relative error is below 10^-16, i.e.
+/// below precision of `f64`).
+pub type Degree =
+    Unit<Dimensions<Z0, Z0, Z0, Z0, Z0, Z0, Z0>, Fraction<DegreeNumerator, U1000000000000000000>>;
 /// Steradian. `sr`
 pub type Steradian = Unit![Metre ^ 2 / Metre ^ 2];
 /// Hertz. `Hz`
@@ -45,6 +58,29 @@ pub type Pascal = Unit![KiloGram / Metre / Second ^ 2];
 pub type Joule = Unit![KiloGram * Metre ^ 2 / Second ^ 2];
 /// Watt. `W`
 pub type Watt = Unit![KiloGram * Metre ^ 2 * Second ^ -3];
+/// Coulomb. `C`
+pub type Coulomb = Unit![Ampere * Second];
+/// Volt. `V`
+pub type Volt = Unit![Watt / Ampere];
+/// Ohm. `Ω`
+pub type Ohm = Unit![Volt / Ampere];
+/// Farad. `F`
+pub type Farad = Unit![Coulomb / Volt];
+/// Becquerel. `Bq`
+///
+/// Note: this is the same type as [`Hertz`], so it's displayed as `Hz`.
+pub type Becquerel = Unit![Dimensionless / Second];
+/// Gray. `Gy`. Absorbed dose.
+pub type Gray = Unit![Joule / KiloGram];
+/// Sievert. `Sv`. Equivalent dose.
+///
+/// Note: this is the same type as [`Gray`], so neither of them has a display
+/// name (they are displayed in base units), but both `Sv` and `Gy` (with
+/// prefixes) are accepted by [`RtUnit::parse`]. [`SievertPerHour`] does have a
+/// display name.
+///
+/// [`RtUnit::parse`]: crate::rt::RtUnit::parse
+pub type Sievert = Unit![Joule / KiloGram];
 // TODO
 
 // Coherent derived units
@@ -55,6 +91,82 @@ pub type SquareMetre = Unit![Metre ^ 2];
 pub type CubicMetre = Unit![Metre ^ 3];
 /// Metre per second. `v`
 pub type MetrePerSecond = Unit![Metre / Second];
+/// Radian per second. `ω`
+///
+/// Note: radian is dimensionless, so this is the same type as [`Hertz`]. See
+/// [`Quantity::to_angular_velocity`] for converting revolutions to radians.
+///
+/// [`Quantity::to_angular_velocity`]: crate::Quantity::to_angular_velocity
+pub type RadianPerSecond = Unit![Radian / Second];
+/// Metre per second squared. `a`
+pub type MetrePerSecondSquared = Unit![Metre / Second ^ 2];
+/// Newton metre. `τ`
+///
+/// Note: this is the same type as [`Joule`], so it's displayed as `J`. Use
+/// [`Quantity::display_torque`] to show torque as `N·m`. To keep torque and
+/// energy apart in the type system wrap one of them into a newtype, e.g.
+/// `struct Torque(Quantity<f32, NewtonMetre>);`, that doesn't implement
+/// conversions to/from energy.
+///
+/// [`Quantity::display_torque`]: crate::Quantity::display_torque
+pub type NewtonMetre = Unit![Newton * Metre];
+/// Joule per kelvin. `C`
+pub type JoulePerKelvin = Unit![Joule / Kelvin];
+/// Watt per square metre. `E`
+pub type WattPerSquareMetre = Unit![Watt / Metre ^ 2];
+/// Watt per kilogram. `W/kg`. Specific power (power density).
+///
+/// Note: this is the same type as [`GrayPerSecond`], so neither of them has a
+/// display name.
+pub type WattPerKiloGram = Unit![Watt / KiloGram];
+/// Watt per metre kelvin. `k`. Thermal conductivity.
+pub type WattPerMetreKelvin = Unit![Watt / Metre / Kelvin];
+/// Joule per kilogram kelvin. `c`. Specific heat capacity.
+pub type JoulePerKiloGramKelvin = Unit![Joule / KiloGram / Kelvin];
+/// Kelvin per watt. `R_th`. Thermal resistance.
+pub type KelvinPerWatt = Unit![Kelvin / Watt];
+/// Kelvin per second. `K/s`. Rate of temperature change.
+pub type KelvinPerSecond = Unit![Kelvin / Second];
+/// Mole per cubic metre. `c`. Amount concentration.
+pub type MolePerCubicMetre = Unit![Mole / Metre ^ 3];
+/// Mole per kilogram. `b`. Molality.
+pub type MolePerKiloGram = Unit![Mole / KiloGram];
+/// Lumen second. `Q_v`. Luminous energy.
+///
+/// Note: steradian is dimensionless, so lumen (`cd·sr`) is the same type as
+/// [`Candela`] and this is the same type as `Unit![Candela * Second]`.
+pub type LumenSecond = Unit![Candela * Steradian * Second];
+/// Lux second. `H_v`. Luminous exposure.
+pub type LuxSecond = Unit![Candela * Steradian / Metre ^ 2 * Second];
+/// Candela per square metre. `L_v`. Luminance.
+///
+/// Note: steradian is dimensionless, so this is the same type as lux
+/// (`lm/m^2`, illuminance).
+pub type CandelaPerSquareMetre = Unit![Candela / Metre ^ 2];
+/// Volt per metre. `E`. Electric field strength.
+pub type VoltPerMetre = Unit![Volt / Metre];
+/// Coulomb per square metre. `σ`. Surface charge density.
+pub type CoulombPerSquareMetre = Unit![Coulomb / Metre ^ 2];
+/// Coulomb per cubic metre. `ρ`. Charge density.
+pub type CoulombPerCubicMetre = Unit![Coulomb / Metre ^ 3];
+/// Farad per metre. `ε`. Permittivity.
+pub type FaradPerMetre = Unit![Farad / Metre];
+/// Pascal second. `μ`. Dynamic viscosity.
+pub type PascalSecond = Unit![Pascal * Second];
+/// Cubic metre per second. `Q`. Volumetric flow rate.
+pub type CubicMetrePerSecond = Unit![Metre ^ 3 / Second];
+/// Gray per second. `Gy/s`. Absorbed dose rate.
+///
+/// Note: this is the same type as [`WattPerKiloGram`], so neither of them has
+/// a display name.
+pub type GrayPerSecond = Unit![Gray / Second];
+/// Watt per steradian. `I_e`. Radiant intensity.
+///
+/// Note: steradian is dimensionless, so this is the same type as [`Watt`]
+/// and is displayed as `W`.
+pub type WattPerSteradian = Unit![Watt / Steradian];
+/// Watt per square metre per hertz. `S_ν`. Spectral flux density.
+pub type WattPerSquareMetrePerHertz = Unit![Watt / Metre ^ 2 / Hertz];
 // TODO
 
 // Non-SI
@@ -65,9 +177,145 @@ pub type Minute = MulBy<Second, U60>;
 pub type Hour = MulBy<Minute, U60>;
 /// day. 24 hours.
 pub type Day = MulBy<Hour, U24>;
+/// week. 7 days.
+pub type Week = MulBy<Day, U7>;
+/// Julian year. `a`. 365.25 days (= 8766 hours).
+pub type JulianYear = MulBy<Hour, Prod<U487, U18>>;
 /// Kilometre per hour. `km/h`
 pub type KiloMetrePerHour = Unit![Kilo<Metre> / Hour];
+/// Knot. `kn`. One nautical mile (1852 metres) per hour, `463/900` metres
+/// per second.
+pub type Knot = MulBy<DivBy<MetrePerSecond, U900>, U463>;
+/// Mile per hour. `mph`. One international mile (1609.344 metres) per hour,
+/// `1397/3125` (= 0.44704) metres per second.
+pub type MilePerHour = DivBy<MulBy<MetrePerSecond, Thousands<U1, U397>>, Exp<U5, U5>>;
+/// Revolutions per minute. `rpm`. `1/60` hertz.
+///
+/// Revolutions are counted as dimensionless, so this unit can be converted to
+/// [`Hertz`] with `into_unit`. Use [`Quantity::to_angular_velocity`] to get
+/// radians per second.
+///
+/// [`Quantity::to_angular_velocity`]: crate::Quantity::to_angular_velocity
+pub type RevolutionsPerMinute = Unit![Dimensionless / Minute];
+/// Watt-hour. `Wh`. 3600 joules.
+pub type WattHour = Unit![Watt * Hour];
+/// Kilowatt-hour. `kWh`. 3.6 megajoules.
+pub type KiloWattHour = Kilo<WattHour>;
+/// Ampere-hour. `Ah`. 3600 coulombs.
+pub type AmpereHour = Unit![Ampere * Hour];
+/// Milliampere-hour. `mAh`. 3.6 coulombs.
+pub type MilliAmpereHour = Milli<AmpereHour>;
+/// Jansky. `Jy`. `10^-26` watts per square metre per hertz.
+pub type Jansky = DivBy<WattPerSquareMetrePerHertz, Exp<U10, U26>>;
+/// Litre per minute. `L/min`. `1/60000` cubic metre per second.
+pub type LitrePerMinute = Unit![Litre / Minute];
+/// Litre per 100 kilometres. `L/100km`. Fuel consumption, `10^-8` square
+/// metre.
+///
+/// See [`Quantity::recip_into`] for converting to [`KiloMetrePerLitre`].
+///
+/// [`Quantity::recip_into`]: crate::Quantity::recip_into
+pub type LitrePerHundredKiloMetre = Unit![Litre / Hecto<Kilo<Metre>>];
+/// Kilometre per litre. `km/L`. Fuel economy, `10^6` per square metre.
+pub type KiloMetrePerLitre = Unit![Kilo<Metre> / Litre];
+/// Sievert per hour. `Sv/h`. Equivalent dose rate.
+///
+/// Note: sieverts are the same type as grays, so this is the same type as
+/// gray per hour.
+pub type SievertPerHour = Unit![Sievert / Hour];
+/// Litre. `L`. `1/1000` cubic metre.
+pub type Litre = Milli<CubicMetre>;
+/// Mole per litre. `mol/L`. 1000 moles per cubic metre.
+pub type MolePerLitre = Unit![Mole / Litre];
+/// Watt-hour per kilogram. `Wh/kg`. Specific energy (energy density) of
+/// batteries, 3600 joules per kilogram.
+pub type WattHourPerKiloGram = Unit![WattHour / KiloGram];
+/// Percent. `%`. `1/100`.
+pub type Percent = Centi<Dimensionless>;
+/// Bar. `bar`. 100 000 pascals.
+pub type Bar = MulBy<Pascal, Exp<U10, U5>>;
+/// Standard atmosphere. `atm`. 101 325 pascals.
+pub type Atmosphere = MulBy<Pascal, Thousands<U101, U325>>;
+/// Torr. `Torr`. `1/760` of [`Atmosphere`] (≈ 133.322 pascals).
+pub type Torr = DivBy<Atmosphere, U760>;
+/// Conventional millimetre of mercury. `mmHg`. Exactly 133.322387415
+/// pascals.
+///
+/// Note: this differs from [`Torr`] by less than 0.000015%.
+pub type MilliMetreOfMercury =
+    DivBy<MulBy<Pascal, Thousands<Thousands<Thousands<U133, U322>, U387>, U415>>, Exp<U10, U9>>;
+/// Pound-force per square inch. `psi`. ≈ 6894.757 pascals.
+///
+/// Defined exactly as `0.45359237 kg × 9.80665 m/s² / (0.0254 m)²`.
+///
+/// Note: the ratio has a big numerator (≈ `8.9 × 10^12`), so converting
+/// integer quantities to/from `psi` with `into_unit` easily overflows, prefer
+/// floats.
+pub type PoundPerSquareInch = DivBy<
+    MulBy<Pascal, Prod<Thousands<Thousands<U45, U359>, U237>, Thousands<U980, U665>>>,
+    Prod<Thousands<U64, U516>, Exp<U10, U5>>,
+>;
+/// Degree Celsius interval. `°C`. Exactly 1 kelvin.
+///
+/// Celsius and kelvin scales only differ in the origin, so *differences* of
+/// temperatures are the same in both and this is the same type as [`Kelvin`].
+/// Absolute temperatures are points, see [`absolute::Temperature`].
+///
+/// [`absolute::Temperature`]: crate::absolute::Temperature
+pub type DegreeCelsiusInterval = Kelvin;
+/// Degree Fahrenheit interval. `°F`. `5/9` kelvin.
+pub type DegreeFahrenheitInterval = MulBy<DivBy<Kelvin, U9>, U5>;
+/// Kelvin per minute. `K/min`. `1/60` kelvin per second.
+pub type KelvinPerMinute = Unit![Kelvin / Minute];
+
+/// Nautical mile. `nmi`. Exactly 1852 metres.
+pub type NauticalMile = MulBy<Metre, Thousands<U1, U852>>;
+/// Ångström. `Å`. `10^-10` metres.
+pub type Angstrom = DivBy<Metre, Exp<U10, U10>>;
+/// Light-year. `ly`. Distance light travels in a [`JulianYear`], exactly
+/// 9 460 730 472 580 800 metres.
+pub type LightYear = MulBy<
+    Metre,
+    Thousands<Thousands<Thousands<Thousands<Thousands<U9, U460>, U730>, U472>, U580>, U800>,
+>;
+/// Parsec. `pc`. ≈ 3.0857 × 10^16 metres.
+///
+/// Parsec is defined as `648 000 / π` astronomical units, so it can't be
+/// represented by a rational ratio. This alias uses 30 856 775 814 913 673
+/// metres (the nearest whole number of metres), so conversions are exact up
+/// to a metre, which is far below precision of any astronomical distance.
+pub type Parsec = MulBy<
+    Metre,
+    Thousands<Thousands<Thousands<Thousands<Thousands<U30, U856>, U775>, U814>, U913>, U673>,
+>;
 
 // Etc
 /// gram. `g`.
 pub type Gram = Milli<KiloGram>; // I know, that's weird but in CI base unit is kilogram, not gram.
+/// Tonne. `t`. 1000 kilograms.
+pub type Tonne = Kilo<KiloGram>;
+/// Carat. `ct`. 200 milligrams.
+pub type Carat = MulBy<Milli<Gram>, U200>;
+/// Dalton (unified atomic mass unit). `Da`. ≈ 1.66054 × 10^-27 kilograms.
+///
+/// Dalton is a measured constant, not a defined one, so it can't be
+/// represented by an exact ratio. Like other inexact units of this crate (e.g.
+/// [`Parsec`]) this alias uses a fixed value: `1 g / N_A` (gram divided by
+/// the Avogadro constant, which is exact), i.e. ≈ 1.660 539 067 × 10^-27 kg.
+/// This is off by ≈ 3.5 × 10^-10 relative to the measured value, which is
+/// below the precision of most mass measurements.
+///
+/// The ratio has a ≈ `6 × 10^26` divisor, so only float storage is
+/// meaningful.
+pub type Dalton = DivBy<DivBy<Gram, Thousands<Thousands<U602, U214>, U76>>, Exp<U10, U15>>;
+
+/// `17 453 292 519 943 296`, the numerator of [`Degree`] ratio.
+///
+/// Written out in binary (instead of `Thousands`), because coherence checks
+/// can't normalize typenum operations and [`Degree`] needs to be distinct from
+/// [`Radian`] for [`AngleUnit`](crate::angle::AngleUnit) impls.
+#[rustfmt::skip]
+type DegreeNumerator = UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, B1>, B1>, B1>, B1>, B0>, B0>, B0>, B0>, B0>, B0>, B0>, B0>, B1>, B1>, B0>, B1>, B0>, B1>, B1>, B0>, B1>, B0>, B1>, B0>, B1>, B0>, B1>, B0>, B0>, B1>, B1>, B0>, B0>, B1>, B1>, B1>, B1>, B1>, B1>, B1>, B0>, B1>, B1>, B0>, B0>, B1>, B0>, B0>, B0>, B0>, B0>, B0>, B0>;
+
+/// `H * 1000 + L`, to write big constants in groups of 3 digits.
+type Thousands<H, L> = Sum<Prod<H, U1000>, L>;
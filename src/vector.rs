@@ -0,0 +1,350 @@
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use typenum::Prod;
+
+use crate::{
+    from_int::{Sqrt, Zero},
+    strict::{StorageAdd, StorageMul, StorageSub},
+    Quantity, UnitTrait,
+};
+
+macro_rules! vector {
+    (
+        $( #[$meta:meta] )*
+        $name:ident { $first:ident $(, $field:ident )* }
+    ) => {
+        $( #[$meta] )*
+        pub struct $name<S, U> {
+            #[doc = concat!("`", stringify!($first), "` component")]
+            pub $first: Quantity<S, U>,
+            $(
+                #[doc = concat!("`", stringify!($field), "` component")]
+                pub $field: Quantity<S, U>,
+            )*
+        }
+
+        impl<S, U> $name<S, U>
+        where
+            U: UnitTrait,
+        {
+            /// Creates new vector from its components.
+            #[inline]
+            pub const fn new($first: Quantity<S, U> $(, $field: Quantity<S, U> )*) -> Self {
+                Self { $first $(, $field )* }
+            }
+
+            /// Returns vector with all components equal to zero.
+            #[inline]
+            pub fn zero() -> Self
+            where
+                S: Zero,
+            {
+                Self {
+                    $first: Quantity::new(S::zero()),
+                    $( $field: Quantity::new(S::zero()), )*
+                }
+            }
+
+            /// Dot product, the unit is the product of units (e.g. `m * m =
+            /// m^2`).
+            #[inline]
+            pub fn dot<U1>(self, rhs: $name<S, U1>) -> Quantity<S, Prod<U, U1>>
+            where
+                S: StorageMul<U, U1, Output = S> + StorageAdd<Prod<U, U1>, Output = S>,
+                U: Mul<U1>,
+                U1: UnitTrait,
+            {
+                self.$first * rhs.$first $( + self.$field * rhs.$field )*
+            }
+
+            /// Returns squared length of the vector.
+            #[inline]
+            pub fn norm_squared(self) -> Quantity<S, Prod<U, U>>
+            where
+                S: StorageMul<U, U, Output = S> + StorageAdd<Prod<U, U>, Output = S> + Copy,
+                U: Mul<U>,
+            {
+                self.dot(self)
+            }
+
+            /// Returns length of the vector (`Sqrt` is implemented for floats
+            /// with `float-math` feature).
+            #[inline]
+            pub fn norm(self) -> Quantity<S, U>
+            where
+                S: Add<Output = S> + Mul<Output = S> + Sqrt + Copy,
+            {
+                let sqr = self.$first.into_inner() * self.$first.into_inner()
+                    $( + self.$field.into_inner() * self.$field.into_inner() )*;
+                Quantity::new(sqr.sqrt())
+            }
+
+            /// Applies `f` to every component.
+            #[inline]
+            pub fn map<F, T, U1>(self, mut f: F) -> $name<T, U1>
+            where
+                F: FnMut(Quantity<S, U>) -> Quantity<T, U1>,
+            {
+                $name {
+                    $first: f(self.$first),
+                    $( $field: f(self.$field), )*
+                }
+            }
+        }
+
+        impl<S, U> Add for $name<S, U>
+        where
+            S: StorageAdd<U, Output = S>,
+            U: UnitTrait,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self {
+                    $first: self.$first + rhs.$first,
+                    $( $field: self.$field + rhs.$field, )*
+                }
+            }
+        }
+
+        impl<S, U> Sub for $name<S, U>
+        where
+            S: StorageSub<U, Output = S>,
+            U: UnitTrait,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self {
+                    $first: self.$first - rhs.$first,
+                    $( $field: self.$field - rhs.$field, )*
+                }
+            }
+        }
+
+        impl<S, U> Neg for $name<S, U>
+        where
+            S: Neg<Output = S>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self {
+                    $first: -self.$first,
+                    $( $field: -self.$field, )*
+                }
+            }
+        }
+
+        /// Multiplication by scalar.
+        impl<S, U> Mul<S> for $name<S, U>
+        where
+            S: Mul<Output = S> + Copy,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: S) -> Self::Output {
+                Self {
+                    $first: self.$first.map(|s| s * rhs),
+                    $( $field: self.$field.map(|s| s * rhs), )*
+                }
+            }
+        }
+
+        /// Division by scalar.
+        impl<S, U> Div<S> for $name<S, U>
+        where
+            S: Div<Output = S> + Copy,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: S) -> Self::Output {
+                Self {
+                    $first: self.$first.map(|s| s / rhs),
+                    $( $field: self.$field.map(|s| s / rhs), )*
+                }
+            }
+        }
+
+        /// Multiplication by quantity (e.g. velocity * time = displacement).
+        impl<S, U0, U1> Mul<Quantity<S, U1>> for $name<S, U0>
+        where
+            S: StorageMul<U0, U1, Output = S> + Copy,
+            U0: UnitTrait + Mul<U1>,
+            U1: UnitTrait,
+        {
+            type Output = $name<S, Prod<U0, U1>>;
+
+            #[inline]
+            fn mul(self, rhs: Quantity<S, U1>) -> Self::Output {
+                $name {
+                    $first: self.$first * rhs,
+                    $( $field: self.$field * rhs, )*
+                }
+            }
+        }
+
+        impl<S: Clone, U> Clone for $name<S, U> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    $first: self.$first.clone(),
+                    $( $field: self.$field.clone(), )*
+                }
+            }
+        }
+
+        impl<S: Copy, U> Copy for $name<S, U> {}
+
+        impl<S: PartialEq, U> PartialEq for $name<S, U> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.$first == other.$first $( && self.$field == other.$field )*
+            }
+        }
+
+        impl<S, U> fmt::Debug for $name<S, U>
+        where
+            S: fmt::Debug,
+            U: fmt::Debug + Default,
+        {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field(stringify!($first), &self.$first)
+                    $( .field(stringify!($field), &self.$field) )*
+                    .finish()
+            }
+        }
+    };
+}
+
+vector! {
+    /// 2D vector of quantities of the same unit.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{vector::Vector2, IntExt};
+    ///
+    /// let a = Vector2::new(3.m(), 4.m());
+    /// let b = Vector2::new(1.m(), 2.m());
+    ///
+    /// assert_eq!(a + b, Vector2::new(4.m(), 6.m()));
+    /// assert_eq!(a * 2, Vector2::new(6.m(), 8.m()));
+    /// assert_eq!(a.dot(b), 11.sqm());
+    /// assert_eq!(a.cross(b), 2.sqm());
+    /// ```
+    Vector2 { x, y }
+}
+
+vector! {
+    /// 3D vector of quantities of the same unit.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typed_phy::{
+    ///     units::{Metre, Newton, NewtonMetre},
+    ///     vector::Vector3,
+    ///     IntExt, Quantity,
+    /// };
+    ///
+    /// let r = Vector3::new(2.m(), 0.m(), 0.m());
+    /// let force = Vector3::<_, Newton>::new(0.quantity(), 5.quantity(), 0.quantity());
+    ///
+    /// // Torque
+    /// assert_eq!(
+    ///     r.cross(force),
+    ///     Vector3::<_, NewtonMetre>::new(0.quantity(), 0.quantity(), 10.quantity())
+    /// );
+    ///
+    /// // Displacement
+    /// let v = Vector3::new(1.0.mps(), 2.0.mps(), 3.0.mps());
+    /// assert_eq!(v * 2.0.s(), Vector3::new(2.0.m(), 4.0.m(), 6.0.m()));
+    /// # #[cfg(feature = "float-math")]
+    /// assert_eq!(Vector3::new(2.0.m(), 3.0.m(), 6.0.m()).norm(), 7.0.m());
+    /// ```
+    Vector3 { x, y, z }
+}
+
+impl<S, U> Vector2<S, U>
+where
+    U: UnitTrait,
+{
+    /// 2D cross product (`x0 * y1 - y0 * x1`, z component of the 3D cross
+    /// product).
+    #[inline]
+    pub fn cross<U1>(self, rhs: Vector2<S, U1>) -> Quantity<S, Prod<U, U1>>
+    where
+        S: StorageMul<U, U1, Output = S> + StorageSub<Prod<U, U1>, Output = S>,
+        U: Mul<U1>,
+        U1: UnitTrait,
+    {
+        self.x * rhs.y - self.y * rhs.x
+    }
+}
+
+impl<S, U> Vector3<S, U>
+where
+    U: UnitTrait,
+{
+    /// Cross product, the unit is the product of units (e.g. `m * N = N*m`).
+    #[inline]
+    pub fn cross<U1>(self, rhs: Vector3<S, U1>) -> Vector3<S, Prod<U, U1>>
+    where
+        S: StorageMul<U, U1, Output = S> + StorageSub<Prod<U, U1>, Output = S> + Copy,
+        U: Mul<U1>,
+        U1: UnitTrait,
+    {
+        Vector3 {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Vector2, Vector3};
+    use crate::{units::Metre, IntExt};
+
+    #[test]
+    fn vector2() {
+        let a = Vector2::new(1.m(), 2.m());
+
+        assert_eq!(a - a, Vector2::zero());
+        assert_eq!(-a, Vector2::new((-1).m(), (-2).m()));
+        assert_eq!(a * 4 / 2, Vector2::new(2.m(), 4.m()));
+        assert_eq!(a.norm_squared(), 5.sqm());
+        assert_eq!(a.cross(a), 0.sqm());
+        #[cfg(feature = "float-math")]
+        assert_eq!(Vector2::new(3.0.m(), 4.0.m()).norm(), 5.0.m());
+    }
+
+    #[test]
+    fn vector3() {
+        let x = Vector3::new(1.m(), 0.m(), 0.m());
+        let y = Vector3::new(0.m(), 1.m(), 0.m());
+        let z = Vector3::new(0.m(), 0.m(), 1.m());
+
+        assert_eq!(x.cross(y), z.map(|c| c * 1.m()));
+        assert_eq!(y.cross(z), x.map(|c| c * 1.m()));
+        assert_eq!(z.cross(x), y.map(|c| c * 1.m()));
+        assert_eq!(x.dot(y), 0.sqm());
+        assert_eq!((x + y + z).norm_squared(), 3.sqm());
+        assert_eq!(
+            Vector3::new(1.s(), 2.s(), 3.s()).map(|c| c * 2.mps()),
+            Vector3::<_, Metre>::new(2.m(), 4.m(), 6.m())
+        );
+    }
+}